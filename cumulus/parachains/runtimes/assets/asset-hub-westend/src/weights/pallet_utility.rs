@@ -115,4 +115,26 @@ impl<T: frame_system::Config> pallet_utility::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(8_903_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// The range of component `c` is `[0, 1000]`.
+	fn batch_with_checkpoints(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_795_000 picoseconds.
+		Weight::from_parts(5_802_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 1_818
+			.saturating_add(Weight::from_parts(3_842_517, 0).saturating_mul(c.into()))
+	}
+	/// The range of component `c` is `[0, 1000]`.
+	fn dry_run_batch(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_106_000 picoseconds.
+		Weight::from_parts(6_113_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 1_729
+			.saturating_add(Weight::from_parts(3_655_090, 0).saturating_mul(c.into()))
+	}
 }
@@ -321,4 +321,6 @@ impl pallet_broker::Config for Runtime {
 	type MaxAutoRenewals = ConstU32<100>;
 	type PriceAdapter = pallet_broker::CenterTargetPrice<Balance>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
+	type MaxListingsPerTimeslice = ConstU32<50>;
+	type MaxSaleHistory = ConstU32<12>;
 }
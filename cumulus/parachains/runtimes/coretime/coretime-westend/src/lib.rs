@@ -799,6 +799,10 @@ impl_runtime_apis! {
 		fn sale_price() -> Result<Balance, DispatchError> {
 			Broker::current_price()
 		}
+
+		fn sale_history() -> Vec<pallet_broker::SaleHistoryRecord<Balance>> {
+			Broker::sale_history()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
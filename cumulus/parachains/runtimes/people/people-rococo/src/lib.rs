@@ -569,6 +569,7 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type MaxActiveMigrations = ConstU32<1>;
 	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
 }
 
@@ -159,7 +159,7 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
 	}
-	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
+	/// Storage: `ConvictionVoting::VotingFor` (r:9 w:9)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:512 w:512)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
@@ -168,20 +168,25 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Storage: `Scheduler::Retries` (r:0 w:50)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[0, 512]`.
-	fn undelegate(r: u32, ) -> Weight {
+	/// The range of component `d` is `[1, 8]`.
+	fn undelegate(r: u32, d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `29556 + r * (365 ±0)`
-		//  Estimated: `83866 + r * (3411 ±0)`
+		//  Measured:  `29556 + r * (365 ±0) + d * (154 ±0)`
+		//  Estimated: `83866 + r * (3411 ±0) + d * (29716 ±0)`
 		// Minimum execution time: 40_027_000 picoseconds.
-		Weight::from_parts(811_699_645, 0)
+		Weight::from_parts(793_594_645, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
 			// Standard Error: 60_140
-			.saturating_add(Weight::from_parts(21_801_527, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(Weight::from_parts(174_412_216, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(18_105_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
-			.saturating_add(T::DbWeight::get().writes(43))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(42))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
@@ -201,4 +206,57 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:9 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27641), added: 30116, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::RetractedDelegations` (r:8 w:8)
+	/// Proof: `ConvictionVoting::RetractedDelegations` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 8]`.
+	fn vote_override(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893 + d * (391 ±0)`
+		//  Estimated: `31106 + d * (2563 ±0)`
+		// Minimum execution time: 61_241_000 picoseconds.
+		Weight::from_parts(61_248_117, 0)
+			.saturating_add(Weight::from_parts(0, 31106))
+			// Standard Error: 4_454
+			.saturating_add(Weight::from_parts(9_412_305, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::DelegatePolicies` (r:0 w:1)
+	/// Proof: `ConvictionVoting::DelegatePolicies` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	fn set_delegate_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 11_724_000 picoseconds.
+		Weight::from_parts(11_731_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ConvictionVoting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `ConvictionVoting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389`
+		//  Estimated: `60422`
+		// Minimum execution time: 23_106_000 picoseconds.
+		Weight::from_parts(24_018_000, 0)
+			.saturating_add(Weight::from_parts(0, 60422))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
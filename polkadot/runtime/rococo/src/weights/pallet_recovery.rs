@@ -125,17 +125,19 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Fallbacks` (r:1 w:0)
+	/// Proof: `Recovery::Fallbacks` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `326 + n * (64 ±0)`
+		//  Measured:  `418 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 24_247_000 picoseconds.
-		Weight::from_parts(25_414_474, 0)
+		// Minimum execution time: 29_991_000 picoseconds.
+		Weight::from_parts(31_158_474, 0)
 			.saturating_add(Weight::from_parts(0, 3854))
 			// Standard Error: 6_515
-			.saturating_add(Weight::from_parts(114_119, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(219_585, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
@@ -184,4 +186,52 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn rotate_friends(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + n * (32 ±0)`
+		//  Estimated: `3816`
+		// Minimum execution time: 22_424_000 picoseconds.
+		Weight::from_parts(22_431_118, 0)
+			.saturating_add(Weight::from_parts(0, 3816))
+			// Standard Error: 13_587
+			.saturating_add(Weight::from_parts(95_112, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + n * (64 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 36_010_000 picoseconds.
+		Weight::from_parts(36_017_534, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			// Standard Error: 18_771
+			.saturating_add(Weight::from_parts(131_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Fallbacks` (r:1 w:1)
+	/// Proof: `Recovery::Fallbacks` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn set_recovery_fallback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3816`
+		// Minimum execution time: 19_839_000 picoseconds.
+		Weight::from_parts(19_846_000, 0)
+			.saturating_add(Weight::from_parts(0, 3816))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -41,10 +41,17 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegations = ConstU32<8>;
 	type MaxTurnout =
 		frame_support::traits::tokens::currency::ActiveIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
 	type BlockNumberProvider = System;
+	// Pool members keep their delegated stake on their own account. Until a pool slash is
+	// applied to them, it must not back their votes.
+	type VotingHooks = pallet_conviction_voting::ExcludeBalance<
+		pallet_nomination_pools::MemberPendingSlash<Runtime>,
+		pallet_nomination_pools::MemberPendingSlashWeight<Runtime>,
+	>;
 }
 
 parameter_types! {
@@ -750,6 +750,7 @@ impl pallet_staking::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Slash = ();
 	type Reward = ();
+	type SecondaryReward = ();
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
@@ -979,6 +980,7 @@ parameter_types! {
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type LeaseCurrency = Balances;
 	type Slashed = ();
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
@@ -1549,6 +1551,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
 	type BlockNumberProvider = System;
 	type Filter = Nothing;
+	type MaxAutoCompoundPerBlock = ConstU32<64>;
 }
 
 parameter_types! {
@@ -1577,7 +1580,10 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>;
+	type Migrations = (
+		pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_conviction_voting::migration::v1::LazyMigrationV0ToV1<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1586,6 +1592,7 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type MaxActiveMigrations = ConstU32<1>;
 	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
 }
 
@@ -2590,6 +2597,22 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda::runtime_api::ReferendaApi<Block, BlockNumber> for Runtime {
+		fn referendum_timeline(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Result<pallet_referenda::ReferendumTimeline<BlockNumber>, sp_runtime::DispatchError> {
+			Referenda::referendum_timeline(index)
+		}
+
+		fn passing_requirement(
+			index: pallet_referenda::ReferendumIndex,
+			at: BlockNumber,
+		) -> Result<Option<pallet_referenda::PassingRequirement>, sp_runtime::DispatchError> {
+			Referenda::passing_requirement(index, at)
+		}
+	}
+
+	#[api_version(3)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2602,6 +2625,28 @@ sp_api::impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn simulate_rewards(
+			bond: Balance,
+			targets: Vec<AccountId>,
+		) -> Vec<sp_staking::EraRewardProjection<AccountId, Balance>> {
+			Staking::api_simulate_rewards(bond, targets)
+		}
+
+		fn simulate_slash(
+			validator: AccountId,
+			era: sp_staking::EraIndex,
+			fraction: Perbill,
+		) -> Option<sp_staking::SlashSimulation<AccountId, Balance>> {
+			Staking::api_simulate_slash(validator, era, fraction)
+		}
+
+		fn recorded_offences(
+			start_after: Option<(sp_staking::EraIndex, AccountId)>,
+			limit: u32,
+		) -> Vec<(sp_staking::EraIndex, AccountId, Perbill)> {
+			Staking::api_recorded_offences(start_after, limit)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -875,4 +875,17 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(457))
 			.saturating_add(T::DbWeight::get().writes(261))
 	}
+	/// Westend sets `SecondaryReward = ()`, so no era has a `Staking::ErasSecondaryReward` and the
+	/// call always fails once it finds none. Only the reads up to that point are charged.
+	///
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Proof: `Staking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ErasStakersOverview` (r:1 w:0)
+	/// Proof: `Staking::ErasStakersOverview` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ErasSecondaryReward` (r:1 w:0)
+	/// Proof: `Staking::ErasSecondaryReward` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn payout_secondary_stakers(_n: u32, ) -> Weight {
+		Weight::from_parts(0, 5585)
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
 }
@@ -83,6 +83,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Freezer = ();
 	type Holder = ();
+	type Attestor = ();
 	type CallbackHandle = ();
 }
 
@@ -99,6 +100,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Freezer = ();
 	type Holder = ();
+	type Attestor = ();
 	type CallbackHandle = ();
 }
 
@@ -113,6 +113,7 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
@@ -115,6 +115,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		config,
 		None,
 		false,
+		None,
 		|_, _| (),
 	)
 	.expect("creating a full node doesn't fail")
@@ -102,6 +102,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 			config,
 			None,
 			false,
+			None,
 			|_, _| (),
 		)
 		.expect("Creates node")
@@ -43,6 +43,14 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Include the tasks offered by the runtime in authored blocks, up to this much reference
+	/// time per block, in picoseconds.
+	///
+	/// Only runtimes built with the `experimental` feature offer tasks. By default no tasks are
+	/// included.
+	#[arg(long, value_name = "PICOSECONDS")]
+	pub task_ref_time_limit: Option<u64>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
@@ -26,7 +26,7 @@ use crate::{
 };
 use frame_benchmarking_cli::*;
 use kitchensink_runtime::{ExistentialDeposit, RuntimeApi};
-use node_primitives::Block;
+use node_primitives::{AccountId, Balance, Block};
 use sc_cli::{Result, SubstrateCli};
 use sc_service::PartialComponents;
 use sp_keyring::Sr25519Keyring;
@@ -166,6 +166,11 @@ pub fn run() -> Result<()> {
 					},
 					BenchmarkCmd::Machine(cmd) =>
 						cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone()),
+					BenchmarkCmd::Slashing(cmd) => {
+						// ensure that we keep the task manager alive
+						let partial = new_partial(&config, None)?;
+						cmd.run::<Block, _, Balance, AccountId>(partial.client)
+					},
 				}
 			})
 		},
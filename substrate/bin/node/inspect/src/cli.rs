@@ -19,6 +19,7 @@
 //! Structs to easily compose inspect sub-command for CLI.
 
 use sc_cli::{ImportParams, SharedParams};
+use std::path::PathBuf;
 
 /// The `inspect` command used to print decoded chain data.
 #[derive(Debug, clap::Parser)]
@@ -59,4 +60,24 @@ pub enum InspectSubCmd {
 		#[arg(value_name = "BLOCK:INDEX or BYTES")]
 		input: String,
 	},
+	/// Compare the storage layout of two runtimes and check the local state against the new one.
+	///
+	/// Reports storage items that were added, removed or changed their type. Retyped items whose
+	/// pallet did not bump its storage version are flagged, since they likely miss a migration.
+	StorageDiff {
+		/// Path to the wasm blob of the currently deployed runtime.
+		#[arg(long, value_name = "PATH")]
+		old_runtime: PathBuf,
+		/// Path to the wasm blob of the runtime to upgrade to.
+		#[arg(long, value_name = "PATH")]
+		new_runtime: PathBuf,
+		/// Block at which the local state is checked. Defaults to the best block.
+		#[arg(long, value_name = "HASH or NUMBER")]
+		at: Option<String>,
+		/// Number of values per storage item to decode with the types of the new runtime.
+		///
+		/// Set to zero to skip checking the local state.
+		#[arg(long, default_value_t = 16)]
+		samples: usize,
+	},
 }
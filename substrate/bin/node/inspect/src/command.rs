@@ -20,11 +20,16 @@
 
 use crate::{
 	cli::{InspectCmd, InspectSubCmd},
-	Inspector,
+	storage_diff::StorageLayout,
+	BlockAddress, BlockAddressFor, Inspector,
 };
 use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::StorageProvider;
 use sc_service::Configuration;
-use sp_runtime::traits::Block;
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+use sp_runtime::{generic::BlockId, traits::Block};
+use std::path::Path;
 
 type HostFunctions =
 	(sp_io::SubstrateHostFunctions, sp_statement_store::runtime_api::HostFunctions);
@@ -38,25 +43,70 @@ impl InspectCmd {
 	{
 		let executor = sc_service::new_wasm_executor::<HostFunctions>(&config.executor);
 		let client = sc_service::new_full_client::<B, RA, _>(&config, None, executor)?;
-		let inspect = Inspector::<B>::new(client);
 
 		match &self.command {
 			InspectSubCmd::Block { input } => {
 				let input = input.parse()?;
-				let res = inspect.block(input).map_err(|e| e.to_string())?;
+				let res = Inspector::<B>::new(client).block(input).map_err(|e| e.to_string())?;
 				println!("{res}");
 				Ok(())
 			},
 			InspectSubCmd::Extrinsic { input } => {
 				let input = input.parse()?;
-				let res = inspect.extrinsic(input).map_err(|e| e.to_string())?;
+				let res =
+					Inspector::<B>::new(client).extrinsic(input).map_err(|e| e.to_string())?;
 				println!("{res}");
 				Ok(())
 			},
+			InspectSubCmd::StorageDiff { old_runtime, new_runtime, at, samples } => {
+				let old = load_storage_layout(old_runtime)?;
+				let new = load_storage_layout(new_runtime)?;
+				print!("{}", old.diff(&new));
+
+				if *samples == 0 {
+					return Ok(())
+				}
+
+				let at = match at.as_ref().map(|at| at.parse::<BlockAddressFor<B>>()).transpose()? {
+					None => client.info().best_hash,
+					Some(BlockAddress::Hash(hash)) => hash,
+					Some(BlockAddress::Number(number)) =>
+						client.expect_block_hash_from_id(&BlockId::number(number))?,
+					Some(BlockAddress::Bytes(_)) =>
+						return Err("Expected a block hash or number".into()),
+				};
+
+				let undecodable = new
+					.check_state(*samples, |prefix, limit| {
+						let prefix = StorageKey(prefix.to_vec());
+						let mut values = Vec::new();
+						for key in client.storage_keys(at, Some(&prefix), None)?.take(limit) {
+							if let Some(value) = client.storage(at, &key)? {
+								values.push((key.0, value.0));
+							}
+						}
+						Ok(values)
+					})
+					.map_err(|e| e.to_string())?;
+
+				if undecodable.is_empty() {
+					println!("All sampled values decode with the new runtime.");
+				}
+				for value in undecodable {
+					println!("Undecodable: {value}");
+				}
+				Ok(())
+			},
 		}
 	}
 }
 
+/// Read a runtime wasm blob from `path` and load its storage layout.
+fn load_storage_layout(path: &Path) -> Result<StorageLayout> {
+	let code = std::fs::read(path)?;
+	StorageLayout::from_code(&code).map_err(|e| e.to_string().into())
+}
+
 impl CliConfiguration for InspectCmd {
 	fn shared_params(&self) -> &SharedParams {
 		&self.shared_params
@@ -21,11 +21,15 @@
 //!
 //! The blocks and extrinsics can either be retrieved from the database (on-chain),
 //! or a raw SCALE-encoding can be provided.
+//!
+//! It can also compare the storage layout of two runtimes and check the local state against the
+//! new one, see [`storage_diff`].
 
 #![warn(missing_docs)]
 
 pub mod cli;
 pub mod command;
+pub mod storage_diff;
 
 use codec::{Decode, Encode};
 use sc_client_api::BlockBackend;
@@ -89,6 +93,9 @@ pub enum Error {
 	/// Given block has not been found.
 	#[error("{0}")]
 	NotFound(String),
+	/// Could not call into a runtime.
+	#[error("{0}")]
+	Runtime(String),
 }
 
 /// A helper trait to access block headers and bodies.
@@ -0,0 +1,686 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Compare the storage layout of two runtimes.
+//!
+//! The layout of every storage item is taken from the runtime metadata. Two items are considered
+//! equal if they use the same hashers and their key and value types have the same shape. Names of
+//! types, fields and variants as well as docs are ignored, since they do not influence the
+//! encoding.
+//!
+//! The storage version of each pallet is read from the default genesis state of the runtime, which
+//! contains the in-code storage version of every pallet.
+
+use crate::Error;
+use codec::{Compact, Decode};
+use sc_executor::WasmExecutor;
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
+use sp_core::{hexdisplay::HexDisplay, storage::Storage};
+use sp_crypto_hashing::{blake2_256, twox_128};
+use std::{
+	collections::{BTreeMap, HashMap},
+	fmt,
+};
+use subxt_metadata::{Metadata, StorageEntryType, StorageHasher};
+
+/// Postfix of the storage key under which FRAME stores the storage version of a pallet.
+const STORAGE_VERSION_STORAGE_KEY_POSTFIX: &[u8] = b":__STORAGE_VERSION__:";
+
+/// Nesting limit when decoding values, to not overflow the stack on malformed data.
+const MAX_DECODE_DEPTH: u32 = 256;
+
+/// The storage layout of a runtime.
+pub struct StorageLayout {
+	metadata: Metadata,
+	/// The in-code storage versions by pallet storage prefix.
+	///
+	/// `None` if the runtime could not build its default genesis state.
+	versions: Option<BTreeMap<String, u16>>,
+}
+
+impl StorageLayout {
+	/// Load the storage layout from a runtime wasm blob.
+	pub fn from_code(code: &[u8]) -> Result<Self, Error> {
+		let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder()
+			.with_allow_missing_host_functions(true)
+			.build();
+		let metadata =
+			sc_runtime_utilities::fetch_latest_metadata_from_code_blob(&executor, code.into())
+				.map_err(|e| Error::Runtime(format!("Could not fetch metadata: {e}")))?;
+		let metadata = Metadata::decode(&mut (*metadata).as_slice())?;
+
+		let caller = sc_chain_spec::GenesisConfigBuilderRuntimeCaller::<()>::new(code);
+		let genesis = caller
+			.get_default_config()
+			.and_then(|config| caller.get_storage_for_config(config))
+			.ok();
+
+		Ok(Self::new(metadata, genesis.as_ref()))
+	}
+
+	/// Create the storage layout from the metadata and the default genesis state of a runtime.
+	pub fn new(metadata: Metadata, genesis: Option<&Storage>) -> Self {
+		let versions = genesis.map(|genesis| {
+			metadata
+				.pallets()
+				.filter_map(|pallet| pallet.storage())
+				.filter_map(|storage| {
+					let key = storage_version_key(storage.prefix());
+					let version = genesis.top.get(&key)?;
+					Some((storage.prefix().to_owned(), u16::decode(&mut &version[..]).ok()?))
+				})
+				.collect()
+		});
+
+		Self { metadata, versions }
+	}
+
+	/// The storage version of the pallet with storage `prefix`, if known.
+	pub fn storage_version(&self, prefix: &str) -> Option<u16> {
+		self.versions.as_ref()?.get(prefix).copied()
+	}
+
+	/// Compare this layout with the one of the `new` runtime.
+	pub fn diff(&self, new: &StorageLayout) -> StorageDiff {
+		let old_items = self.items();
+		let new_items = new.items();
+		let mut old_hashes = TypeHasher::new(self.metadata.types());
+		let mut new_hashes = TypeHasher::new(new.metadata.types());
+
+		let mut changes = Vec::new();
+		for (name, old_item) in &old_items {
+			let Some(new_item) = new_items.get(name) else {
+				changes.push(StorageItemChange::new(name, StorageChange::Removed));
+				continue
+			};
+
+			if old_item.shape(&mut old_hashes) != new_item.shape(&mut new_hashes) {
+				let change = StorageChange::Retyped {
+					old_version: self.storage_version(&name.0),
+					new_version: new.storage_version(&name.0),
+				};
+				changes.push(StorageItemChange::new(name, change));
+			}
+		}
+		for name in new_items.keys().filter(|name| !old_items.contains_key(*name)) {
+			changes.push(StorageItemChange::new(name, StorageChange::Added));
+		}
+
+		StorageDiff { changes, versions_known: self.versions.is_some() && new.versions.is_some() }
+	}
+
+	/// Try to decode values of every storage item with the types of this layout.
+	///
+	/// `fetch` is called with the prefix of each storage item and must return up to `samples`
+	/// key-value pairs under that prefix.
+	pub fn check_state(
+		&self,
+		samples: usize,
+		mut fetch: impl FnMut(&[u8], usize) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Error>,
+	) -> Result<Vec<UndecodableValue>, Error> {
+		let registry = self.metadata.types();
+		let mut undecodable = Vec::new();
+
+		for ((pallet, item), layout) in self.items() {
+			let prefix = [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat();
+			for (key, value) in fetch(&prefix, samples)? {
+				// Plain items are stored right at the prefix, maps only below it.
+				if layout.hashers.is_empty() != (key.len() == prefix.len()) {
+					continue
+				}
+
+				if let Err(error) = decode_all_as_type(registry, layout.value_ty, &value) {
+					let (pallet, item) = (pallet.clone(), item.clone());
+					undecodable.push(UndecodableValue { pallet, item, key, error });
+				}
+			}
+		}
+
+		Ok(undecodable)
+	}
+
+	/// All storage items by their pallet prefix and name.
+	fn items(&self) -> BTreeMap<(String, String), ItemLayout> {
+		let mut items = BTreeMap::new();
+		for storage in self.metadata.pallets().filter_map(|pallet| pallet.storage()) {
+			for entry in storage.entries() {
+				let layout = match entry.entry_type() {
+					StorageEntryType::Plain(value_ty) =>
+						ItemLayout { hashers: Vec::new(), key_ty: None, value_ty: *value_ty },
+					StorageEntryType::Map { hashers, key_ty, value_ty } => ItemLayout {
+						hashers: hashers.clone(),
+						key_ty: Some(*key_ty),
+						value_ty: *value_ty,
+					},
+				};
+				items.insert((storage.prefix().to_owned(), entry.name().to_owned()), layout);
+			}
+		}
+		items
+	}
+}
+
+/// The parts of a storage item that determine its encoding.
+struct ItemLayout {
+	hashers: Vec<StorageHasher>,
+	key_ty: Option<u32>,
+	value_ty: u32,
+}
+
+impl ItemLayout {
+	fn shape(&self, hasher: &mut TypeHasher) -> (Vec<StorageHasher>, Option<[u8; 32]>, [u8; 32]) {
+		let key = self.key_ty.map(|ty| hasher.hash(ty));
+		(self.hashers.clone(), key, hasher.hash(self.value_ty))
+	}
+}
+
+/// How a storage item changed between two runtimes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageChange {
+	/// The item only exists in the new runtime.
+	Added,
+	/// The item only exists in the old runtime.
+	Removed,
+	/// The hashers, key or value type of the item changed.
+	Retyped {
+		/// The storage version of the pallet in the old runtime, if known.
+		old_version: Option<u16>,
+		/// The storage version of the pallet in the new runtime, if known.
+		new_version: Option<u16>,
+	},
+}
+
+/// A change of a single storage item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageItemChange {
+	/// The storage prefix of the pallet.
+	pub pallet: String,
+	/// The name of the storage item.
+	pub item: String,
+	/// How the item changed.
+	pub change: StorageChange,
+}
+
+impl StorageItemChange {
+	fn new((pallet, item): &(String, String), change: StorageChange) -> Self {
+		Self { pallet: pallet.clone(), item: item.clone(), change }
+	}
+
+	/// Whether the item changed its type without a bump of the pallet's storage version.
+	pub fn is_missing_version_bump(&self) -> bool {
+		matches!(
+			self.change,
+			StorageChange::Retyped { old_version: Some(old), new_version: Some(new) } if old == new
+		)
+	}
+}
+
+/// The differences between the storage layouts of two runtimes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageDiff {
+	/// All changed storage items, sorted by name with the added ones last.
+	pub changes: Vec<StorageItemChange>,
+	/// Whether the storage versions of both runtimes are known.
+	pub versions_known: bool,
+}
+
+impl StorageDiff {
+	/// The retyped storage items without a bump of their pallet's storage version.
+	pub fn missing_version_bumps(&self) -> impl Iterator<Item = &StorageItemChange> {
+		self.changes.iter().filter(|change| change.is_missing_version_bump())
+	}
+}
+
+impl fmt::Display for StorageDiff {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		if self.changes.is_empty() {
+			return writeln!(fmt, "Storage layout is unchanged.")
+		}
+		if !self.versions_known {
+			writeln!(fmt, "Storage versions are unknown: a runtime could not build its genesis.")?;
+		}
+
+		for change in &self.changes {
+			let StorageItemChange { pallet, item, change: kind } = change;
+			match kind {
+				StorageChange::Added => writeln!(fmt, "Added:   {pallet}::{item}")?,
+				StorageChange::Removed => writeln!(fmt, "Removed: {pallet}::{item}")?,
+				StorageChange::Retyped { old_version, new_version } => {
+					let version = |v: &Option<u16>| v.map_or("?".into(), |v| v.to_string());
+					write!(
+						fmt,
+						"Retyped: {pallet}::{item} (storage version {} -> {})",
+						version(old_version),
+						version(new_version),
+					)?;
+					if change.is_missing_version_bump() {
+						write!(fmt, " MISSING STORAGE VERSION BUMP")?;
+					}
+					writeln!(fmt)?;
+				},
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// A value in the state that does not decode with the type of its storage item.
+#[derive(Debug)]
+pub struct UndecodableValue {
+	/// The storage prefix of the pallet.
+	pub pallet: String,
+	/// The name of the storage item.
+	pub item: String,
+	/// The full storage key of the value.
+	pub key: Vec<u8>,
+	/// Why the value could not be decoded.
+	pub error: codec::Error,
+}
+
+impl fmt::Display for UndecodableValue {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		let Self { pallet, item, key, error } = self;
+		write!(fmt, "{pallet}::{item} at 0x{}: {error}", HexDisplay::from(key))
+	}
+}
+
+/// The storage key of the storage version of the pallet with storage `prefix`.
+fn storage_version_key(prefix: &str) -> Vec<u8> {
+	[twox_128(prefix.as_bytes()), twox_128(STORAGE_VERSION_STORAGE_KEY_POSTFIX)].concat()
+}
+
+/// Hashes the shape of types in a registry, ignoring the names of types, fields and variants as
+/// well as docs.
+struct TypeHasher<'a> {
+	registry: &'a PortableRegistry,
+	cache: HashMap<u32, [u8; 32]>,
+	/// The types that are currently being hashed, to detect recursion.
+	stack: Vec<u32>,
+}
+
+impl<'a> TypeHasher<'a> {
+	fn new(registry: &'a PortableRegistry) -> Self {
+		Self { registry, cache: HashMap::new(), stack: Vec::new() }
+	}
+
+	fn hash(&mut self, id: u32) -> [u8; 32] {
+		self.hash_inner(id).0
+	}
+
+	/// Returns the hash and whether it depends on a recursive type that was not resolved.
+	fn hash_inner(&mut self, id: u32) -> ([u8; 32], bool) {
+		if let Some(hash) = self.cache.get(&id) {
+			return (*hash, false)
+		}
+		let registry = self.registry;
+		let Some(ty) = registry.resolve(id) else { return (blake2_256(b"unknown"), false) };
+		if let Some(depth) = self.stack.iter().position(|t| *t == id) {
+			// Refer to recursive types by their distance on the stack, like a de Bruijn index.
+			let distance = (self.stack.len() - depth) as u32;
+			return (blake2_256(&[&b"recursive"[..], &distance.to_le_bytes()].concat()), true)
+		}
+
+		self.stack.push(id);
+		let mut recursive = false;
+		let mut shape = Vec::new();
+		let mut field = |hasher: &mut Self, shape: &mut Vec<u8>, ty: u32| {
+			let (hash, r) = hasher.hash_inner(ty);
+			recursive |= r;
+			shape.extend(hash);
+		};
+
+		match &ty.type_def {
+			TypeDef::Composite(composite) => {
+				shape.extend(b"composite");
+				shape.extend((composite.fields.len() as u32).to_le_bytes());
+				for f in &composite.fields {
+					field(self, &mut shape, f.ty.id);
+				}
+			},
+			TypeDef::Variant(variant) =>
+				for v in &variant.variants {
+					shape.extend(b"variant");
+					shape.push(v.index);
+					shape.extend((v.fields.len() as u32).to_le_bytes());
+					for f in &v.fields {
+						field(self, &mut shape, f.ty.id);
+					}
+				},
+			TypeDef::Sequence(sequence) => {
+				shape.extend(b"sequence");
+				field(self, &mut shape, sequence.type_param.id);
+			},
+			TypeDef::Array(array) => {
+				shape.extend(b"array");
+				shape.extend(array.len.to_le_bytes());
+				field(self, &mut shape, array.type_param.id);
+			},
+			TypeDef::Tuple(tuple) => {
+				shape.extend(b"tuple");
+				for f in &tuple.fields {
+					field(self, &mut shape, f.id);
+				}
+			},
+			TypeDef::Primitive(primitive) => shape.extend(format!("{primitive:?}").as_bytes()),
+			TypeDef::Compact(compact) => {
+				shape.extend(b"compact");
+				field(self, &mut shape, compact.type_param.id);
+			},
+			TypeDef::BitSequence(bits) => {
+				shape.extend(b"bits");
+				field(self, &mut shape, bits.bit_store_type.id);
+				field(self, &mut shape, bits.bit_order_type.id);
+			},
+		}
+		self.stack.pop();
+
+		let hash = blake2_256(&shape);
+		// Hashes of types in a cycle depend on where the cycle was entered.
+		if !recursive {
+			self.cache.insert(id, hash);
+		}
+		(hash, recursive)
+	}
+}
+
+/// Check that `value` is exactly one valid encoding of the type `id`.
+fn decode_all_as_type(
+	registry: &PortableRegistry,
+	id: u32,
+	value: &[u8],
+) -> Result<(), codec::Error> {
+	let mut input = value;
+	skip_type(registry, id, &mut input, 0)?;
+	if !input.is_empty() {
+		return Err("Trailing bytes after the value".into())
+	}
+	Ok(())
+}
+
+/// Advance `input` past one encoded value of the type `id`.
+fn skip_type(
+	registry: &PortableRegistry,
+	id: u32,
+	input: &mut &[u8],
+	depth: u32,
+) -> Result<(), codec::Error> {
+	if depth > MAX_DECODE_DEPTH {
+		return Err("Maximum nesting depth exceeded".into())
+	}
+	let ty = registry.resolve(id).ok_or("Unknown type id")?;
+	let depth = depth + 1;
+
+	match &ty.type_def {
+		TypeDef::Composite(composite) =>
+			for field in &composite.fields {
+				skip_type(registry, field.ty.id, input, depth)?;
+			},
+		TypeDef::Variant(variant) => {
+			let index = u8::decode(input)?;
+			let variant = variant
+				.variants
+				.iter()
+				.find(|v| v.index == index)
+				.ok_or("Unknown variant index")?;
+			for field in &variant.fields {
+				skip_type(registry, field.ty.id, input, depth)?;
+			}
+		},
+		TypeDef::Sequence(sequence) => {
+			let len = Compact::<u32>::decode(input)?.0;
+			skip_many(registry, sequence.type_param.id, len, input, depth)?;
+		},
+		TypeDef::Array(array) => skip_many(registry, array.type_param.id, array.len, input, depth)?,
+		TypeDef::Tuple(tuple) =>
+			for field in &tuple.fields {
+				skip_type(registry, field.id, input, depth)?;
+			},
+		TypeDef::Primitive(primitive) => skip_primitive(primitive, input)?,
+		TypeDef::Compact(compact) => skip_compact(registry, compact.type_param.id, input, depth)?,
+		TypeDef::BitSequence(bits) => {
+			let len = Compact::<u32>::decode(input)?.0 as usize;
+			let store = registry.resolve(bits.bit_store_type.id).ok_or("Unknown type id")?;
+			let store_bits = match &store.type_def {
+				TypeDef::Primitive(TypeDefPrimitive::U8) => 8,
+				TypeDef::Primitive(TypeDefPrimitive::U16) => 16,
+				TypeDef::Primitive(TypeDefPrimitive::U32) => 32,
+				TypeDef::Primitive(TypeDefPrimitive::U64) => 64,
+				_ => return Err("Unsupported bit store type".into()),
+			};
+			skip_bytes(input, len.div_ceil(store_bits) * store_bits / 8)?;
+		},
+	}
+
+	Ok(())
+}
+
+/// Advance `input` past one compact encoded value of the type `id`.
+///
+/// The value must fit into the integer that `id` wraps, as it would when decoding it.
+fn skip_compact(
+	registry: &PortableRegistry,
+	id: u32,
+	input: &mut &[u8],
+	depth: u32,
+) -> Result<(), codec::Error> {
+	if depth > MAX_DECODE_DEPTH {
+		return Err("Maximum nesting depth exceeded".into())
+	}
+	let ty = registry.resolve(id).ok_or("Unknown type id")?;
+
+	// Types with a single field, such as `Perbill`, are compact encoded like that field.
+	let inner = match &ty.type_def {
+		TypeDef::Primitive(primitive) => primitive,
+		TypeDef::Composite(composite) => match &composite.fields[..] {
+			[] => return Ok(()),
+			[field] => return skip_compact(registry, field.ty.id, input, depth + 1),
+			_ => return Err("Compact type with more than one field".into()),
+		},
+		TypeDef::Tuple(tuple) => match &tuple.fields[..] {
+			[] => return Ok(()),
+			[field] => return skip_compact(registry, field.id, input, depth + 1),
+			_ => return Err("Compact type with more than one field".into()),
+		},
+		_ => return Err("Unsupported compact type".into()),
+	};
+
+	match inner {
+		TypeDefPrimitive::U8 => {
+			Compact::<u8>::decode(input)?;
+		},
+		TypeDefPrimitive::U16 => {
+			Compact::<u16>::decode(input)?;
+		},
+		TypeDefPrimitive::U32 => {
+			Compact::<u32>::decode(input)?;
+		},
+		TypeDefPrimitive::U64 => {
+			Compact::<u64>::decode(input)?;
+		},
+		TypeDefPrimitive::U128 => {
+			Compact::<u128>::decode(input)?;
+		},
+		_ => return Err("Unsupported compact type".into()),
+	}
+	Ok(())
+}
+
+/// Advance `input` past `len` encoded values of the type `id`.
+fn skip_many(
+	registry: &PortableRegistry,
+	id: u32,
+	len: u32,
+	input: &mut &[u8],
+	depth: u32,
+) -> Result<(), codec::Error> {
+	// Fast path for byte vectors and arrays.
+	if let Some(TypeDef::Primitive(TypeDefPrimitive::U8)) =
+		registry.resolve(id).map(|ty| &ty.type_def)
+	{
+		return skip_bytes(input, len as usize)
+	}
+
+	for _ in 0..len {
+		skip_type(registry, id, input, depth)?;
+	}
+	Ok(())
+}
+
+fn skip_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<(), codec::Error> {
+	match primitive {
+		TypeDefPrimitive::Bool => {
+			bool::decode(input)?;
+		},
+		TypeDefPrimitive::Char => {
+			let c = u32::decode(input)?;
+			char::from_u32(c).ok_or("Invalid char")?;
+		},
+		TypeDefPrimitive::Str => {
+			String::decode(input)?;
+		},
+		TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => skip_bytes(input, 1)?,
+		TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => skip_bytes(input, 2)?,
+		TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => skip_bytes(input, 4)?,
+		TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => skip_bytes(input, 8)?,
+		TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => skip_bytes(input, 16)?,
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => skip_bytes(input, 32)?,
+	}
+	Ok(())
+}
+
+fn skip_bytes(input: &mut &[u8], len: usize) -> Result<(), codec::Error> {
+	if input.len() < len {
+		return Err("Not enough data to fill buffer".into())
+	}
+	*input = &input[len..];
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use scale_info::{meta_type, Registry, TypeInfo};
+
+	mod old {
+		#[derive(scale_info::TypeInfo, codec::Encode)]
+		pub struct Ledger {
+			pub stash: [u8; 32],
+			pub active: u64,
+			pub unlocking: Vec<(u32, u64)>,
+		}
+	}
+
+	mod new {
+		#[derive(scale_info::TypeInfo, codec::Encode)]
+		pub struct Ledger {
+			pub stash: [u8; 32],
+			pub active: u128,
+			pub unlocking: Vec<(u32, u128)>,
+		}
+
+		/// Same shape as `old::Ledger` with different field names.
+		#[derive(scale_info::TypeInfo)]
+		pub struct RenamedLedger {
+			pub controller: [u8; 32],
+			pub bonded: u64,
+			pub chunks: Vec<(u32, u64)>,
+		}
+
+		/// Same shape as `old::Ledger` under a different name.
+		#[derive(scale_info::TypeInfo)]
+		pub struct StakingLedger {
+			pub stash: [u8; 32],
+			pub active: u64,
+			pub unlocking: Vec<(u32, u64)>,
+		}
+	}
+
+	#[derive(TypeInfo, Encode)]
+	enum Tree {
+		Leaf(u32),
+		Node(Vec<Tree>),
+	}
+
+	fn registry_of<T: TypeInfo + 'static>() -> (PortableRegistry, u32) {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<T>()).id;
+		(registry.into(), id)
+	}
+
+	fn shape_of<T: TypeInfo + 'static>() -> [u8; 32] {
+		let (registry, id) = registry_of::<T>();
+		TypeHasher::new(&registry).hash(id)
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum Renamed {
+		Leaf { value: u32 },
+		Branch(Vec<Renamed>),
+	}
+
+	#[test]
+	fn type_shapes_ignore_names() {
+		assert_eq!(shape_of::<old::Ledger>(), shape_of::<new::StakingLedger>());
+		assert_eq!(shape_of::<old::Ledger>(), shape_of::<new::RenamedLedger>());
+		assert_ne!(shape_of::<old::Ledger>(), shape_of::<new::Ledger>());
+		assert_ne!(shape_of::<u32>(), shape_of::<Compact<u32>>());
+		assert_eq!(shape_of::<Tree>(), shape_of::<Tree>());
+		assert_eq!(shape_of::<Tree>(), shape_of::<Renamed>());
+	}
+
+	#[test]
+	fn compacts_are_checked_against_their_width() {
+		let (registry, id) = registry_of::<Compact<u8>>();
+		assert!(decode_all_as_type(&registry, id, &Compact(255u8).encode()).is_ok());
+		assert!(decode_all_as_type(&registry, id, &Compact(256u32).encode()).is_err());
+
+		let (registry, id) = registry_of::<Compact<sp_runtime::Perbill>>();
+		let value = Compact(sp_runtime::Perbill::from_percent(50));
+		assert!(decode_all_as_type(&registry, id, &value.encode()).is_ok());
+		assert!(decode_all_as_type(&registry, id, &Compact(u64::MAX).encode()).is_err());
+	}
+
+	#[test]
+	fn values_are_checked_against_their_type() {
+		let (registry, id) = registry_of::<new::Ledger>();
+		let ledger = new::Ledger { stash: [1; 32], active: 5, unlocking: vec![(1, 2), (3, 4)] };
+		assert!(decode_all_as_type(&registry, id, &ledger.encode()).is_ok());
+
+		let ledger = old::Ledger { stash: [1; 32], active: 5, unlocking: vec![(1, 2), (3, 4)] };
+		assert!(decode_all_as_type(&registry, id, &ledger.encode()).is_err());
+
+		let (registry, id) = registry_of::<Tree>();
+		let tree = Tree::Node(vec![Tree::Leaf(1), Tree::Node(vec![Tree::Leaf(2)])]);
+		assert!(decode_all_as_type(&registry, id, &tree.encode()).is_ok());
+		assert!(decode_all_as_type(&registry, id, &[2]).is_err());
+		assert!(decode_all_as_type(&registry, id, &[0, 1, 0, 0, 0, 0]).is_err());
+	}
+
+	#[test]
+	fn storage_version_key_matches_frame() {
+		// `twox_128(b"System") ++ twox_128(b":__STORAGE_VERSION__:")`
+		assert_eq!(
+			HexDisplay::from(&storage_version_key("System")).to_string(),
+			"26aa394eea5630e07c48ae0c9558cef7\
+			 4e7b9012096b41c4eb3aaf947f6ea429",
+		);
+	}
+}
@@ -855,6 +855,7 @@ impl pallet_staking::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type Slash = ResolveTo<TreasuryAccount, Balances>; // send the slashed funds to the treasury.
 	type Reward = (); // rewards are minted from the void
+	type SecondaryReward = ();
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
@@ -1241,6 +1242,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	>;
 	type BlockNumberProvider = System;
 	type Filter = Nothing;
+	type MaxAutoCompoundPerBlock = ConstU32<64>;
 }
 
 parameter_types! {
@@ -1253,9 +1255,16 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegations = ConstU32<8>;
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
 	type BlockNumberProvider = System;
+	// Pool members keep their delegated stake on their own account. Until a pool slash is
+	// applied to them, it must not back their votes.
+	type VotingHooks = pallet_conviction_voting::ExcludeBalance<
+		pallet_nomination_pools::MemberPendingSlash<Runtime>,
+		pallet_nomination_pools::MemberPendingSlashWeight<Runtime>,
+	>;
 }
 
 parameter_types! {
@@ -1431,6 +1440,7 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberWeight = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
@@ -1504,6 +1514,7 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type MaxProposals = TechnicalMaxProposals;
 	type MaxMembers = TechnicalMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberWeight = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
@@ -1882,6 +1893,7 @@ parameter_types! {
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type LeaseCurrency = Balances;
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type UsernameDeposit = UsernameDeposit;
@@ -2036,6 +2048,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
@@ -2064,6 +2077,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -2449,6 +2463,7 @@ impl pallet_collective::Config<AllianceCollective> for Runtime {
 	type MaxProposals = AllianceMaxProposals;
 	type MaxMembers = AllianceMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberWeight = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
@@ -2531,7 +2546,7 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = ();
+	type Migrations = pallet_conviction_voting::migration::v1::LazyMigrationV0ToV1<Runtime>;
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -2540,6 +2555,7 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type MaxActiveMigrations = ConstU32<4>;
 	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
 }
 
@@ -2599,6 +2615,8 @@ impl pallet_broker::Config for Runtime {
 	type MaxAutoRenewals = ConstU32<10>;
 	type PriceAdapter = pallet_broker::CenterTargetPrice<Balance>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
+	type MaxListingsPerTimeslice = ConstU32<50>;
+	type MaxSaleHistory = ConstU32<12>;
 }
 
 parameter_types! {
@@ -3108,6 +3126,9 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_collective::migrations::v5::MigrateToV5<Runtime, CouncilCollective>,
+	pallet_collective::migrations::v5::MigrateToV5<Runtime, TechnicalCollective>,
+	pallet_collective::migrations::v5::MigrateToV5<Runtime, AllianceCollective>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -3333,6 +3354,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_block_builder::TaskApi<Block> for Runtime {
+		fn task_extrinsics(weight_limit: Weight) -> Vec<<Block as BlockT>::Extrinsic> {
+			#[cfg(feature = "experimental")]
+			return frame_system::offchain::TaskExtrinsics::<Runtime>::all(weight_limit);
+			#[cfg(not(feature = "experimental"))]
+			{
+				let _ = weight_limit;
+				Vec::new()
+			}
+		}
+	}
+
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -3436,6 +3469,22 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda::runtime_api::ReferendaApi<Block, BlockNumber> for Runtime {
+		fn referendum_timeline(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Result<pallet_referenda::ReferendumTimeline<BlockNumber>, sp_runtime::DispatchError> {
+			Referenda::referendum_timeline(index)
+		}
+
+		fn passing_requirement(
+			index: pallet_referenda::ReferendumIndex,
+			at: BlockNumber,
+		) -> Result<Option<pallet_referenda::PassingRequirement>, sp_runtime::DispatchError> {
+			Referenda::passing_requirement(index, at)
+		}
+	}
+
+	#[api_version(3)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -3448,6 +3497,28 @@ impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn simulate_rewards(
+			bond: Balance,
+			targets: Vec<AccountId>,
+		) -> Vec<sp_staking::EraRewardProjection<AccountId, Balance>> {
+			Staking::api_simulate_rewards(bond, targets)
+		}
+
+		fn simulate_slash(
+			validator: AccountId,
+			era: sp_staking::EraIndex,
+			fraction: Perbill,
+		) -> Option<sp_staking::SlashSimulation<AccountId, Balance>> {
+			Staking::api_simulate_slash(validator, era, fraction)
+		}
+
+		fn recorded_offences(
+			start_after: Option<(sp_staking::EraIndex, AccountId)>,
+			limit: u32,
+		) -> Vec<(sp_staking::EraIndex, AccountId, Perbill)> {
+			Staking::api_recorded_offences(start_after, limit)
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -4109,6 +4180,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::HookSimulation<Block> for Runtime {
+		fn simulate_hooks(blocks: u32) -> Vec<frame_benchmarking::PalletHookWeights> {
+			Executive::simulate_hooks(blocks)
+				.into_iter()
+				.map(|(pallet, on_poll, on_idle)| frame_benchmarking::PalletHookWeights {
+					pallet: pallet.as_bytes().to_vec(),
+					on_poll,
+					on_idle,
+				})
+				.collect()
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -20,7 +20,7 @@
 
 // FIXME #1021 move this into sp-consensus
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use futures::{
 	channel::oneshot,
 	future,
@@ -30,14 +30,15 @@ use log::{debug, error, info, trace, warn};
 use sc_block_builder::{BlockBuilderApi, BlockBuilderBuilder};
 use sc_telemetry::{telemetry, TelemetryHandle, CONSENSUS_INFO};
 use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TxInvalidityReportMap};
-use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
+use sp_api::{ApiExt, CallApiAt, CallApiAtParams, ProvideRuntimeApi, RuntimeApiInfo};
+use sp_block_builder::TaskApi;
 use sp_blockchain::{ApplyExtrinsicFailed::Validity, Error::ApplyExtrinsicFailed, HeaderBackend};
 use sp_consensus::{DisableProofRecording, EnableProofRecording, ProofRecording, Proposal};
-use sp_core::traits::SpawnNamed;
+use sp_core::traits::{CallContext, SpawnNamed};
 use sp_inherents::InherentData;
 use sp_runtime::{
 	traits::{BlakeTwo256, Block as BlockT, Hash as HashT, Header as HeaderT},
-	Digest, ExtrinsicInclusionMode, Percent, SaturatedConversion,
+	Digest, ExtrinsicInclusionMode, Percent, SaturatedConversion, Weight,
 };
 use std::{marker::PhantomData, pin::Pin, sync::Arc, time};
 
@@ -82,6 +83,8 @@ pub struct ProposerFactory<A, C, PR> {
 	telemetry: Option<TelemetryHandle>,
 	/// When estimating the block size, should the proof be included?
 	include_proof_in_block_size_estimation: bool,
+	/// The weight budget for tasks offered by the runtime, if they should be included.
+	task_weight_limit: Option<Weight>,
 	/// phantom member to pin the `ProofRecording` type.
 	_phantom: PhantomData<PR>,
 }
@@ -97,6 +100,7 @@ impl<A, C, PR> Clone for ProposerFactory<A, C, PR> {
 			soft_deadline_percent: self.soft_deadline_percent,
 			telemetry: self.telemetry.clone(),
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			task_weight_limit: self.task_weight_limit,
 			_phantom: self._phantom,
 		}
 	}
@@ -123,6 +127,7 @@ impl<A, C> ProposerFactory<A, C, DisableProofRecording> {
 			telemetry,
 			client,
 			include_proof_in_block_size_estimation: false,
+			task_weight_limit: None,
 			_phantom: PhantomData,
 		}
 	}
@@ -151,6 +156,7 @@ impl<A, C> ProposerFactory<A, C, EnableProofRecording> {
 			soft_deadline_percent: DEFAULT_SOFT_DEADLINE_PERCENT,
 			telemetry,
 			include_proof_in_block_size_estimation: true,
+			task_weight_limit: None,
 			_phantom: PhantomData,
 		}
 	}
@@ -188,6 +194,17 @@ impl<A, C, PR> ProposerFactory<A, C, PR> {
 	pub fn set_soft_deadline(&mut self, percent: Percent) {
 		self.soft_deadline_percent = percent;
 	}
+
+	/// Set the weight budget for tasks offered by the runtime.
+	///
+	/// If set, the extrinsics executing the tasks returned by the runtime's [`TaskApi`] are
+	/// included right after the inherents, as long as their combined weight stays within
+	/// `limit`. Runtimes which do not implement the api are not affected.
+	///
+	/// By default no tasks are included.
+	pub fn set_task_weight_limit(&mut self, limit: Option<Weight>) {
+		self.task_weight_limit = limit;
+	}
 }
 
 impl<Block, C, A, PR> ProposerFactory<A, C, PR>
@@ -223,6 +240,7 @@ where
 			telemetry: self.telemetry.clone(),
 			_phantom: PhantomData,
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			task_weight_limit: self.task_weight_limit,
 		};
 
 		proposer
@@ -259,6 +277,7 @@ pub struct Proposer<Block: BlockT, C, A: TransactionPool, PR> {
 	include_proof_in_block_size_estimation: bool,
 	soft_deadline_percent: Percent,
 	telemetry: Option<TelemetryHandle>,
+	task_weight_limit: Option<Weight>,
 	_phantom: PhantomData<PR>,
 }
 
@@ -340,8 +359,14 @@ where
 
 		let mode = block_builder.extrinsic_inclusion_mode();
 		let end_reason = match mode {
-			ExtrinsicInclusionMode::AllExtrinsics =>
-				self.apply_extrinsics(&mut block_builder, deadline, block_size_limit).await?,
+			ExtrinsicInclusionMode::AllExtrinsics => {
+				if let Some(weight_limit) = self.task_weight_limit {
+					let block_size_limit =
+						block_size_limit.unwrap_or(self.default_block_size_limit);
+					self.apply_tasks(&mut block_builder, weight_limit, deadline, block_size_limit);
+				}
+				self.apply_extrinsics(&mut block_builder, deadline, block_size_limit).await?
+			},
 			ExtrinsicInclusionMode::OnlyInherents => EndProposingReason::TransactionForbidden,
 		};
 		let (block, storage_changes, proof) = block_builder.build()?.into_inner();
@@ -398,6 +423,88 @@ where
 		Ok(())
 	}
 
+	/// Apply the extrinsics executing the tasks offered by the runtime, up to `weight_limit`.
+	///
+	/// Stops at the first task which would exceed the deadline, the block size limit or the block
+	/// weight. Failures are logged and otherwise ignored, since tasks are not essential to the
+	/// block.
+	fn apply_tasks(
+		&self,
+		block_builder: &mut sc_block_builder::BlockBuilder<'_, Block, C>,
+		weight_limit: Weight,
+		deadline: time::Instant,
+		block_size_limit: usize,
+	) {
+		let tasks = match self.task_extrinsics(weight_limit) {
+			Ok(Some(tasks)) => tasks,
+			Ok(None) => return,
+			Err(e) => {
+				warn!(target: LOG_TARGET, "❗️ Failed to fetch tasks from the runtime: {}", e);
+				return
+			},
+		};
+
+		for task in tasks {
+			if (self.now)() > deadline {
+				debug!(target: LOG_TARGET, "Consensus deadline reached, not including further tasks.");
+				break
+			}
+
+			let block_size =
+				block_builder.estimate_block_size(self.include_proof_in_block_size_estimation);
+			if block_size + task.encoded_size() > block_size_limit {
+				debug!(
+					target: LOG_TARGET,
+					"Task would overflow the block size limit, not including further tasks."
+				);
+				break
+			}
+
+			match block_builder.push(task) {
+				Err(ApplyExtrinsicFailed(Validity(e))) if e.exhausted_resources() => {
+					debug!(target: LOG_TARGET, "Block is full, not including further tasks.");
+					break
+				},
+				Err(e) => {
+					debug!(target: LOG_TARGET, "Task extrinsic returned error: {}. Dropping.", e);
+				},
+				Ok(_) => {},
+			}
+		}
+	}
+
+	/// Fetch the extrinsics executing the tasks offered by the runtime at the parent block.
+	///
+	/// Returns `None` if the runtime does not implement [`TaskApi`].
+	fn task_extrinsics(
+		&self,
+		weight_limit: Weight,
+	) -> Result<Option<Vec<Block::Extrinsic>>, sp_blockchain::Error> {
+		let version = self.client.runtime_version_at(self.parent_hash)?;
+		if !version.has_api_with(&<dyn TaskApi<Block>>::ID, |_| true) {
+			return Ok(None)
+		}
+
+		// Called by name, so that the client is not required to implement the api.
+		let encoded = self.client.call_api_at(CallApiAtParams {
+			at: self.parent_hash,
+			function: "TaskApi_task_extrinsics",
+			arguments: weight_limit.encode(),
+			overlayed_changes: &Default::default(),
+			call_context: CallContext::Offchain,
+			recorder: &None,
+			extensions: &Default::default(),
+		})?;
+		let tasks = Vec::<Block::Extrinsic>::decode(&mut &encoded[..]).map_err(|e| {
+			sp_blockchain::Error::RuntimeApiError(sp_api::ApiError::FailedToDecodeReturnValue {
+				function: "TaskApi_task_extrinsics",
+				error: e,
+				raw: encoded.clone(),
+			})
+		})?;
+		Ok(Some(tasks))
+	}
+
 	/// Apply as many extrinsics as possible to the block.
 	async fn apply_extrinsics(
 		&self,
@@ -615,7 +722,7 @@ mod tests {
 	use sp_runtime::{generic::BlockId, traits::NumberFor, Perbill};
 	use substrate_test_runtime_client::{
 		prelude::*,
-		runtime::{Block as TestBlock, Extrinsic, ExtrinsicBuilder, Transfer},
+		runtime::{Block as TestBlock, Extrinsic, ExtrinsicBuilder, Transfer, TASK_KEY},
 		TestClientBuilder, TestClientBuilderExt,
 	};
 
@@ -698,6 +805,53 @@ mod tests {
 		assert_eq!(txpool.ready().count(), 2);
 	}
 
+	#[test]
+	fn should_include_tasks_offered_by_the_runtime() {
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let txpool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner.clone(),
+			client.clone(),
+		));
+
+		let hashof0 = client.info().genesis_hash;
+		block_on(txpool.submit_at(hashof0, SOURCE, vec![extrinsic(0)])).unwrap();
+		block_on(
+			txpool.maintain(chain_event(
+				client.expect_header(hashof0).expect("there should be header"),
+			)),
+		);
+
+		let mut proposer_factory =
+			ProposerFactory::new(spawner.clone(), client.clone(), txpool.clone(), None, None);
+		let mut propose = |task_weight_limit| {
+			proposer_factory.set_task_weight_limit(task_weight_limit);
+			let proposer = proposer_factory.init_with_now(
+				&client.expect_header(hashof0).unwrap(),
+				Box::new(time::Instant::now),
+			);
+			let deadline = time::Duration::from_secs(9);
+			block_on(proposer.propose(Default::default(), Default::default(), deadline, None))
+				.map(|r| r.block)
+				.unwrap()
+		};
+		let task = ExtrinsicBuilder::new_storage_change(TASK_KEY.to_vec(), Some(vec![1])).build();
+
+		// The task goes first, before the transactions.
+		let block = propose(Some(Weight::MAX));
+		assert_eq!(block.extrinsics().len(), 2);
+		assert_eq!(block.extrinsics()[0], task);
+
+		// Tasks are not included if they do not fit the weight limit, or by default.
+		let block = propose(Some(Weight::from_parts(1, u64::MAX)));
+		assert_eq!(block.extrinsics().len(), 1);
+		let block = propose(None);
+		assert_eq!(block.extrinsics().len(), 1);
+	}
+
 	#[test]
 	fn should_not_panic_when_deadline_is_reached() {
 		let client = Arc::new(substrate_test_runtime_client::new());
@@ -30,3 +30,24 @@ pub struct ReadProof<Hash> {
 	/// A proof used to prove that storage entries are included in the storage trie
 	pub proof: Vec<Bytes>,
 }
+
+/// The blocks followed by a view function subscription.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewFunctionFollow {
+	/// Re-evaluate the view function on each new best block.
+	#[default]
+	Best,
+	/// Re-evaluate the view function on each newly finalized block.
+	Finalized,
+}
+
+/// The result of a view function at a given block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewFunctionResult<Hash> {
+	/// Block hash at which the view function was executed
+	pub block: Hash,
+	/// SCALE encoded result of the view function
+	pub result: Bytes,
+}
@@ -28,7 +28,7 @@ use sp_version::RuntimeVersion;
 pub mod error;
 pub mod helpers;
 
-pub use self::helpers::ReadProof;
+pub use self::helpers::{ReadProof, ViewFunctionFollow, ViewFunctionResult};
 pub use error::Error;
 
 /// Substrate state API
@@ -38,6 +38,18 @@ pub trait StateApi<Hash> {
 	#[method(name = "state_call", aliases = ["state_callAt"], blocking)]
 	fn call(&self, name: String, bytes: Bytes, hash: Option<Hash>) -> Result<Bytes, Error>;
 
+	/// Execute a pallet view function at a block's state.
+	///
+	/// `id` is the 32 byte identifier of the view function, as found in the metadata, and `args`
+	/// are its SCALE encoded arguments. Returns the SCALE encoded result.
+	#[method(name = "state_callViewFunction", blocking)]
+	fn call_view_function(
+		&self,
+		id: Bytes,
+		args: Bytes,
+		hash: Option<Hash>,
+	) -> Result<Bytes, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	#[method(name = "state_getKeys", blocking)]
 	#[deprecated(since = "2.0.0", note = "Please use `getKeysPaged` with proper paging support")]
@@ -140,6 +152,18 @@ pub trait StateApi<Hash> {
 	)]
 	fn subscribe_storage(&self, keys: Option<Vec<StorageKey>>);
 
+	/// New view function result subscription.
+	///
+	/// Executes the view function `id` with the SCALE encoded `args` at the current best or
+	/// finalized block, as selected by `follow` (best by default). It is then re-executed on each
+	/// new best or finalized block and the result is only sent when it changed.
+	#[subscription(
+		name = "state_subscribeViewFunction" => "state_viewFunction",
+		unsubscribe = "state_unsubscribeViewFunction",
+		item = ViewFunctionResult<Hash>,
+	)]
+	fn subscribe_view_function(&self, id: Bytes, args: Bytes, follow: Option<ViewFunctionFollow>);
+
 	/// The `traceBlock` RPC provides a way to trace the re-execution of a single
 	/// block, collecting Spans and Events from both the client and the relevant WASM runtime.
 	/// The Spans and Events are conceptually equivalent to those from the [Tracing][1] crate.
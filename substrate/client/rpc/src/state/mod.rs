@@ -59,6 +59,14 @@ where
 		call_data: Bytes,
 	) -> Result<Bytes, Error>;
 
+	/// Execute a view function at given block.
+	fn call_view_function(
+		&self,
+		block: Option<Block::Hash>,
+		id: Bytes,
+		args: Bytes,
+	) -> Result<Bytes, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	fn storage_keys(
 		&self,
@@ -158,6 +166,15 @@ where
 		keys: Option<Vec<StorageKey>>,
 		deny_unsafe: DenyUnsafe,
 	);
+
+	/// New view function result subscription
+	fn subscribe_view_function(
+		&self,
+		pending: PendingSubscriptionSink,
+		id: Bytes,
+		args: Bytes,
+		follow: ViewFunctionFollow,
+	);
 }
 
 /// Create new state API that works on full node.
@@ -209,6 +226,15 @@ where
 		self.backend.call(block, method, data).map_err(Into::into)
 	}
 
+	fn call_view_function(
+		&self,
+		id: Bytes,
+		args: Bytes,
+		block: Option<Block::Hash>,
+	) -> Result<Bytes, Error> {
+		self.backend.call_view_function(block, id, args).map_err(Into::into)
+	}
+
 	fn storage_keys(
 		&self,
 		key_prefix: StorageKey,
@@ -341,6 +367,17 @@ where
 			.expect("DenyUnsafe extension is always set by the substrate rpc server; qed");
 		self.backend.subscribe_storage(pending, keys, deny_unsafe)
 	}
+
+	fn subscribe_view_function(
+		&self,
+		pending: PendingSubscriptionSink,
+		id: Bytes,
+		args: Bytes,
+		follow: Option<ViewFunctionFollow>,
+	) {
+		self.backend
+			.subscribe_view_function(pending, id, args, follow.unwrap_or_default())
+	}
 }
 
 /// Child state backend API.
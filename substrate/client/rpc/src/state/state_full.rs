@@ -23,13 +23,14 @@ use std::{collections::HashMap, marker::PhantomData, sync::Arc, time::Duration};
 use super::{
 	client_err,
 	error::{Error, Result},
-	ChildStateBackend, StateBackend,
+	ChildStateBackend, StateBackend, ViewFunctionFollow, ViewFunctionResult,
 };
 use crate::{
 	utils::{spawn_subscription_task, BoundedVecDeque, PendingSubscription},
 	DenyUnsafe, SubscriptionTaskExecutor,
 };
 
+use codec::{Decode, Encode};
 use futures::{future, stream, StreamExt};
 use jsonrpsee::{core::async_trait, types::ErrorObject, PendingSubscriptionSink};
 use sc_client_api::{
@@ -43,6 +44,7 @@ use sp_blockchain::{
 	Result as ClientResult,
 };
 use sp_core::{
+	hexdisplay::HexDisplay,
 	storage::{
 		ChildInfo, ChildType, PrefixedStorageKey, StorageChangeSet, StorageData, StorageKey,
 	},
@@ -55,6 +57,17 @@ use sp_version::RuntimeVersion;
 /// The maximum time allowed for an RPC call when running without unsafe RPC enabled.
 const MAXIMUM_SAFE_RPC_CALL_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// The runtime API method which executes view functions.
+const EXECUTE_VIEW_FUNCTION: &str = "RuntimeViewFunction_execute_view_function";
+
+/// Mirror of `ViewFunctionDispatchError` from `frame-support`, which the client does not depend on.
+#[derive(Decode)]
+enum ViewFunctionDispatchError {
+	NotImplemented,
+	NotFound([u8; 32]),
+	Codec,
+}
+
 /// Ranges to query in state_queryStorage.
 struct QueryStorageRange<Block: BlockT> {
 	/// Hashes of all the blocks in the range.
@@ -204,6 +217,17 @@ where
 			.map_err(client_err)
 	}
 
+	fn call_view_function(
+		&self,
+		block: Option<Block::Hash>,
+		id: Bytes,
+		args: Bytes,
+	) -> std::result::Result<Bytes, Error> {
+		let id = view_function_id(&id)?;
+		let block = self.block_or_best(block).map_err(client_err)?;
+		execute_view_function(&*self.client, block, &id, &args)
+	}
+
 	// TODO: This is horribly broken; either remove it, or make it streaming.
 	fn storage_keys(
 		&self,
@@ -466,6 +490,66 @@ where
 		);
 	}
 
+	fn subscribe_view_function(
+		&self,
+		pending: PendingSubscriptionSink,
+		id: Bytes,
+		args: Bytes,
+		follow: ViewFunctionFollow,
+	) {
+		let initial = view_function_id(&id).and_then(|id| {
+			let info = self.client.info();
+			let block = match follow {
+				ViewFunctionFollow::Best => info.best_hash,
+				ViewFunctionFollow::Finalized => info.finalized_hash,
+			};
+			let result = execute_view_function(&*self.client, block, &id, &args)?;
+			Ok((id, ViewFunctionResult { block, result }))
+		});
+		let (id, initial) = match initial {
+			Ok(initial) => initial,
+			Err(e) => {
+				spawn_subscription_task(&self.executor, pending.reject(e));
+				return
+			},
+		};
+
+		let blocks = match follow {
+			ViewFunctionFollow::Best => self
+				.client
+				.import_notification_stream()
+				.filter(|n| future::ready(n.is_new_best))
+				.map(|n| n.hash)
+				.boxed(),
+			ViewFunctionFollow::Finalized =>
+				self.client.finality_notification_stream().map(|n| n.hash).boxed(),
+		};
+
+		let mut previous_result = initial.result.clone();
+		let client = self.client.clone();
+
+		// A stream of changed results
+		let result_stream = blocks.filter_map(move |block| {
+			match execute_view_function(&*client, block, &id, &args) {
+				Ok(result) if result != previous_result => {
+					previous_result = result.clone();
+					future::ready(Some(ViewFunctionResult { block, result }))
+				},
+				Ok(_) => future::ready(None),
+				Err(e) => {
+					log::debug!(target: "rpc", "View function failed at block {block:?}: {e}");
+					future::ready(None)
+				},
+			}
+		});
+
+		let stream = stream::once(future::ready(initial)).chain(result_stream);
+		spawn_subscription_task(
+			&self.executor,
+			PendingSubscription::from(pending).pipe_from_stream(stream, BoundedVecDeque::default()),
+		);
+	}
+
 	fn trace_block(
 		&self,
 		block: Block::Hash,
@@ -647,3 +731,38 @@ fn invalid_block_range<B: BlockT>(
 fn invalid_block<B: BlockT>(from: B::Hash, to: Option<B::Hash>, details: String) -> Error {
 	Error::InvalidBlockRange { from: format!("{:?}", from), to: format!("{:?}", to), details }
 }
+
+fn view_function_id(id: &Bytes) -> Result<[u8; 32]> {
+	id.0.as_slice().try_into().map_err(|_| {
+		Error::ViewFunction(format!("expected a 32 byte view function id, got {} bytes", id.len()))
+	})
+}
+
+/// Execute the view function `id` with the SCALE encoded `args` at `block`.
+fn execute_view_function<Block, Client>(
+	client: &Client,
+	block: Block::Hash,
+	id: &[u8; 32],
+	args: &[u8],
+) -> Result<Bytes>
+where
+	Block: BlockT,
+	Client: ExecutorProvider<Block>,
+{
+	let call_data = (id, args).encode();
+	let output = client
+		.executor()
+		.call(block, EXECUTE_VIEW_FUNCTION, &call_data, CallContext::Offchain)
+		.map_err(client_err)?;
+
+	match std::result::Result::<Vec<u8>, ViewFunctionDispatchError>::decode(&mut &output[..]) {
+		Ok(Ok(result)) => Ok(result.into()),
+		Ok(Err(ViewFunctionDispatchError::NotImplemented)) =>
+			Err(Error::ViewFunction("view functions are not implemented by the runtime".into())),
+		Ok(Err(ViewFunctionDispatchError::NotFound(id))) =>
+			Err(Error::ViewFunction(format!("view function 0x{} not found", HexDisplay::from(&id)))),
+		Ok(Err(ViewFunctionDispatchError::Codec)) =>
+			Err(Error::ViewFunction("failed to decode the view function arguments".into())),
+		Err(e) => Err(Error::ViewFunction(format!("invalid runtime response: {e}"))),
+	}
+}
@@ -20,6 +20,7 @@ use self::error::Error;
 use super::*;
 use crate::testing::{allow_unsafe, test_executor, timeout_secs};
 use assert_matches::assert_matches;
+use codec::Encode;
 use futures::executor;
 use jsonrpsee::{core::EmptyServerParams as EmptyParams, MethodsError as RpcError};
 use sc_block_builder::BlockBuilderBuilder;
@@ -28,7 +29,7 @@ use sp_core::{hash::H256, storage::ChildInfo};
 use std::sync::Arc;
 use substrate_test_runtime_client::{
 	prelude::*,
-	runtime::{ExtrinsicBuilder, Transfer},
+	runtime::{get_storage_view_function_id, ExtrinsicBuilder, Transfer},
 };
 
 const STORAGE_KEY: &[u8] = b"child";
@@ -207,6 +208,117 @@ async fn should_call_contract() {
 	)
 }
 
+#[tokio::test]
+async fn should_reject_invalid_view_function_id() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let (api, _child) = new_full(client, test_executor());
+
+	assert_matches!(
+		api.call_view_function(Bytes(vec![0; 31]), Bytes(vec![]), None),
+		Err(Error::ViewFunction(_))
+	);
+}
+
+#[tokio::test]
+async fn should_reject_unknown_view_function() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.genesis_hash();
+	let (api, _child) = new_full(client, test_executor());
+
+	assert_matches!(
+		api.call_view_function(Bytes(vec![0; 32]), Bytes(vec![]), Some(genesis_hash)),
+		Err(Error::ViewFunction(_))
+	);
+}
+
+#[tokio::test]
+async fn should_call_view_function() {
+	const KEY: &[u8] = b":mock";
+	const VALUE: &[u8] = b"hello world";
+
+	let client = TestClientBuilder::new().add_extra_storage(KEY.to_vec(), VALUE.to_vec()).build();
+	let genesis_hash = client.genesis_hash();
+	let (api, _child) = new_full(Arc::new(client), test_executor());
+	let id = Bytes(get_storage_view_function_id().to_vec());
+
+	assert_eq!(
+		api.call_view_function(id.clone(), Bytes(KEY.to_vec().encode()), Some(genesis_hash))
+			.unwrap(),
+		Bytes(Some(VALUE.to_vec()).encode()),
+	);
+	assert_eq!(
+		api.call_view_function(id, Bytes(b":other".to_vec().encode()), Some(genesis_hash))
+			.unwrap(),
+		Bytes(None::<Vec<u8>>.encode()),
+	);
+}
+
+#[tokio::test]
+async fn should_notify_about_view_function_changes() {
+	const KEY: &[u8] = b":mock";
+	const VALUE: &[u8] = b"hello world";
+
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let (api, _child) = new_full(client.clone(), test_executor());
+	let mut api_rpc = api.into_rpc();
+	api_rpc.extensions_mut().insert(DenyUnsafe::No);
+
+	let mut sub = api_rpc
+		.subscribe_unbounded(
+			"state_subscribeViewFunction",
+			[Bytes(get_storage_view_function_id().to_vec()), Bytes(KEY.to_vec().encode())],
+		)
+		.await
+		.unwrap();
+
+	// The first block leaves the value untouched, the second one sets it.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().best_hash)
+		.with_parent_block_number(client.chain_info().best_number)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block).await.unwrap();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().best_hash)
+		.with_parent_block_number(client.chain_info().best_number)
+		.build()
+		.unwrap();
+	builder.push_storage_change(KEY.to_vec(), Some(VALUE.to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let changed_hash = block.header.hash();
+	client.import(BlockOrigin::Own, block).await.unwrap();
+
+	// The initial result is sent right away.
+	let (initial, _) = timeout_secs(1, sub.next::<ViewFunctionResult<H256>>())
+		.await
+		.unwrap()
+		.unwrap()
+		.unwrap();
+	assert_eq!(initial.block, client.genesis_hash());
+	assert_eq!(initial.result, Bytes(None::<Vec<u8>>.encode()));
+
+	// The unchanged result at the first block is skipped.
+	let (changed, _) = timeout_secs(1, sub.next::<ViewFunctionResult<H256>>())
+		.await
+		.unwrap()
+		.unwrap()
+		.unwrap();
+	assert_eq!(changed.block, changed_hash);
+	assert_eq!(changed.result, Bytes(Some(VALUE.to_vec()).encode()));
+
+	assert_matches!(timeout_secs(1, sub.next::<ViewFunctionResult<H256>>()).await, Err(_));
+}
+
 #[tokio::test]
 async fn should_notify_about_storage_changes() {
 	let mut sub = {
@@ -473,7 +585,8 @@ async fn should_return_runtime_version() {
 	// it is basically json-encoded substrate_test_runtime_client::runtime::VERSION
 	let result = "{\"specName\":\"test\",\"implName\":\"parity-test\",\"authoringVersion\":1,\
 		\"specVersion\":2,\"implVersion\":2,\"apis\":[[\"0xdf6acb689907609b\",5],\
-		[\"0x37e397fc7c91f5e4\",2],[\"0xd2bc9897eed08f15\",3],[\"0x40fe3ad401f8959a\",6],\
+		[\"0x37e397fc7c91f5e4\",2],[\"0xccd9de6396c899ca\",1],[\"0xd2bc9897eed08f15\",3],\
+		[\"0x40fe3ad401f8959a\",6],[\"0x426f359225537dc6\",1],\
 		[\"0xbc9d89904f5b923f\",1],[\"0xc6e9a76309f39b09\",2],[\"0xdd718d5cc53262d4\",1],\
 		[\"0xcbca25e39f142387\",2],[\"0xf78b278be53f454c\",2],[\"0xab3c0572291feb8b\",1],\
 		[\"0xed99c5acb25eedf5\",3],[\"0xfbc577b9d747efd6\",1]],\"transactionVersion\":1,\"systemVersion\":1,\
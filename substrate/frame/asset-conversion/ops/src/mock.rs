@@ -68,6 +68,7 @@ impl pallet_assets::Config<Instance1> for Test {
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 }
 
@@ -78,6 +79,7 @@ impl pallet_assets::Config<Instance2> for Test {
 		AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 }
 
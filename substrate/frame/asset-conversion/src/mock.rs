@@ -84,6 +84,7 @@ impl pallet_assets::Config<Instance1> for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
@@ -110,6 +111,7 @@ impl pallet_assets::Config<Instance2> for Test {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
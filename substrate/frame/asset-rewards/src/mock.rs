@@ -91,6 +91,7 @@ impl pallet_assets::Config<Instance1> for MockRuntime {
 	type StringLimit = ConstU32<50>;
 	type Freezer = AssetsFreezer;
 	type Holder = ();
+	type Attestor = ();
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
@@ -105,6 +105,7 @@ impl pallet_assets::Config for Test {
 	type CallbackHandle = ();
 	type Currency = Balances;
 	type Holder = ();
+	type Attestor = ();
 	type Freezer = AssetsFreezer;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
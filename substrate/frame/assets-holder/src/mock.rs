@@ -71,6 +71,7 @@ impl pallet_assets::Config for Test {
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Currency = Balances;
 	type Holder = AssetsHolder;
+	type Attestor = ();
 }
 
 #[derive(
@@ -73,6 +73,31 @@ pub fn create_default_minted_asset<T: Config<I>, I: 'static>(
 	(asset_id, caller, caller_lookup)
 }
 
+/// Puts the most restrictive transfer policy which `who` can satisfy on the default asset, so that
+/// crediting `who` checks every restriction of the policy.
+fn set_transfer_policy_for<T: Config<I>, I: 'static>(admin: &T::AccountId, who: &T::AccountId) {
+	let asset_id = default_asset_id::<T, I>();
+	// Attestation can only be required if the configured attestor already attests `who`.
+	let policy = TransferPolicy {
+		allow_list: true,
+		attestation: T::Attestor::is_attested(&asset_id.clone().into(), who),
+		max_holding: Some(T::Balance::max_value()),
+	};
+	let origin = SystemOrigin::Signed(admin.clone());
+	assert!(Assets::<T, I>::set_transfer_policy(
+		origin.clone().into(),
+		asset_id.clone(),
+		Some(policy)
+	)
+	.is_ok());
+	assert!(Assets::<T, I>::add_to_allow_list(
+		origin.into(),
+		asset_id,
+		T::Lookup::unlookup(who.clone())
+	)
+	.is_ok());
+}
+
 fn swap_is_sufficient<T: Config<I>, I: 'static>(s: &mut bool) {
 	let asset_id = default_asset_id::<T, I>();
 	Asset::<T, I>::mutate(&asset_id.into(), |maybe_a| {
@@ -225,6 +250,7 @@ benchmarks_instance_pallet! {
 
 	mint {
 		let (asset_id, caller, caller_lookup) = create_default_asset::<T, I>(true);
+		set_transfer_policy_for::<T, I>(&caller, &caller);
 		let amount = T::Balance::from(100u32);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, amount)
 	verify {
@@ -244,6 +270,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		set_transfer_policy_for::<T, I>(&caller, &target);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
@@ -255,6 +282,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, mint_amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		set_transfer_policy_for::<T, I>(&caller, &target);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, amount)
 	verify {
 		assert!(frame_system::Pallet::<T>::account_exists(&caller));
@@ -266,6 +294,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		set_transfer_policy_for::<T, I>(&caller, &target);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(
@@ -444,6 +473,7 @@ benchmarks_instance_pallet! {
 
 		let dest: T::AccountId = account("dest", 0, SEED);
 		let dest_lookup = T::Lookup::unlookup(dest.clone());
+		set_transfer_policy_for::<T, I>(&owner, &dest);
 	}: _(SystemOrigin::Signed(delegate.clone()), asset_id.clone(), owner_lookup, dest_lookup, amount)
 	verify {
 		assert!(T::Currency::reserved_balance(&owner).is_zero());
@@ -559,10 +589,46 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		set_transfer_policy_for::<T, I>(&caller, &target);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, false)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
 	}
 
+	set_transfer_policy {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let policy = TransferPolicy {
+			allow_list: true,
+			attestation: true,
+			max_holding: Some(100u32.into()),
+		};
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), Some(policy.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::TransferPolicySet { asset_id: asset_id.into(), policy }.into());
+	}
+
+	add_to_allow_list {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AllowListed { asset_id: asset_id.into(), who: target }.into());
+	}
+
+	remove_from_allow_list {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		Assets::<T, I>::add_to_allow_list(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id.clone(),
+			target_lookup.clone(),
+		)?;
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AllowListRemoved { asset_id: asset_id.into(), who: target }.into());
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -149,12 +149,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if increase_supply && details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
-		if let Some(account) = Account::<T, I>::get(id, who) {
+		let new_balance = if let Some(account) = Account::<T, I>::get(&id, who) {
 			if account.status.is_blocked() {
 				return DepositConsequence::Blocked
 			}
-			if account.balance.checked_add(&amount).is_none() {
-				return DepositConsequence::Overflow
+			match account.balance.checked_add(&amount) {
+				Some(new_balance) => new_balance,
+				None => return DepositConsequence::Overflow,
 			}
 		} else {
 			if amount < details.min_balance {
@@ -166,11 +167,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if details.is_sufficient && details.sufficients.checked_add(1).is_none() {
 				return DepositConsequence::Overflow
 			}
+			amount
+		};
+		if !Self::transfer_policy_permits(id, who, new_balance) {
+			return DepositConsequence::Blocked
 		}
 
 		DepositConsequence::Success
 	}
 
+	/// Returns `true` if the [`TransferPolicy`] of asset `id`, if any, allows `who` to end up with
+	/// a balance of `new_balance`, not counting any balance on hold.
+	pub(super) fn transfer_policy_permits(
+		id: T::AssetId,
+		who: &T::AccountId,
+		new_balance: T::Balance,
+	) -> bool {
+		let Some(policy) = TransferPolicies::<T, I>::get(&id) else { return true };
+		let allow_listed = policy.allow_list && AllowList::<T, I>::contains_key(&id, who);
+		let attested = policy.attestation && T::Attestor::is_attested(&id, who);
+		let held = if policy.max_holding.is_some() {
+			T::Holder::balance_on_hold(id, who).unwrap_or_default()
+		} else {
+			Zero::zero()
+		};
+		policy.permits(allow_listed, attested, &new_balance.saturating_add(held))
+	}
+
 	/// Return the consequence of a withdraw.
 	pub(super) fn can_decrease(
 		id: T::AssetId,
@@ -807,8 +830,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Destroy accounts associated with a given asset up to the max (T::RemoveItemsLimit).
 	///
+	/// When no accounts remain, the allow-list entries of the asset are removed as well, within the
+	/// same limit.
+	///
 	/// Each call emits the `Event::DestroyedAccounts` event.
-	/// Returns the number of destroyed accounts.
+	/// Returns the number of destroyed accounts and allow-list entries.
 	pub(super) fn do_destroy_accounts(
 		id: T::AssetId,
 		max_items: u32,
@@ -847,6 +873,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Ok(())
 			})?;
 
+		// Once all accounts are gone, use the remaining budget to clear the allow-list.
+		let mut removed_items = dead_accounts.len() as u32;
+		if remaining_accounts == 0 {
+			let budget = max_items.saturating_sub(removed_items) as usize;
+			for _ in AllowList::<T, I>::drain_prefix(&id).take(budget) {
+				removed_items.saturating_inc();
+			}
+		}
+
 		for who in &dead_accounts {
 			T::Freezer::died(id.clone(), &who);
 			T::Holder::died(id.clone(), &who);
@@ -857,7 +892,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			accounts_destroyed: dead_accounts.len() as u32,
 			accounts_remaining: remaining_accounts as u32,
 		});
-		Ok(dead_accounts.len() as u32)
+		Ok(removed_items)
 	}
 
 	/// Destroy approvals associated with a given asset up to the max (T::RemoveItemsLimit).
@@ -905,8 +940,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
 			ensure!(details.accounts == 0, Error::<T, I>::InUse);
 			ensure!(details.approvals == 0, Error::<T, I>::InUse);
+			ensure!(AllowList::<T, I>::iter_key_prefix(&id).next().is_none(), Error::<T, I>::InUse);
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
+			TransferPolicies::<T, I>::remove(&id);
 			let metadata = Metadata::<T, I>::take(&id);
 			T::Currency::unreserve(
 				&details.owner,
@@ -66,6 +66,8 @@
 //!   from the approving account into some third-party destination account.
 //! * **Sufficiency**: The idea of a minimum-balance of an asset being sufficient to allow the
 //!   account's existence on the system without requiring any other existential-deposit.
+//! * **Transfer policy**: Per-asset restrictions on which accounts may receive and hold an asset,
+//!   based on an allow-list, an attestation by [`Config::Attestor`] and a per-account holding cap.
 //!
 //! ### Goals
 //!
@@ -120,6 +122,12 @@
 //!   called by the asset class's Freezer or Admin.
 //! * `block`: Disallows further `transfer`s to and from an account; called by the asset class's
 //!   Freezer.
+//! * `set_transfer_policy`: Restricts which accounts may receive and hold an asset; called by the
+//!   asset class's Admin.
+//! * `add_to_allow_list`: Permits an account to receive an asset with an allow-list transfer
+//!   policy; called by the asset class's Admin.
+//! * `remove_from_allow_list`: Revokes the permission granted by `add_to_allow_list`; called by the
+//!   asset class's Admin.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -298,6 +306,7 @@ pub mod pallet {
 			type StringLimit = ConstU32<50>;
 			type Freezer = ();
 			type Holder = ();
+			type Attestor = ();
 			type Extra = ();
 			type CallbackHandle = ();
 			type WeightInfo = ();
@@ -400,6 +409,10 @@ pub mod pallet {
 		/// accordance with balance model.
 		type Holder: BalanceOnHold<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// A hook to decide whether an account is eligible to hold assets whose
+		/// [`TransferPolicy`] requires attestation.
+		type Attestor: HolderAttestation<Self::AssetId, Self::AccountId>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
@@ -453,6 +466,16 @@ pub mod pallet {
 		Approval<T::Balance, DepositBalanceOf<T, I>>,
 	>;
 
+	#[pallet::storage]
+	/// The transfer policy of a permissioned asset, if any.
+	pub type TransferPolicies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, TransferPolicy<T::Balance>>;
+
+	#[pallet::storage]
+	/// Accounts permitted to receive an asset whose [`TransferPolicy`] enables the allow-list.
+	pub type AllowList<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AccountId, ()>;
+
 	#[pallet::storage]
 	/// Metadata of an asset.
 	pub type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -646,6 +669,14 @@ pub mod pallet {
 		Deposited { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
 		/// Some assets were withdrawn from the account (e.g. for transaction fees).
 		Withdrawn { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
+		/// The transfer policy of an asset was set.
+		TransferPolicySet { asset_id: T::AssetId, policy: TransferPolicy<T::Balance> },
+		/// The transfer policy of an asset was removed.
+		TransferPolicyCleared { asset_id: T::AssetId },
+		/// Some account `who` was added to the allow-list of an asset.
+		AllowListed { asset_id: T::AssetId, who: T::AccountId },
+		/// Some account `who` was removed from the allow-list of an asset.
+		AllowListRemoved { asset_id: T::AssetId, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -699,6 +730,10 @@ pub mod pallet {
 		ContainsFreezes,
 		/// The asset cannot be destroyed because some accounts for this asset contain holds.
 		ContainsHolds,
+		/// The account is already in the allow-list of the asset.
+		AlreadyAllowListed,
+		/// The account is not in the allow-list of the asset.
+		NotAllowListed,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -1818,6 +1853,121 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Set or remove the transfer policy of an asset.
+		///
+		/// While a policy is in place, the balance of an account can only be increased if the
+		/// account satisfies every restriction of the policy. This applies to all credits of the
+		/// asset, including minting and transfers made by privileged accounts or other pallets.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `policy`: The new transfer policy, or `None` to lift all restrictions.
+		///
+		/// Emits `TransferPolicySet` or `TransferPolicyCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		pub fn set_transfer_policy(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			policy: Option<TransferPolicy<T::Balance>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
+			ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+
+			match policy {
+				Some(policy) => {
+					TransferPolicies::<T, I>::insert(&id, &policy);
+					Self::deposit_event(Event::<T, I>::TransferPolicySet { asset_id: id, policy });
+				},
+				None => {
+					TransferPolicies::<T, I>::remove(&id);
+					Self::deposit_event(Event::<T, I>::TransferPolicyCleared { asset_id: id });
+				},
+			}
+			Ok(())
+		}
+
+		/// Add an account to the allow-list of an asset.
+		///
+		/// The allow-list only has an effect while the [`TransferPolicy`] of the asset has
+		/// `allow_list` enabled.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be allowed to receive the asset.
+		///
+		/// Emits `AllowListed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(34)]
+		pub fn add_to_allow_list(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
+			ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!AllowList::<T, I>::contains_key(&id, &who), Error::<T, I>::AlreadyAllowListed);
+
+			AllowList::<T, I>::insert(&id, &who, ());
+			Self::deposit_event(Event::<T, I>::AllowListed { asset_id: id, who });
+			Ok(())
+		}
+
+		/// Remove an account from the allow-list of an asset.
+		///
+		/// This does not affect the existing balance of the account, which can still be
+		/// transferred away. Use `freeze` or `block` to also restrict it.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be removed from the allow-list.
+		///
+		/// Emits `AllowListRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(35)]
+		pub fn remove_from_allow_list(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
+			ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+			ensure!(AllowList::<T, I>::contains_key(&id, &who), Error::<T, I>::NotAllowListed);
+
+			AllowList::<T, I>::remove(&id, &who);
+			Self::deposit_event(Event::<T, I>::AllowListRemoved { asset_id: id, who });
+			Ok(())
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -104,6 +104,7 @@ impl Config for Test {
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Freezer = TestFreezer;
 	type Holder = TestHolder;
+	type Attestor = TestAttestor;
 	type CallbackHandle = (AssetsCallbackHandle, AutoIncAssetId<Test>);
 }
 
@@ -117,6 +118,18 @@ parameter_types! {
 	static Frozen: HashMap<(u32, u64), u64> = Default::default();
 	static OnHold: HashMap<(u32, u64), u64> = Default::default();
 	static Hooks: Vec<Hook> = Default::default();
+	static Attested: Vec<(u32, u64)> = Default::default();
+}
+
+pub struct TestAttestor;
+impl HolderAttestation<u32, u64> for TestAttestor {
+	fn is_attested(asset: &u32, who: &u64) -> bool {
+		Attested::get().contains(&(*asset, *who))
+	}
+}
+
+pub(crate) fn set_attested(asset: u32, who: u64) {
+	Attested::mutate(|v| v.push((asset, who)));
 }
 
 pub struct TestHolder;
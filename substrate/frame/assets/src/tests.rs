@@ -2180,3 +2180,144 @@ fn asset_id_cannot_be_reused() {
 		assert!(Asset::<Test>::contains_key(7));
 	});
 }
+
+#[test]
+fn transfer_policy_allow_list_should_work() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::fungibles::Mutate;
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+
+		let policy = TransferPolicy { allow_list: true, attestation: false, max_holding: None };
+		assert_noop!(
+			Assets::set_transfer_policy(RuntimeOrigin::signed(2), 0, Some(policy.clone())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, Some(policy.clone())));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferPolicySet {
+			asset_id: 0,
+			policy,
+		}));
+
+		// Nobody is allow-listed yet, so neither transfers nor mints can credit any account.
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10), TokenError::Blocked);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10), TokenError::Blocked);
+		assert_noop!(
+			<Assets as Mutate<_>>::transfer(0, &1, &3, 10, Expendable),
+			TokenError::Blocked
+		);
+		assert_noop!(<Assets as Mutate<_>>::mint_into(0, &3, 10), TokenError::Blocked);
+		// Existing holders which are not allow-listed can still send their funds away.
+		assert_ok!(Assets::add_to_allow_list(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 5));
+
+		assert_noop!(
+			Assets::add_to_allow_list(RuntimeOrigin::signed(2), 0, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::add_to_allow_list(RuntimeOrigin::signed(1), 0, 3));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AllowListed {
+			asset_id: 0,
+			who: 3,
+		}));
+		assert_noop!(
+			Assets::add_to_allow_list(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::AlreadyAllowListed
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10));
+		assert_ok!(<Assets as Mutate<_>>::mint_into(0, &3, 10));
+		assert_eq!(Assets::balance(0, 3), 20);
+
+		assert_ok!(Assets::remove_from_allow_list(RuntimeOrigin::signed(1), 0, 3));
+		assert_noop!(
+			Assets::remove_from_allow_list(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::NotAllowListed
+		);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10), TokenError::Blocked);
+
+		// Lifting the policy lifts all restrictions.
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, None));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferPolicyCleared {
+			asset_id: 0,
+		}));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10));
+	});
+}
+
+#[test]
+fn transfer_policy_attestation_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		let policy = TransferPolicy { allow_list: true, attestation: true, max_holding: None };
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, Some(policy)));
+
+		assert_eq!(Assets::can_increase(0, &2, 10, false), DepositConsequence::Blocked);
+		// Both restrictions must be satisfied.
+		set_attested(0, 2);
+		assert_eq!(Assets::can_increase(0, &2, 10, false), DepositConsequence::Blocked);
+		assert_ok!(Assets::add_to_allow_list(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(Assets::can_increase(0, &2, 10, false), DepositConsequence::Success);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+
+		// Attestation for one asset does not apply to another.
+		assert_ok!(Assets::add_to_allow_list(RuntimeOrigin::signed(1), 0, 3));
+		set_attested(1, 3);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10), TokenError::Blocked);
+	});
+}
+
+#[test]
+fn transfer_policy_max_holding_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
+
+		let policy =
+			TransferPolicy { allow_list: false, attestation: false, max_holding: Some(50) };
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, Some(policy)));
+
+		// Holdings above the cap at the time the policy is set are not affected.
+		assert_eq!(Assets::balance(0, 1), 80);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1), TokenError::Blocked);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 51), TokenError::Blocked);
+
+		// Balance on hold counts towards the cap.
+		set_balance_on_hold(0, 2, 10);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 10));
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 11), TokenError::Blocked);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+	});
+}
+
+#[test]
+fn destroy_clears_transfer_policy_and_allow_list() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		let policy = TransferPolicy { allow_list: true, attestation: false, max_holding: None };
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, Some(policy)));
+		for who in 1..=10 {
+			assert_ok!(Assets::add_to_allow_list(RuntimeOrigin::signed(1), 0, who));
+		}
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		// The first call removes the only account and clears part of the allow-list.
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_eq!(AllowList::<Test>::iter_prefix(0).count(), 6);
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0), Error::<Test>::InUse);
+
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_eq!(AllowList::<Test>::iter_prefix(0).count(), 0);
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(!TransferPolicies::<Test>::contains_key(0));
+	});
+}
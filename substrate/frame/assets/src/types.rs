@@ -274,6 +274,59 @@ impl<AssetId, AccountId, Balance> BalanceOnHold<AssetId, AccountId, Balance> for
 	}
 }
 
+/// Restrictions on which accounts may receive and hold a permissioned asset.
+///
+/// All enabled restrictions must be satisfied for an account's balance to be increased. The
+/// policy is checked for every credit of the asset, including mints, transfers and deposits made
+/// through the [`fungibles`] traits. It does not restrict debits, so an account which no longer
+/// satisfies the policy can still move its funds out unless it is frozen or blocked.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	Default,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct TransferPolicy<Balance> {
+	/// Only accounts present in the [`AllowList`] of the asset may receive it.
+	pub allow_list: bool,
+	/// Only accounts attested by [`Config::Attestor`] may receive the asset.
+	pub attestation: bool,
+	/// The maximum balance, including any balance on hold, that a single account may hold.
+	pub max_holding: Option<Balance>,
+}
+
+impl<Balance: PartialOrd> TransferPolicy<Balance> {
+	/// Returns `true` if an account with the given policy properties may end up holding
+	/// `new_balance`.
+	pub fn permits(&self, allow_listed: bool, attested: bool, new_balance: &Balance) -> bool {
+		(!self.allow_list || allow_listed) &&
+			(!self.attestation || attested) &&
+			self.max_holding.as_ref().map_or(true, |max| new_balance <= max)
+	}
+}
+
+/// Trait for attesting that an account is eligible to hold an asset, e.g. because it passed an
+/// identity verification or is registered in a permitted jurisdiction.
+///
+/// Consulted only for assets whose [`TransferPolicy`] has `attestation` enabled.
+pub trait HolderAttestation<AssetId, AccountId> {
+	/// Return `true` if `who` is permitted to receive and hold `asset`.
+	fn is_attested(asset: &AssetId, who: &AccountId) -> bool;
+}
+
+/// No account is attested, so assets requiring attestation cannot be received by anyone.
+impl<AssetId, AccountId> HolderAttestation<AssetId, AccountId> for () {
+	fn is_attested(_: &AssetId, _: &AccountId) -> bool {
+		false
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
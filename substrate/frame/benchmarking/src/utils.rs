@@ -229,6 +229,17 @@ pub struct BenchmarkMetadata {
 	pub pov_modes: Vec<(Vec<u8>, Vec<u8>)>,
 }
 
+/// The weight consumed by the `on_poll` and `on_idle` hooks of a pallet, see [`HookSimulation`].
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, TypeInfo)]
+pub struct PalletHookWeights {
+	/// The encoded name of the pallet in the runtime.
+	pub pallet: Vec<u8>,
+	/// The weight consumed by the `on_poll` hook.
+	pub on_poll: Weight,
+	/// The weight consumed by the `on_idle` hook.
+	pub on_idle: Weight,
+}
+
 sp_api::decl_runtime_apis! {
 	/// Runtime api for benchmarking a FRAME runtime.
 	#[api_version(2)]
@@ -243,6 +254,15 @@ sp_api::decl_runtime_apis! {
 		/// Dispatch the given benchmark.
 		fn dispatch_benchmark(config: BenchmarkConfig) -> Result<Vec<BenchmarkBatch>, alloc::string::String>;
 	}
+
+	/// Runtime api for simulating how the weight of blocks is distributed between the `on_poll`
+	/// and `on_idle` hooks of the pallets.
+	pub trait HookSimulation {
+		/// Simulate the hooks of `blocks` empty blocks following the current one.
+		///
+		/// Returns the weight consumed by the hooks of each pallet over all simulated blocks.
+		fn simulate_hooks(blocks: u32) -> Vec<PalletHookWeights>;
+	}
 }
 
 /// Interface that provides functions for benchmarking the runtime.
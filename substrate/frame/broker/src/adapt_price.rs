@@ -18,11 +18,14 @@
 #![deny(missing_docs)]
 
 use crate::{CoreIndex, SaleInfoRecord};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Get;
+use scale_info::TypeInfo;
 use sp_arithmetic::{traits::One, FixedU64};
-use sp_runtime::{FixedPointNumber, FixedPointOperand, Saturating};
+use sp_runtime::{FixedPointNumber, FixedPointOperand, Perbill, RuntimeDebug, Saturating};
 
 /// Performance of a past sale.
-#[derive(Copy, Clone)]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct SalePerformance<Balance> {
 	/// The price at which the last core was sold.
 	///
@@ -40,6 +43,9 @@ pub struct SalePerformance<Balance> {
 
 	/// Number of cores which have been sold; never more than cores_offered.
 	pub cores_sold: CoreIndex,
+
+	/// Number of the sold cores which went to renewals; never more than cores_sold.
+	pub cores_renewed: CoreIndex,
 }
 
 /// Result of `AdaptPrice::adapt_price`.
@@ -61,6 +67,8 @@ pub struct AdaptedPrices<Balance> {
 
 impl<Balance: Copy> SalePerformance<Balance> {
 	/// Construct performance via data from a `SaleInfoRecord`.
+	///
+	/// The sale record does not track renewals, so `cores_renewed` is left at zero.
 	pub fn from_sale<BlockNumber>(record: &SaleInfoRecord<Balance, BlockNumber>) -> Self {
 		Self {
 			sellout_price: record.sellout_price,
@@ -68,12 +76,20 @@ impl<Balance: Copy> SalePerformance<Balance> {
 			ideal_cores_sold: record.ideal_cores_sold,
 			cores_offered: record.cores_offered,
 			cores_sold: record.cores_sold,
+			cores_renewed: 0,
 		}
 	}
 
 	#[cfg(test)]
 	fn new(sellout_price: Option<Balance>, end_price: Balance) -> Self {
-		Self { sellout_price, end_price, ideal_cores_sold: 0, cores_offered: 0, cores_sold: 0 }
+		Self {
+			sellout_price,
+			end_price,
+			ideal_cores_sold: 0,
+			cores_offered: 0,
+			cores_sold: 0,
+			cores_renewed: 0,
+		}
 	}
 }
 
@@ -88,6 +104,20 @@ pub trait AdaptPrice<Balance> {
 	///
 	/// Based on the previous sale's performance.
 	fn adapt_price(performance: SalePerformance<Balance>) -> AdaptedPrices<Balance>;
+
+	/// Return adapted prices for next sale.
+	///
+	/// Based on the previous sale's `performance` and the performance of the sales before it,
+	/// given in `history` from oldest to most recent. The history is bounded and may be empty.
+	///
+	/// By default the history is ignored and this is the same as `adapt_price`.
+	fn adapt_price_with_history(
+		performance: SalePerformance<Balance>,
+		history: &[SalePerformance<Balance>],
+	) -> AdaptedPrices<Balance> {
+		let _ = history;
+		Self::adapt_price(performance)
+	}
 }
 
 impl<Balance: Copy> AdaptPrice<Balance> for () {
@@ -135,6 +165,71 @@ impl<Balance: FixedPointOperand> AdaptPrice<Balance> for CenterTargetPrice<Balan
 	}
 }
 
+/// Implementation of `AdaptPrice` which follows an exponential moving average of past sales.
+///
+/// The target price is the exponential moving average of the sellout prices of the recorded sales,
+/// where `Smoothing` is the weight given to each newer sale. A sale without a sellout price counts
+/// with its end price, so a lack of demand lowers the target price. Renewals are sold at their
+/// renewal price rather than at the market price, so the weight of a sale is scaled down by the
+/// share of its sold cores which were renewed. The end price is 1/10 of the target price and the
+/// leadin curve is that of `CenterTargetPrice`.
+///
+/// Should the average come out at zero, this falls back to `CenterTargetPrice`.
+pub struct EmaTargetPrice<Balance, Smoothing>(core::marker::PhantomData<(Balance, Smoothing)>);
+
+impl<Balance: FixedPointOperand, Smoothing: Get<Perbill>> AdaptPrice<Balance>
+	for EmaTargetPrice<Balance, Smoothing>
+{
+	fn leadin_factor_at(when: FixedU64) -> FixedU64 {
+		CenterTargetPrice::<Balance>::leadin_factor_at(when)
+	}
+
+	fn adapt_price(performance: SalePerformance<Balance>) -> AdaptedPrices<Balance> {
+		Self::adapt_price_with_history(performance, &[])
+	}
+
+	fn adapt_price_with_history(
+		performance: SalePerformance<Balance>,
+		history: &[SalePerformance<Balance>],
+	) -> AdaptedPrices<Balance> {
+		let smoothing = FixedU64::from_perbill(Smoothing::get());
+		let average = history.iter().chain(core::iter::once(&performance)).fold(
+			None,
+			|average: Option<Balance>, p| {
+				let price = p.sellout_price.unwrap_or(p.end_price);
+				let Some(average) = average else { return Some(price) };
+				let market_share = if p.cores_sold == 0 {
+					FixedU64::one()
+				} else {
+					let bought = p.cores_sold.saturating_sub(p.cores_renewed);
+					FixedU64::from_rational(bought.into(), p.cores_sold.into())
+				};
+				let weight = smoothing.saturating_mul(market_share);
+				// Move towards the newer price without rounding away a constant price.
+				Some(if price >= average {
+					average.saturating_add(weight.saturating_mul_int(price.saturating_sub(average)))
+				} else {
+					average.saturating_sub(weight.saturating_mul_int(average.saturating_sub(price)))
+				})
+			},
+		);
+
+		let Some(target_price) = average.filter(|p| !p.is_zero()) else {
+			return CenterTargetPrice::<Balance>::adapt_price(performance)
+		};
+
+		let end_price = FixedU64::from_rational(1, 10).saturating_mul_int(target_price);
+		let end_price = if end_price.is_zero() {
+			// We could not recover from a price equal 0 ever.
+			target_price
+		} else {
+			end_price
+		};
+
+		AdaptedPrices { end_price, target_price }
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -240,4 +335,76 @@ mod tests {
 		let prices = CenterTargetPrice::adapt_price(performance);
 		assert_eq!(prices.target_price, 1000);
 	}
+
+	frame_support::parameter_types! {
+		pub const HalfSmoothing: Perbill = Perbill::from_percent(50);
+	}
+	type Ema = EmaTargetPrice<u64, HalfSmoothing>;
+
+	#[test]
+	fn ema_without_history_follows_last_sale() {
+		let prices = Ema::adapt_price(SalePerformance::new(Some(1000), 100));
+		assert_eq!(prices.target_price, 1000);
+		assert_eq!(prices.end_price, 100);
+	}
+
+	#[test]
+	fn ema_averages_history() {
+		let history =
+			[SalePerformance::new(Some(4000), 100), SalePerformance::new(Some(2000), 100)];
+		// Halfway from 4000 to 2000 is 3000, halfway from there to 1000 is 2000.
+		let prices = Ema::adapt_price_with_history(SalePerformance::new(Some(1000), 100), &history);
+		assert_eq!(prices.target_price, 2000);
+		assert_eq!(prices.end_price, 200);
+	}
+
+	#[test]
+	fn ema_counts_sales_without_sellout_at_end_price() {
+		let history = [SalePerformance::new(Some(4000), 100), SalePerformance::new(None, 1000)];
+		// Halfway from 4000 to 1000 is 2500, halfway from there to 2000 is 2250.
+		let prices = Ema::adapt_price_with_history(SalePerformance::new(Some(2000), 100), &history);
+		assert_eq!(prices.target_price, 2250);
+
+		// Sales without demand keep lowering the price.
+		let history = [SalePerformance::new(Some(4000), 100), SalePerformance::new(None, 400)];
+		let prices = Ema::adapt_price_with_history(SalePerformance::new(None, 400), &history);
+		assert_eq!(prices.target_price, 1300);
+	}
+
+	#[test]
+	fn ema_discounts_renewals() {
+		let history = [SalePerformance::new(Some(4000), 100)];
+		let sale = |cores_sold, cores_renewed| SalePerformance {
+			cores_sold,
+			cores_renewed,
+			..SalePerformance::new(Some(2000), 100)
+		};
+
+		// Only cores bought on the market.
+		let prices = Ema::adapt_price_with_history(sale(2, 0), &history);
+		assert_eq!(prices.target_price, 3000);
+		// Half of the cores were renewed, so the sale only has half the weight.
+		let prices = Ema::adapt_price_with_history(sale(2, 1), &history);
+		assert_eq!(prices.target_price, 3500);
+		// Renewals alone say nothing about the market price.
+		let prices = Ema::adapt_price_with_history(sale(2, 2), &history);
+		assert_eq!(prices.target_price, 4000);
+	}
+
+	#[test]
+	fn ema_price_never_goes_to_zero() {
+		let history = [SalePerformance::new(Some(1), 1); 5];
+		let prices = Ema::adapt_price_with_history(SalePerformance::new(Some(1), 1), &history);
+		assert_eq!(prices.target_price, 1);
+		assert_eq!(prices.end_price, 1);
+	}
+
+	#[test]
+	fn default_adapt_price_with_history_ignores_history() {
+		let history = [SalePerformance::new(Some(10_000), 100)];
+		let performance = SalePerformance::new(Some(1000), 100);
+		let prices = CenterTargetPrice::adapt_price_with_history(performance, &history);
+		assert_eq!(prices.target_price, 1000);
+		assert_eq!(prices.end_price, 100);
+	}
 }
@@ -342,6 +342,15 @@ mod benches {
 
 		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
 			.expect("Offer not high enough for configuration.");
+		// Dropping a listing which lapses early is the worst case.
+		Broker::<T>::do_list_region(
+			region,
+			caller.clone(),
+			1_000u32.into(),
+			None,
+			Some(region.begin + 2),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
 
 		let recipient: T::AccountId = account("recipient", 0, SEED);
 
@@ -375,6 +384,15 @@ mod benches {
 
 		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
 			.expect("Offer not high enough for configuration.");
+		// Dropping a listing which lapses early is the worst case.
+		Broker::<T>::do_list_region(
+			region,
+			caller.clone(),
+			1_000u32.into(),
+			None,
+			Some(region.begin + 2),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), region, 2);
@@ -407,6 +425,15 @@ mod benches {
 
 		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
 			.expect("Offer not high enough for configuration.");
+		// Dropping a listing which lapses early is the worst case.
+		Broker::<T>::do_list_region(
+			region,
+			caller.clone(),
+			1_000u32.into(),
+			None,
+			Some(region.begin + 2),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), region, 0x00000_fffff_fffff_00000.into());
@@ -443,6 +470,15 @@ mod benches {
 
 		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
 			.expect("Offer not high enough for configuration.");
+		// Dropping a listing which lapses early is the worst case.
+		Broker::<T>::do_list_region(
+			region,
+			caller.clone(),
+			1_000u32.into(),
+			None,
+			Some(region.begin + 2),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), region, 1000, Provisional);
@@ -478,6 +514,15 @@ mod benches {
 
 		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
 			.expect("Offer not high enough for configuration.");
+		// Dropping a listing which lapses early is the worst case.
+		Broker::<T>::do_list_region(
+			region,
+			caller.clone(),
+			1_000u32.into(),
+			None,
+			Some(region.begin + 2),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
 
 		let recipient: T::AccountId = account("recipient", 0, SEED);
 
@@ -604,6 +649,8 @@ mod benches {
 
 		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
 			.expect("Offer not high enough for configuration.");
+		Broker::<T>::do_list_region(region, caller.clone(), 1_000u32.into(), None, None)
+			.map_err(|_| BenchmarkError::Weightless)?;
 
 		advance_to::<T>(
 			(T::TimeslicePeriod::get() * (region_len * 4).into()).try_into().ok().unwrap(),
@@ -875,13 +922,29 @@ mod benches {
 		Broker::<T>::process_revenue();
 		status.last_committed_timeslice = config.region_length;
 
+		// Fill up the sale history for the worst case.
+		let record = SaleHistoryRecord {
+			region_begin: sale.region_begin,
+			region_end: sale.region_end,
+			performance: SalePerformance::from_sale(&sale),
+		};
+		SaleHistory::<T>::put(BoundedVec::truncate_from(vec![
+			record;
+			T::MaxSaleHistory::get() as usize
+		]));
+		let performance = SalePerformance {
+			cores_renewed: CoresRenewed::<T>::get(),
+			..SalePerformance::from_sale(&sale)
+		};
+		let past: Vec<_> = SaleHistory::<T>::get().iter().map(|r| r.performance).collect();
+
 		#[block]
 		{
 			Broker::<T>::rotate_sale(sale.clone(), &config, &status);
 		}
 
 		// Get prices from the actual price adapter.
-		let new_prices = T::PriceAdapter::adapt_price(SalePerformance::from_sale(&sale));
+		let new_prices = T::PriceAdapter::adapt_price_with_history(performance, &past);
 		let new_sale = SaleInfo::<T>::get().expect("Sale has started.");
 		let now = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
 		let sale_start = config.interlude_length.saturating_add(rotate_block.into());
@@ -1252,6 +1315,128 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn list_region() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
+			.expect("Offer not high enough for configuration.");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, 1_000u32.into(), Some(1), None);
+
+		assert!(Listings::<T>::contains_key(region));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlist_region() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
+			.expect("Offer not high enough for configuration.");
+		Broker::<T>::do_list_region(region, caller.clone(), 1_000u32.into(), Some(1), None)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region);
+
+		assert_last_event::<T>(Event::Unlisted { region_id: region }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn purchase_region() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let seller: T::AccountId = account("seller", 0, SEED);
+		T::Currency::set_balance(
+			&seller.clone(),
+			T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(seller.clone(), 10_000_000u32.into())
+			.expect("Offer not high enough for configuration.");
+		// Selling only part of the Region is the worst case.
+		Broker::<T>::do_list_region(region, seller.clone(), 1_000u32.into(), Some(1), None)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, 1_000u32.into());
+
+		assert_last_event::<T>(
+			Event::RegionSold {
+				region_id: region,
+				seller,
+				buyer: caller,
+				price: 1_000u32.into(),
+				remainder: Some(RegionId { begin: region.begin + 1, ..region }),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn process_listing_expiries(
+		n: Linear<0, { T::MaxListingsPerTimeslice::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let expiry = 10u32;
+
+		for core in 0..n {
+			let region_id =
+				RegionId { begin: 5, core: core as CoreIndex, mask: CoreMask::complete() };
+			Listings::<T>::insert(
+				&region_id,
+				ListingRecord {
+					seller: seller.clone(),
+					price: 1_000u32.into(),
+					region_end: expiry,
+					pivot: None,
+					expiry,
+				},
+			);
+			ListingExpiries::<T>::insert(expiry, region_id, ());
+		}
+
+		#[block]
+		{
+			Broker::<T>::process_listing_expiries(expiry - 1);
+		}
+
+		assert_eq!(Listings::<T>::iter().count(), 0);
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-broker --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use super::*;
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
@@ -186,6 +187,7 @@ impl<T: Config> Pallet<T> {
 		let old_core = core;
 
 		let core = Self::purchase_core(&who, record.price, &mut sale)?;
+		CoresRenewed::<T>::mutate(|n| n.saturating_inc());
 
 		Self::deposit_event(Event::Renewed {
 			who,
@@ -234,6 +236,8 @@ impl<T: Config> Pallet<T> {
 		let old_owner = region.owner;
 		region.owner = Some(new_owner);
 		Regions::<T>::insert(&region_id, &region);
+		// A listing made by the previous owner must not outlive their ownership.
+		Self::remove_listing(&region_id);
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::Transferred {
 			region_id,
@@ -245,6 +249,93 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_list_region(
+		region_id: RegionId,
+		seller: T::AccountId,
+		price: BalanceOf<T>,
+		pivot: Option<Timeslice>,
+		maybe_expiry: Option<Timeslice>,
+	) -> Result<(), Error<T>> {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(region.owner.as_ref() == Some(&seller), Error::<T>::NotOwner);
+
+		let offer_end = match pivot {
+			Some(pivot_offset) => {
+				let pivot = region_id.begin.saturating_add(pivot_offset);
+				ensure!(pivot < region.end, Error::<T>::PivotTooLate);
+				ensure!(pivot > region_id.begin, Error::<T>::PivotTooEarly);
+				pivot
+			},
+			None => region.end,
+		};
+		let expiry = maybe_expiry.map_or(offer_end, |e| e.min(offer_end));
+		ensure!(
+			status.last_committed_timeslice.saturating_add(1) < expiry,
+			Error::<T>::ListingExpired
+		);
+
+		Self::remove_listing(&region_id);
+		let listing = ListingRecord { seller, price, region_end: region.end, pivot, expiry };
+		ListingExpiries::<T>::insert(expiry, &region_id, ());
+		Listings::<T>::insert(&region_id, &listing);
+
+		Self::deposit_event(Event::Listed {
+			region_id,
+			seller: listing.seller,
+			price,
+			pivot,
+			expiry,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_unlist_region(region_id: RegionId, who: T::AccountId) -> Result<(), Error<T>> {
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		ensure!(listing.seller == who, Error::<T>::NotOwner);
+
+		Self::remove_listing(&region_id);
+		Self::deposit_event(Event::Unlisted { region_id });
+		Ok(())
+	}
+
+	pub(crate) fn do_purchase_region(
+		region_id: RegionId,
+		buyer: T::AccountId,
+		max_price: BalanceOf<T>,
+	) -> DispatchResult {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		ensure!(
+			status.last_committed_timeslice.saturating_add(1) < listing.expiry,
+			Error::<T>::ListingExpired
+		);
+		ensure!(listing.price <= max_price, Error::<T>::Overpriced);
+
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::ListingOutdated)?;
+		ensure!(
+			region.owner.as_ref() == Some(&listing.seller) && region.end == listing.region_end,
+			Error::<T>::ListingOutdated
+		);
+
+		T::Currency::transfer(&buyer, &listing.seller, listing.price, Expendable)?;
+
+		let remainder = match listing.pivot {
+			Some(pivot) => Some(Self::do_partition(region_id, None, pivot)?.1),
+			None => None,
+		};
+		Self::do_transfer(region_id, None, buyer.clone())?;
+
+		Self::deposit_event(Event::RegionSold {
+			region_id,
+			seller: listing.seller,
+			buyer,
+			price: listing.price,
+			remainder,
+		});
+		Ok(())
+	}
+
 	pub(crate) fn do_partition(
 		region_id: RegionId,
 		maybe_check_owner: Option<T::AccountId>,
@@ -260,6 +351,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(pivot > region_id.begin, Error::<T>::PivotTooEarly);
 
 		region.paid = None;
+		Self::remove_listing(&region_id);
 		let new_region_ids = (region_id, RegionId { begin: pivot, ..region_id });
 
 		Regions::<T>::insert(&new_region_ids.0, &RegionRecord { end: pivot, ..region.clone() });
@@ -286,6 +378,7 @@ impl<T: Config> Pallet<T> {
 
 		// The old region should be removed.
 		Regions::<T>::remove(&region_id);
+		Self::remove_listing(&region_id);
 
 		let one = RegionId { mask: pivot, ..region_id };
 		Regions::<T>::insert(&one, &region);
@@ -457,6 +550,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(status.last_committed_timeslice >= region.end, Error::<T>::StillValid);
 
 		Regions::<T>::remove(&region_id);
+		Self::remove_listing(&region_id);
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::RegionDropped { region_id, duration });
 		Ok(())
@@ -611,4 +705,9 @@ impl<T: Config> Pallet<T> {
 		let now = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
 		Ok(Self::sale_price(&sale, now))
 	}
+
+	/// Returns the recorded history of past sales, oldest first.
+	pub fn sale_history() -> Vec<SaleHistoryRecordOf<T>> {
+		SaleHistory::<T>::get().into_inner()
+	}
 }
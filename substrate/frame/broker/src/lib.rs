@@ -130,6 +130,15 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.
 		#[pallet::constant]
 		type MinimumCreditPurchase: Get<BalanceOf<Self>>;
+
+		/// Maximum number of lapsed Region listings which are cleared when a timeslice is
+		/// committed. Any others are cleared as the following timeslices are committed.
+		#[pallet::constant]
+		type MaxListingsPerTimeslice: Get<u32>;
+
+		/// Maximum number of past sales kept in the sale history given to the `PriceAdapter`.
+		#[pallet::constant]
+		type MaxSaleHistory: Get<u32>;
 	}
 
 	/// The current configuration of this pallet.
@@ -201,6 +210,29 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RevenueInbox<T> = StorageValue<_, OnDemandRevenueRecordOf<T>, OptionQuery>;
 
+	/// Regions, or leading parts of them, which are offered for sale by their owners.
+	#[pallet::storage]
+	pub type Listings<T> =
+		StorageMap<_, Blake2_128Concat, RegionId, ListingRecordOf<T>, OptionQuery>;
+
+	/// The listings which lapse once all timeslices before the given one have been committed.
+	#[pallet::storage]
+	pub type ListingExpiries<T> =
+		StorageDoubleMap<_, Twox64Concat, Timeslice, Blake2_128Concat, RegionId, (), OptionQuery>;
+
+	/// The earliest timeslice of `ListingExpiries` which may still hold lapsed listings.
+	#[pallet::storage]
+	pub type ListingExpiryCursor<T> = StorageValue<_, Timeslice, OptionQuery>;
+
+	/// The performance of the most recent sales, oldest first. Sales in which no cores were
+	/// offered are not recorded.
+	#[pallet::storage]
+	pub type SaleHistory<T: Config> = StorageValue<_, SaleHistoryOf<T>, ValueQuery>;
+
+	/// Number of cores which have been renewed in the current sale.
+	#[pallet::storage]
+	pub type CoresRenewed<T> = StorageValue<_, CoreIndex, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -485,6 +517,44 @@ pub mod pallet {
 		/// This should never happen, given that enable_auto_renew checks for this before enabling
 		/// auto-renewal.
 		AutoRenewalLimitReached,
+		/// A Region, or the leading part of it, has been offered for sale.
+		Listed {
+			/// The Region which has been listed.
+			region_id: RegionId,
+			/// The owner of the Region who will be paid.
+			seller: T::AccountId,
+			/// The price asked for the part of the Region on offer.
+			price: BalanceOf<T>,
+			/// The offset into the Region at which it will be partitioned when sold, if only
+			/// part of it is on offer.
+			pivot: Option<Timeslice>,
+			/// The timeslice from which the listing is no longer valid.
+			expiry: Timeslice,
+		},
+		/// A listing has been withdrawn by the seller.
+		Unlisted {
+			/// The Region which is no longer on offer.
+			region_id: RegionId,
+		},
+		/// A listing has lapsed without the Region being sold.
+		ListingExpired {
+			/// The Region which is no longer on offer.
+			region_id: RegionId,
+		},
+		/// A listed Region, or the leading part of it, has been sold.
+		RegionSold {
+			/// The Region which now belongs to `buyer`.
+			region_id: RegionId,
+			/// The previous owner of the Region who has been paid.
+			seller: T::AccountId,
+			/// The new owner of the Region.
+			buyer: T::AccountId,
+			/// The price paid.
+			price: BalanceOf<T>,
+			/// The rest of the Region which is still owned by `seller`, if only part of the
+			/// Region was on offer.
+			remainder: Option<RegionId>,
+		},
 	}
 
 	#[pallet::error]
@@ -568,6 +638,12 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.The amount of credits the user attempted to purchase is
 		/// below `T::MinimumCreditPurchase`.
 		CreditPurchaseTooSmall,
+		/// The Region is not listed for sale.
+		NotListed,
+		/// The listing has lapsed or would lapse immediately.
+		ListingExpired,
+		/// The Region has been changed or has changed hands since it was listed.
+		ListingOutdated,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -1013,6 +1089,62 @@ pub mod pallet {
 			Self::do_remove_assignment(region_id)
 		}
 
+		/// Offer a Bulk Coretime Region, or the leading part of it, for sale at a fixed price.
+		///
+		/// The Region stays with its owner until it is bought. Listing a Region again replaces
+		/// the previous listing. The listing is dropped once the Region is transferred,
+		/// partitioned, interlaced, assigned, pooled or dropped.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which should be offered.
+		/// - `price`: The price asked for the part of the Region on offer.
+		/// - `pivot`: If `Some`, only the part of the Region up to this offset into it is offered
+		///   and the Region is partitioned there when sold.
+		/// - `expiry`: The timeslice from which the listing is no longer valid. It can be no later
+		///   than the end of the part on offer, which is also the default.
+		#[pallet::call_index(27)]
+		pub fn list_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			price: BalanceOf<T>,
+			pivot: Option<Timeslice>,
+			expiry: Option<Timeslice>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_list_region(region_id, who, price, pivot, expiry)?;
+			Ok(())
+		}
+
+		/// Withdraw a listing of a Bulk Coretime Region.
+		///
+		/// - `origin`: Must be a Signed origin of the account which listed the Region.
+		/// - `region_id`: The Region which should no longer be offered.
+		#[pallet::call_index(28)]
+		pub fn unlist_region(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_unlist_region(region_id, who)?;
+			Ok(())
+		}
+
+		/// Buy a listed Bulk Coretime Region, or the leading part of it.
+		///
+		/// The price is paid to the seller in `Currency`. If only part of the Region was on
+		/// offer, the Region is partitioned at the listed pivot and the seller keeps the rest.
+		///
+		/// - `origin`: Must be a Signed origin able to pay the listed price.
+		/// - `region_id`: The Region which should be bought.
+		/// - `max_price`: An amount no more than which should be paid.
+		#[pallet::call_index(29)]
+		pub fn purchase_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_purchase_region(region_id, who, max_price)?;
+			Ok(())
+		}
+
 		#[pallet::call_index(99)]
 		#[pallet::weight(T::WeightInfo::swap_leases())]
 		pub fn swap_leases(origin: OriginFor<T>, id: TaskId, other: TaskId) -> DispatchResult {
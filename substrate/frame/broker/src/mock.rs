@@ -205,6 +205,8 @@ impl crate::Config for Test {
 	type MaxAutoRenewals = ConstU32<3>;
 	type PriceAdapter = CenterTargetPrice<BalanceOf<Self>>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
+	type MaxListingsPerTimeslice = ConstU32<4>;
+	type MaxSaleHistory = ConstU32<3>;
 }
 
 pub fn advance_to(b: u64) {
@@ -17,15 +17,22 @@
 
 //! Runtime API definition for the FRAME Broker pallet.
 
+use crate::SaleHistoryRecord;
+use alloc::vec::Vec;
 use codec::Codec;
 use sp_runtime::DispatchError;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait BrokerApi<Balance>
 	where
 		Balance: Codec
 	{
 		/// If there is an ongoing sale returns the current price of a core.
 		fn sale_price() -> Result<Balance, DispatchError>;
+
+		/// Returns the performance of the most recent sales, oldest first.
+		#[api_version(2)]
+		fn sale_history() -> Vec<SaleHistoryRecord<Balance>>;
 	}
 }
@@ -2192,3 +2192,251 @@ fn force_reserve_works() {
 		assert_eq!(Workplan::<Test>::get((10, 0)), Some(system_workload.clone()));
 	});
 }
+
+#[test]
+fn list_and_purchase_region_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_eq!(balance(1), 900);
+
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 300, None, None));
+		let listing = Listings::<Test>::get(region).unwrap();
+		assert_eq!(listing.expiry, 7);
+		assert!(ListingExpiries::<Test>::contains_key(7, region));
+		System::assert_last_event(
+			Event::Listed { region_id: region, seller: 1, price: 300, pivot: None, expiry: 7 }
+				.into(),
+		);
+
+		assert_noop!(
+			Broker::purchase_region(RuntimeOrigin::signed(2), region, 299),
+			Error::<Test>::Overpriced
+		);
+		assert_ok!(Broker::purchase_region(RuntimeOrigin::signed(2), region, 300));
+		System::assert_last_event(
+			Event::RegionSold {
+				region_id: region,
+				seller: 1,
+				buyer: 2,
+				price: 300,
+				remainder: None,
+			}
+			.into(),
+		);
+
+		assert_eq!(<Broker as NftInspect<_>>::owner(&region.into()), Some(2));
+		assert_eq!(balance(1), 1200);
+		assert_eq!(balance(2), 700);
+		assert!(Listings::<Test>::get(region).is_none());
+
+		// The region is no longer on offer.
+		assert_noop!(
+			Broker::purchase_region(RuntimeOrigin::signed(1), region, 300),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn partial_region_sale_partitions_at_pivot() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_noop!(
+			Broker::list_region(RuntimeOrigin::signed(1), region, 100, Some(3), None),
+			Error::<Test>::PivotTooLate
+		);
+		assert_noop!(
+			Broker::list_region(RuntimeOrigin::signed(1), region, 100, Some(0), None),
+			Error::<Test>::PivotTooEarly
+		);
+
+		// Only the first timeslice of the region is on offer, so the listing lapses with it.
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, Some(1), None));
+		assert_eq!(Listings::<Test>::get(region).unwrap().expiry, region.begin + 1);
+
+		assert_ok!(Broker::purchase_region(RuntimeOrigin::signed(2), region, 100));
+		let remainder = RegionId { begin: region.begin + 1, ..region };
+		System::assert_last_event(
+			Event::RegionSold {
+				region_id: region,
+				seller: 1,
+				buyer: 2,
+				price: 100,
+				remainder: Some(remainder),
+			}
+			.into(),
+		);
+
+		assert_eq!(Regions::<Test>::get(region).unwrap().end, region.begin + 1);
+		assert_eq!(<Broker as NftInspect<_>>::owner(&region.into()), Some(2));
+		assert_eq!(Regions::<Test>::get(remainder).unwrap().end, 7);
+		assert_eq!(<Broker as NftInspect<_>>::owner(&remainder.into()), Some(1));
+		assert_eq!(balance(1), 1000);
+		assert_eq!(balance(2), 900);
+	});
+}
+
+#[test]
+fn listing_checks_work() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_noop!(
+			Broker::list_region(RuntimeOrigin::signed(2), region, 100, None, None),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, Some(0)),
+			Error::<Test>::ListingExpired
+		);
+		assert_noop!(
+			Broker::unlist_region(RuntimeOrigin::signed(1), region),
+			Error::<Test>::NotListed
+		);
+
+		// Listing again replaces the previous listing.
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, None));
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 200, None, Some(6)));
+		assert_eq!(Listings::<Test>::get(region).unwrap().price, 200);
+		assert!(!ListingExpiries::<Test>::contains_key(7, region));
+		assert!(ListingExpiries::<Test>::contains_key(6, region));
+
+		// Only the seller can withdraw the listing.
+		assert_noop!(
+			Broker::unlist_region(RuntimeOrigin::signed(2), region),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(Broker::unlist_region(RuntimeOrigin::signed(1), region));
+		System::assert_last_event(Event::Unlisted { region_id: region }.into());
+		assert!(!ListingExpiries::<Test>::contains_key(6, region));
+
+		// Changing the region drops its listing.
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, Some(6)));
+		Broker::do_partition(region, Some(1), 1).unwrap();
+		assert!(Listings::<Test>::get(region).is_none());
+		assert!(!ListingExpiries::<Test>::contains_key(6, region));
+		assert_noop!(
+			Broker::purchase_region(RuntimeOrigin::signed(2), region, 100),
+			Error::<Test>::NotListed
+		);
+
+		// So does assigning it, even provisionally.
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, None));
+		assert_ok!(Broker::do_assign(region, Some(1), 1001, Provisional));
+		assert!(Regions::<Test>::get(region).is_some());
+		assert!(Listings::<Test>::get(region).is_none());
+
+		// And transferring it.
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, None));
+		assert_ok!(Broker::do_transfer(region, Some(1), 2));
+		assert!(Listings::<Test>::get(region).is_none());
+		assert!(!ListingExpiries::<Test>::contains_key(7, region));
+	});
+}
+
+#[test]
+fn listings_expire_on_tick() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, Some(5)));
+		advance_to(4);
+		assert!(Listings::<Test>::get(region).is_some());
+
+		// Once timeslice 4 is committed, no part of the offer before timeslice 5 is left.
+		advance_to(8);
+		assert!(Listings::<Test>::get(region).is_none());
+		assert!(!ListingExpiries::<Test>::contains_key(5, region));
+		System::assert_has_event(Event::ListingExpired { region_id: region }.into());
+		assert_noop!(
+			Broker::purchase_region(RuntimeOrigin::signed(2), region, 100),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn listings_lapse_with_their_region() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, None, None));
+		advance_to(12);
+		assert!(Listings::<Test>::get(region).is_none());
+		assert!(!ListingExpiries::<Test>::contains_key(7, region));
+		System::assert_has_event(Event::ListingExpired { region_id: region }.into());
+		assert_noop!(
+			Broker::purchase_region(RuntimeOrigin::signed(2), region, 100),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn listing_expiries_are_bounded_per_timeslice() {
+	TestExt::new().execute_with(|| {
+		let max = <Test as crate::Config>::MaxListingsPerTimeslice::get();
+		for core in 0..=max as CoreIndex {
+			let region_id = RegionId { begin: 0, core, mask: CoreMask::complete() };
+			let listing =
+				ListingRecord { seller: 1, price: 100, region_end: 6, pivot: None, expiry: 6 };
+			Listings::<Test>::insert(region_id, listing);
+			ListingExpiries::<Test>::insert(6, region_id, ());
+		}
+
+		// Only so many lapsed listings are cleared at once...
+		assert_eq!(Broker::process_listing_expiries(5), max);
+		assert_eq!(Listings::<Test>::iter().count(), 1);
+		assert_eq!(ListingExpiryCursor::<Test>::get(), Some(6));
+
+		// ...and those left over are cleared along with the next timeslice.
+		assert_eq!(Broker::process_listing_expiries(6), 1);
+		assert_eq!(Listings::<Test>::iter().count(), 0);
+		assert_eq!(ListingExpiries::<Test>::iter().count(), 0);
+		assert_eq!(ListingExpiryCursor::<Test>::get(), Some(8));
+	});
+}
+
+#[test]
+fn sale_history_is_recorded() {
+	TestExt::new().endow(1, 100_000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		// The imaginary sale used to bootstrap the first one is not recorded.
+		assert!(SaleHistory::<Test>::get().is_empty());
+
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_assign(region, None, 1001, Final));
+		advance_to(6);
+		assert_ok!(Broker::do_renew(1, region.core));
+		assert_eq!(CoresRenewed::<Test>::get(), 1);
+
+		advance_to(12);
+		assert_eq!(CoresRenewed::<Test>::get(), 0);
+		let history = Broker::sale_history();
+		assert_eq!(history.len(), 2);
+		assert_eq!((history[0].region_begin, history[0].region_end), (4, 7));
+		assert_eq!(history[0].performance.cores_offered, 1);
+		assert_eq!(history[0].performance.cores_sold, 1);
+		assert_eq!(history[0].performance.cores_renewed, 0);
+		assert_eq!((history[1].region_begin, history[1].region_end), (7, 10));
+		assert_eq!(history[1].performance.cores_sold, 1);
+		assert_eq!(history[1].performance.cores_renewed, 1);
+
+		// Only the most recent sales are kept.
+		advance_to(30);
+		let begins: Vec<_> = Broker::sale_history().iter().map(|r| r.region_begin).collect();
+		assert_eq!(begins, vec![10, 13, 16]);
+	});
+}
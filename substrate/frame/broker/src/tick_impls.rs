@@ -62,6 +62,9 @@ impl<T: Config> Pallet<T> {
 			Self::process_pool(commit_timeslice, &mut status);
 			meter.consume(T::WeightInfo::process_pool());
 
+			let expired = Self::process_listing_expiries(commit_timeslice);
+			meter.consume(T::WeightInfo::process_listing_expiries(expired));
+
 			let timeslice_period = T::TimeslicePeriod::get();
 			let rc_begin = RelayBlockNumberOf::<T>::from(commit_timeslice) * timeslice_period;
 			for core in 0..status.core_count {
@@ -85,6 +88,35 @@ impl<T: Config> Pallet<T> {
 		meter.consumed()
 	}
 
+	/// Drop up to `MaxListingsPerTimeslice` listings which have lapsed now that
+	/// `commit_timeslice` has been committed. Listings left over are dropped when the following
+	/// timeslices are committed.
+	///
+	/// Returns the number of listings which were dropped.
+	pub(crate) fn process_listing_expiries(commit_timeslice: Timeslice) -> u32 {
+		let lapsed = commit_timeslice.saturating_add(1);
+		let limit = T::MaxListingsPerTimeslice::get();
+		let mut expiry = ListingExpiryCursor::<T>::get().unwrap_or(lapsed);
+		let mut dropped = 0;
+		while expiry <= lapsed {
+			let expired: Vec<_> = ListingExpiries::<T>::iter_key_prefix(expiry)
+				.take(limit.saturating_sub(dropped) as usize)
+				.collect();
+			for region_id in expired {
+				ListingExpiries::<T>::remove(expiry, &region_id);
+				Listings::<T>::remove(&region_id);
+				Self::deposit_event(Event::<T>::ListingExpired { region_id });
+				dropped.saturating_inc();
+			}
+			if dropped >= limit {
+				break
+			}
+			expiry.saturating_inc();
+		}
+		ListingExpiryCursor::<T>::put(expiry);
+		dropped
+	}
+
 	pub(crate) fn process_core_count(status: &mut StatusRecord) -> bool {
 		if let Some(core_count) = CoreCountInbox::<T>::take() {
 			status.core_count = core_count;
@@ -175,7 +207,27 @@ impl<T: Config> Pallet<T> {
 		InstaPoolIo::<T>::mutate(old_sale.region_end, |r| r.system.saturating_reduce(old_pooled));
 
 		// Calculate the start price for the upcoming sale.
-		let new_prices = T::PriceAdapter::adapt_price(SalePerformance::from_sale(&old_sale));
+		let performance = SalePerformance {
+			cores_renewed: CoresRenewed::<T>::take(),
+			..SalePerformance::from_sale(&old_sale)
+		};
+		let mut history = SaleHistory::<T>::get();
+		let past: Vec<_> = history.iter().map(|record| record.performance).collect();
+		let new_prices = T::PriceAdapter::adapt_price_with_history(performance, &past);
+
+		if old_sale.cores_offered > 0 {
+			if history.len() as u32 >= T::MaxSaleHistory::get() && !history.is_empty() {
+				history.remove(0);
+			}
+			let record = SaleHistoryRecord {
+				region_begin: old_sale.region_begin,
+				region_end: old_sale.region_end,
+				performance,
+			};
+			if history.try_push(record).is_ok() {
+				SaleHistory::<T>::put(&history);
+			}
+		}
 
 		log::debug!(
 			"Rotated sale, new prices: {:?}, {:?}",
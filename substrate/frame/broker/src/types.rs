@@ -16,8 +16,8 @@
 // limitations under the License.
 
 use crate::{
-	Config, CoreAssignment, CoreIndex, CoreMask, CoretimeInterface, RCBlockNumberOf, TaskId,
-	CORE_MASK_BITS,
+	Config, CoreAssignment, CoreIndex, CoreMask, CoretimeInterface, RCBlockNumberOf,
+	SalePerformance, TaskId, CORE_MASK_BITS,
 };
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::traits::fungible::Inspect;
@@ -112,6 +112,37 @@ pub struct RegionRecord<AccountId, Balance> {
 }
 pub type RegionRecordOf<T> = RegionRecord<<T as SConfig>::AccountId, BalanceOf<T>>;
 
+/// A fixed-price offer to sell a Region, or its leading part, to anyone willing to pay for it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ListingRecord<AccountId, Balance> {
+	/// The owner of the Region when it was listed, who receives the payment.
+	pub seller: AccountId,
+	/// The price asked for the part of the Region which is on offer.
+	pub price: Balance,
+	/// The end of the Region when it was listed. The listing is only honoured while the Region
+	/// still ends here and is still owned by `seller`.
+	pub region_end: Timeslice,
+	/// If `Some`, only the part of the Region up to this offset into it is on offer. The Region
+	/// gets partitioned at this pivot when sold and the seller keeps the rest.
+	pub pivot: Option<Timeslice>,
+	/// The listing lapses once all timeslices before this one have been committed.
+	pub expiry: Timeslice,
+}
+pub type ListingRecordOf<T> = ListingRecord<<T as SConfig>::AccountId, BalanceOf<T>>;
+
+/// How a past Bulk Coretime Sale went.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SaleHistoryRecord<Balance> {
+	/// The first timeslice of the Regions which were sold in the sale.
+	pub region_begin: Timeslice,
+	/// The timeslice on which the Regions which were sold in the sale terminate.
+	pub region_end: Timeslice,
+	/// The performance of the sale, as given to the price adapter.
+	pub performance: SalePerformance<Balance>,
+}
+pub type SaleHistoryRecordOf<T> = SaleHistoryRecord<BalanceOf<T>>;
+pub type SaleHistoryOf<T> = BoundedVec<SaleHistoryRecordOf<T>, <T as Config>::MaxSaleHistory>;
+
 /// An distinct item which can be scheduled on a Polkadot Core.
 #[derive(
 	Encode,
@@ -104,6 +104,13 @@ impl<T: Config> Pallet<T> {
 		id
 	}
 
+	/// Drop the listing of `region_id`, if any, along with its expiry record.
+	pub(crate) fn remove_listing(region_id: &RegionId) -> Option<ListingRecordOf<T>> {
+		let listing = Listings::<T>::take(region_id)?;
+		ListingExpiries::<T>::remove(listing.expiry, region_id);
+		Some(listing)
+	}
+
 	pub(crate) fn utilize(
 		mut region_id: RegionId,
 		maybe_check_owner: Option<T::AccountId>,
@@ -117,6 +124,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		Regions::<T>::remove(&region_id);
+		Self::remove_listing(&region_id);
 
 		let last_committed_timeslice = status.last_committed_timeslice;
 		if region_id.begin <= last_committed_timeslice {
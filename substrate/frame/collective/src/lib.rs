@@ -38,6 +38,11 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! By default every member carries exactly one vote. A runtime may instead assign each member a
+//! weight through [`Config::MemberWeight`], e.g. derived from ranks with [`RankWeight`] or dampened
+//! with [`QuadraticWeight`]. Thresholds, tallies and the `Members` origin are then all expressed in
+//! units of weight rather than heads.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -49,7 +54,7 @@ use core::{marker::PhantomData, result};
 use scale_info::TypeInfo;
 use sp_io::storage;
 use sp_runtime::{
-	traits::{Dispatchable, Hash},
+	traits::{Dispatchable, Hash, IntegerSquareRoot, SaturatedConversion, Saturating},
 	DispatchError, RuntimeDebug,
 };
 
@@ -60,7 +65,7 @@ use frame_support::{
 	ensure, impl_ensure_origin_with_arg_ignoring_arg,
 	traits::{
 		Backing, ChangeMembers, Consideration, EnsureOrigin, EnsureOriginWithArg, Get, GetBacking,
-		InitializeMembers, MaybeConsideration, StorageVersion,
+		InitializeMembers, MaybeConsideration, RankedMembers, StorageVersion,
 	},
 	weights::Weight,
 };
@@ -95,9 +100,9 @@ pub trait DefaultVote {
 	/// Get the default voting strategy, given:
 	///
 	/// - Whether the prime member voted Aye.
-	/// - Raw number of yes votes.
-	/// - Raw number of no votes.
-	/// - Total number of member count.
+	/// - Weight of yes votes.
+	/// - Weight of no votes.
+	/// - Total weight of all members.
 	fn default_vote(
 		prime_vote: Option<bool>,
 		yes_votes: MemberCount,
@@ -136,6 +141,71 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 	}
 }
 
+/// The voting weight carried by each member of the collective.
+pub trait MemberWeight<AccountId> {
+	/// The number of votes `who` casts with a single aye or nay.
+	///
+	/// Members with a weight of zero may still vote, but their votes do not count towards any
+	/// tally.
+	fn member_weight(who: &AccountId) -> MemberCount;
+
+	/// The weight of a single [`Self::member_weight`] lookup.
+	///
+	/// It is charged for every lookup in `vote`, `close` and `set_members`, on top of the
+	/// benchmarked weights. Changes of the members through [`ChangeMembers`] by other pallets
+	/// look up the weights of the remaining voters without charging it.
+	fn lookup_cost() -> Weight;
+}
+
+/// Each member carries exactly one vote.
+impl<AccountId> MemberWeight<AccountId> for () {
+	fn member_weight(_who: &AccountId) -> MemberCount {
+		1
+	}
+
+	fn lookup_cost() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Weigh members by their rank in a ranked membership organisation such as
+/// `pallet-ranked-collective`.
+///
+/// A member at the lowest rank carries one vote, and each rank above it adds another. Accounts
+/// which are not ranked carry no weight.
+///
+/// `LookupCost` is the weight of looking up a single rank, e.g. one read of the `Members` storage
+/// of `pallet-ranked-collective`.
+pub struct RankWeight<Ranks, LookupCost>(PhantomData<(Ranks, LookupCost)>);
+impl<AccountId, Ranks, LookupCost> MemberWeight<AccountId> for RankWeight<Ranks, LookupCost>
+where
+	Ranks: RankedMembers<AccountId = AccountId>,
+	LookupCost: Get<Weight>,
+{
+	fn member_weight(who: &AccountId) -> MemberCount {
+		Ranks::rank_of(who).map_or(0, |rank| {
+			let excess: MemberCount = rank.saturating_sub(Ranks::min_rank()).saturated_into();
+			excess.saturating_add(1)
+		})
+	}
+
+	fn lookup_cost() -> Weight {
+		LookupCost::get()
+	}
+}
+
+/// Quadratic voting: a member whose inner weight is `w` carries `floor(sqrt(w))` votes.
+pub struct QuadraticWeight<Inner>(PhantomData<Inner>);
+impl<AccountId, Inner: MemberWeight<AccountId>> MemberWeight<AccountId> for QuadraticWeight<Inner> {
+	fn member_weight(who: &AccountId) -> MemberCount {
+		Inner::member_weight(who).integer_sqrt()
+	}
+
+	fn lookup_cost() -> Weight {
+		Inner::lookup_cost()
+	}
+}
+
 /// Origin for the collective module.
 #[derive(
 	PartialEq,
@@ -152,6 +222,8 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 #[codec(mel_bound(AccountId: MaxEncodedLen))]
 pub enum RawOrigin<AccountId, I> {
 	/// It has been condoned by a given number of members of the collective from a given total.
+	///
+	/// When the collective weighs its members, both numbers are weights rather than heads.
 	Members(MemberCount, MemberCount),
 	/// It has been condoned by a single member of the collective.
 	Member(AccountId),
@@ -173,7 +245,7 @@ impl<AccountId, I> GetBacking for RawOrigin<AccountId, I> {
 pub struct Votes<AccountId, BlockNumber> {
 	/// The proposal's unique index.
 	index: ProposalIndex,
-	/// The number of approval votes that are needed to pass the motion.
+	/// The weight of approval votes that is needed to pass the motion.
 	threshold: MemberCount,
 	/// The current set of voters that approved it.
 	ayes: Vec<AccountId>,
@@ -181,6 +253,12 @@ pub struct Votes<AccountId, BlockNumber> {
 	nays: Vec<AccountId>,
 	/// The hard end time of this vote.
 	end: BlockNumber,
+	/// The total weight of `ayes`, as of the last vote or change of the members.
+	///
+	/// This is the tally `close` counts, so changes of weights since are not taken into account.
+	aye_weight: MemberCount,
+	/// The total weight of `nays`, as of the last vote or change of the members.
+	nay_weight: MemberCount,
 }
 
 /// Types implementing various cost strategies for a given proposal count.
@@ -327,7 +405,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -367,6 +445,11 @@ pub mod pallet {
 		/// Default vote strategy of this collective.
 		type DefaultVote: DefaultVote;
 
+		/// The number of votes each member carries.
+		///
+		/// Use `()` to give every member a single vote.
+		type MemberWeight: MemberWeight<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -470,7 +553,7 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// A motion (given hash) has been proposed (by given account) with a threshold (given
-		/// `MemberCount`).
+		/// `MemberCount` of weight).
 		Proposed {
 			account: T::AccountId,
 			proposal_index: ProposalIndex,
@@ -478,7 +561,7 @@ pub mod pallet {
 			threshold: MemberCount,
 		},
 		/// A motion (given hash) has been voted on by given account, leaving
-		/// a tally (yes votes and no votes given respectively as `MemberCount`).
+		/// a tally (weight of yes votes and no votes given respectively as `MemberCount`).
 		Voted {
 			account: T::AccountId,
 			proposal_hash: T::Hash,
@@ -494,7 +577,8 @@ pub mod pallet {
 		Executed { proposal_hash: T::Hash, result: DispatchResult },
 		/// A single member did some action; result will be `Ok` if it returned without error.
 		MemberExecuted { proposal_hash: T::Hash, result: DispatchResult },
-		/// A proposal was closed because its threshold was reached or after its duration was up.
+		/// A proposal was closed because its threshold was reached or after its duration was up,
+		/// with the final weight of yes and no votes.
 		Closed { proposal_hash: T::Hash, yes: MemberCount, no: MemberCount },
 		/// A proposal was killed.
 		Killed { proposal_hash: T::Hash },
@@ -530,6 +614,8 @@ pub mod pallet {
 		PrimeAccountNotMember,
 		/// Proposal is still active.
 		ProposalActive,
+		/// The proposer carries no weight, so cannot execute a proposal directly.
+		NoWeight,
 	}
 
 	#[pallet::hooks]
@@ -581,7 +667,9 @@ pub mod pallet {
 				*old_count, // M
 				new_members.len() as u32, // N
 				T::MaxProposals::get() // P
-			),
+			).saturating_add(Pallet::<T, I>::member_weight_lookups(
+				(new_members.len() as u32).saturating_mul(T::MaxProposals::get())
+			)),
 			DispatchClass::Operational
 		))]
 		pub fn set_members(
@@ -617,11 +705,16 @@ pub mod pallet {
 			<Self as ChangeMembers<T::AccountId>>::set_members_sorted(&new_members, &old);
 			Prime::<T, I>::set(prime);
 
-			Ok(Some(T::WeightInfo::set_members(
-				old.len() as u32,         // M
-				new_members.len() as u32, // N
-				T::MaxProposals::get(),   // P
-			))
+			Ok(Some(
+				T::WeightInfo::set_members(
+					old.len() as u32,         // M
+					new_members.len() as u32, // N
+					T::MaxProposals::get(),   // P
+				)
+				.saturating_add(Self::member_weight_lookups(
+					(new_members.len() as u32).saturating_mul(T::MaxProposals::get()),
+				)),
+			)
 			.into())
 		}
 
@@ -677,6 +770,10 @@ pub mod pallet {
 		///
 		/// `threshold` determines whether `proposal` is executed directly (`threshold < 2`)
 		/// or put up for voting.
+		/// When executed directly, the origin is backed by the weight of the sender out of the
+		/// total weight of the members, as given by [`Config::MemberWeight`]. Members without
+		/// weight cannot execute proposals directly.
+		/// When put up for voting, `threshold` is the weight of approvals required.
 		///
 		/// ## Complexity
 		/// - `O(B + M + P1)` or `O(B + M + P2)` where:
@@ -691,7 +788,11 @@ pub mod pallet {
 				T::WeightInfo::propose_execute(
 					*length_bound, // B
 					T::MaxMembers::get(), // M
-				).saturating_add(proposal.get_dispatch_info().call_weight) // P1
+				)
+				.saturating_add(Pallet::<T, I>::member_weight_lookups(
+					T::MaxMembers::get().saturating_add(1)
+				))
+				.saturating_add(proposal.get_dispatch_info().call_weight) // P1
 			} else {
 				T::WeightInfo::propose_proposed(
 					*length_bound, // B
@@ -712,7 +813,8 @@ pub mod pallet {
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
 			if threshold < 2 {
-				let (proposal_len, result) = Self::do_propose_execute(proposal, length_bound)?;
+				let (proposal_len, result) =
+					Self::do_propose_execute(&who, &members, proposal, length_bound)?;
 
 				Ok(get_result_weight(result)
 					.map(|w| {
@@ -720,6 +822,10 @@ pub mod pallet {
 							proposal_len as u32,  // B
 							members.len() as u32, // M
 						)
+						// The sender and all members are weighed.
+						.saturating_add(Self::member_weight_lookups(
+							(members.len() as u32).saturating_add(1),
+						))
 						.saturating_add(w) // P1
 					})
 					.into())
@@ -746,7 +852,11 @@ pub mod pallet {
 		/// ## Complexity
 		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
 		#[pallet::call_index(3)]
-		#[pallet::weight((T::WeightInfo::vote(T::MaxMembers::get()), DispatchClass::Operational))]
+		#[pallet::weight((
+			T::WeightInfo::vote(T::MaxMembers::get())
+				.saturating_add(Pallet::<T, I>::member_weight_lookups(T::MaxMembers::get())),
+			DispatchClass::Operational
+		))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			proposal: T::Hash,
@@ -760,10 +870,13 @@ pub mod pallet {
 			// Detects first vote of the member in the motion
 			let is_account_voting_first_time = Self::do_vote(who, proposal, index, approve)?;
 
+			// The voters are members, so there are at most as many lookups as members.
+			let weight = T::WeightInfo::vote(members.len() as u32)
+				.saturating_add(Self::member_weight_lookups(members.len() as u32));
 			if is_account_voting_first_time {
-				Ok((Some(T::WeightInfo::vote(members.len() as u32)), Pays::No).into())
+				Ok((Some(weight), Pays::No).into())
 			} else {
-				Ok((Some(T::WeightInfo::vote(members.len() as u32)), Pays::Yes).into())
+				Ok((Some(weight), Pays::Yes).into())
 			}
 		}
 
@@ -825,6 +938,7 @@ pub mod pallet {
 					.max(T::WeightInfo::close_early_disapproved(m, p2))
 					.max(T::WeightInfo::close_approved(b, m, p2))
 					.max(T::WeightInfo::close_disapproved(m, p2))
+					.saturating_add(Pallet::<T, I>::member_weight_lookups(m))
 					.saturating_add(p1)
 			},
 			DispatchClass::Operational
@@ -919,10 +1033,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Execute immediately when adding a new proposal.
+	///
+	/// The proposal is dispatched with the weight of `who` out of the total weight of `members`.
 	pub fn do_propose_execute(
+		who: &T::AccountId,
+		members: &[T::AccountId],
 		proposal: Box<<T as Config<I>>::Proposal>,
 		length_bound: MemberCount,
 	) -> Result<(u32, DispatchResultWithPostInfo), DispatchError> {
+		let weight = T::MemberWeight::member_weight(who);
+		ensure!(weight > 0, Error::<T, I>::NoWeight);
 		let proposal_len = proposal.encoded_size();
 		ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
 		let proposal_weight = proposal.get_dispatch_info().call_weight;
@@ -934,8 +1054,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let proposal_hash = T::Hashing::hash_of(&proposal);
 		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
-		let seats = Members::<T, I>::get().len() as MemberCount;
-		let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
+		let seats = Self::weight_of(members);
+		let result = proposal.dispatch(RawOrigin::Members(weight, seats).into());
 		Self::deposit_event(Event::Executed {
 			proposal_hash,
 			result: result.map(|_| ()).map_err(|e| e.error),
@@ -978,7 +1098,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		<ProposalOf<T, I>>::insert(proposal_hash, proposal);
 		let votes = {
 			let end = frame_system::Pallet::<T>::block_number() + T::MotionDuration::get();
-			Votes { index, threshold, ayes: vec![], nays: vec![], end }
+			Votes {
+				index,
+				threshold,
+				ayes: vec![],
+				nays: vec![],
+				end,
+				aye_weight: 0,
+				nay_weight: 0,
+			}
 		};
 		<Voting<T, I>>::insert(proposal_hash, votes);
 
@@ -1028,8 +1156,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 		}
 
-		let yes_votes = voting.ayes.len() as MemberCount;
-		let no_votes = voting.nays.len() as MemberCount;
+		voting.aye_weight = Self::weight_of(&voting.ayes);
+		voting.nay_weight = Self::weight_of(&voting.nays);
+		let yes_votes = voting.aye_weight;
+		let no_votes = voting.nay_weight;
 		Self::deposit_event(Event::Voted {
 			account: who,
 			proposal_hash: proposal,
@@ -1053,9 +1183,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let voting = Voting::<T, I>::get(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-		let mut no_votes = voting.nays.len() as MemberCount;
-		let mut yes_votes = voting.ayes.len() as MemberCount;
-		let seats = Members::<T, I>::get().len() as MemberCount;
+		let members = Members::<T, I>::get();
+		let mut no_votes = voting.nay_weight;
+		let mut yes_votes = voting.aye_weight;
+		let seats = Self::weight_of(&members);
+		let member_count = members.len() as MemberCount;
+		let lookups = Self::member_weight_lookups(member_count);
 		let approved = yes_votes >= voting.threshold;
 		let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
 		// Allow (dis-)approving the proposal as soon as there are enough votes.
@@ -1070,7 +1203,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			return Ok((
 				Some(
-					T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
+					T::WeightInfo::close_early_approved(len as u32, member_count, proposal_count)
+						.saturating_add(lookups)
 						.saturating_add(proposal_weight),
 				),
 				Pays::Yes,
@@ -1080,7 +1214,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			return Ok((
-				Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+				Some(
+					T::WeightInfo::close_early_disapproved(member_count, proposal_count)
+						.saturating_add(lookups),
+				),
 				Pays::No,
 			)
 				.into())
@@ -1094,7 +1231,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// default voting strategy.
 		let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
 
-		let abstentions = seats - (yes_votes + no_votes);
+		let abstentions = seats.saturating_sub(yes_votes.saturating_add(no_votes));
 		match default {
 			true => yes_votes += abstentions,
 			false => no_votes += abstentions,
@@ -1112,7 +1249,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			Ok((
 				Some(
-					T::WeightInfo::close_approved(len as u32, seats, proposal_count)
+					T::WeightInfo::close_approved(len as u32, member_count, proposal_count)
+						.saturating_add(lookups)
 						.saturating_add(proposal_weight),
 				),
 				Pays::Yes,
@@ -1121,10 +1259,29 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		} else {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-			Ok((Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into())
+			Ok((
+				Some(
+					T::WeightInfo::close_disapproved(member_count, proposal_count)
+						.saturating_add(lookups),
+				),
+				Pays::No,
+			)
+				.into())
 		}
 	}
 
+	/// The weight of looking up the [`Config::MemberWeight`] of `accounts` many accounts.
+	pub fn member_weight_lookups(accounts: u32) -> Weight {
+		T::MemberWeight::lookup_cost().saturating_mul(accounts.into())
+	}
+
+	/// The total weight of the given accounts, according to [`Config::MemberWeight`].
+	pub fn weight_of(accounts: &[T::AccountId]) -> MemberCount {
+		accounts.iter().fold(0, |acc: MemberCount, who| {
+			acc.saturating_add(T::MemberWeight::member_weight(who))
+		})
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
 	///
 	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -1347,6 +1504,8 @@ impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
 						.into_iter()
 						.filter(|i| outgoing.binary_search(i).is_err())
 						.collect();
+					votes.aye_weight = Self::weight_of(&votes.ayes);
+					votes.nay_weight = Self::weight_of(&votes.nays);
 					*v = Some(votes);
 				}
 			});
@@ -17,3 +17,6 @@
 
 /// Version 4.
 pub mod v4;
+
+/// Version 5.
+pub mod v5;
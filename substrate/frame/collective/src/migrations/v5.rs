@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Adds the weighted tallies to every ongoing motion.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The log target.
+const TARGET: &str = "runtime::collective::migration::v5";
+
+/// Storage types prior to version 5.
+pub mod v4 {
+	use super::*;
+
+	/// Info for keeping track of a motion being voted on, without weighted tallies.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct Votes<AccountId, BlockNumber> {
+		/// The proposal's unique index.
+		pub index: ProposalIndex,
+		/// The number of approval votes that are needed to pass the motion.
+		pub threshold: MemberCount,
+		/// The current set of voters that approved it.
+		pub ayes: Vec<AccountId>,
+		/// The current set of voters that rejected it.
+		pub nays: Vec<AccountId>,
+		/// The hard end time of this vote.
+		pub end: BlockNumber,
+	}
+}
+
+/// Weighs the votes of every ongoing motion according to [`Config::MemberWeight`].
+pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let voting_count = Voting::<T, I>::iter_keys().count();
+		log::info!(target: TARGET, "pre-upgrade state contains '{}' motions.", voting_count);
+		Ok((voting_count as u32).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+		let mut weight = T::DbWeight::get().reads(1);
+		if on_chain_version != 4 {
+			log::warn!(target: TARGET, "skipping migration from v4 to v5.");
+			return weight
+		}
+		Voting::<T, I>::translate::<v4::Votes<T::AccountId, BlockNumberFor<T>>, _>(|_, old| {
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			let aye_weight = Pallet::<T, I>::weight_of(&old.ayes);
+			let nay_weight = Pallet::<T, I>::weight_of(&old.nays);
+			Some(Votes {
+				index: old.index,
+				threshold: old.threshold,
+				ayes: old.ayes,
+				nays: old.nays,
+				end: old.end,
+				aye_weight,
+				nay_weight,
+			})
+		});
+		StorageVersion::new(5).put::<Pallet<T, I>>();
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+		ensure!(on_chain_version == 5, "must upgrade from version 4 to 5.");
+		let pre_voting_count: u32 =
+			Decode::decode(&mut &state[..]).expect("failed to decode the state from pre-upgrade.");
+		let post_voting_count = Voting::<T, I>::iter().count() as u32;
+		ensure!(post_voting_count == pre_voting_count, "must migrate all motions.");
+		log::info!(target: TARGET, "migrated all motions.");
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{ExtBuilder, Test};
+	use frame_support::storage::unhashed;
+	use sp_core::H256;
+
+	#[test]
+	fn migration_v4_to_v5_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(4).put::<Pallet<Test>>();
+			let hash = H256::repeat_byte(1);
+			let old = v4::Votes::<u64, u64> {
+				index: 0,
+				threshold: 2,
+				ayes: vec![1, 2],
+				nays: vec![3],
+				end: 4,
+			};
+			unhashed::put_raw(&Voting::<Test>::hashed_key_for(hash), &old.encode());
+
+			MigrateToV5::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				Voting::<Test>::get(hash),
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![1, 2],
+					nays: vec![3],
+					end: 4,
+					aye_weight: 2,
+					nay_weight: 1,
+				})
+			);
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 5);
+		});
+	}
+}
@@ -118,6 +118,23 @@ parameter_types! {
 type CollectiveDeposit =
 	deposit::Delayed<ProposalDepositDelay, deposit::Constant<ProposalDepositBase>>;
 
+parameter_types! {
+	pub static MemberWeights: Vec<(AccountId, MemberCount)> = vec![];
+	pub static MemberWeightLookupCost: Weight = Weight::zero();
+}
+
+/// Weighs members as configured in `MemberWeights`, defaulting to a single vote.
+pub struct TestMemberWeight;
+impl MemberWeight<AccountId> for TestMemberWeight {
+	fn member_weight(who: &AccountId) -> MemberCount {
+		MemberWeights::get().iter().find(|(m, _)| m == who).map_or(1, |(_, w)| *w)
+	}
+
+	fn lookup_cost() -> Weight {
+		MemberWeightLookupCost::get()
+	}
+}
+
 impl Config<Instance1> for Test {
 	type RuntimeOrigin = RuntimeOrigin;
 	type Proposal = RuntimeCall;
@@ -126,6 +143,7 @@ impl Config<Instance1> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type MemberWeight = TestMemberWeight;
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -145,6 +163,7 @@ impl Config<Instance2> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+	type MemberWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -172,6 +191,7 @@ impl Config for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type MemberWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -708,12 +728,28 @@ fn removal_of_old_voters_votes_works() {
 		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, true));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 3,
+				ayes: vec![1, 2],
+				nays: vec![],
+				end,
+				aye_weight: 2,
+				nay_weight: 0
+			})
 		);
 		Collective::change_members_sorted(&[4], &[1], &[2, 3, 4]);
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 3,
+				ayes: vec![2],
+				nays: vec![],
+				end,
+				aye_weight: 1,
+				nay_weight: 0
+			})
 		);
 
 		let proposal = make_proposal(69);
@@ -729,12 +765,28 @@ fn removal_of_old_voters_votes_works() {
 		assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 1, false));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+			Some(Votes {
+				index: 1,
+				threshold: 2,
+				ayes: vec![2],
+				nays: vec![3],
+				end,
+				aye_weight: 1,
+				nay_weight: 1
+			})
 		);
 		Collective::change_members_sorted(&[], &[3], &[2, 4]);
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+			Some(Votes {
+				index: 1,
+				threshold: 2,
+				ayes: vec![2],
+				nays: vec![],
+				end,
+				aye_weight: 1,
+				nay_weight: 0
+			})
 		);
 	});
 }
@@ -756,7 +808,15 @@ fn removal_of_old_voters_votes_works_with_set_members() {
 		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, true));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 3,
+				ayes: vec![1, 2],
+				nays: vec![],
+				end,
+				aye_weight: 2,
+				nay_weight: 0
+			})
 		);
 		assert_ok!(Collective::set_members(
 			RuntimeOrigin::root(),
@@ -766,7 +826,15 @@ fn removal_of_old_voters_votes_works_with_set_members() {
 		));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 3,
+				ayes: vec![2],
+				nays: vec![],
+				end,
+				aye_weight: 1,
+				nay_weight: 0
+			})
 		);
 
 		let proposal = make_proposal(69);
@@ -782,7 +850,15 @@ fn removal_of_old_voters_votes_works_with_set_members() {
 		assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 1, false));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+			Some(Votes {
+				index: 1,
+				threshold: 2,
+				ayes: vec![2],
+				nays: vec![3],
+				end,
+				aye_weight: 1,
+				nay_weight: 1
+			})
 		);
 		assert_ok!(Collective::set_members(
 			RuntimeOrigin::root(),
@@ -792,7 +868,15 @@ fn removal_of_old_voters_votes_works_with_set_members() {
 		));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+			Some(Votes {
+				index: 1,
+				threshold: 2,
+				ayes: vec![2],
+				nays: vec![],
+				end,
+				aye_weight: 1,
+				nay_weight: 0
+			})
 		);
 	});
 }
@@ -814,7 +898,15 @@ fn propose_works() {
 		assert_eq!(ProposalOf::<Test, Instance1>::get(&hash), Some(proposal));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 3, ayes: vec![], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 3,
+				ayes: vec![],
+				nays: vec![],
+				end,
+				aye_weight: 0,
+				nay_weight: 0
+			})
 		);
 
 		assert_eq!(
@@ -978,13 +1070,29 @@ fn motions_vote_after_works() {
 		// Initially there a no votes when the motion is proposed.
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 2,
+				ayes: vec![],
+				nays: vec![],
+				end,
+				aye_weight: 0,
+				nay_weight: 0
+			})
 		);
 		// Cast first aye vote.
 		assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 2,
+				ayes: vec![1],
+				nays: vec![],
+				end,
+				aye_weight: 1,
+				nay_weight: 0
+			})
 		);
 		// Try to cast a duplicate aye vote.
 		assert_noop!(
@@ -995,7 +1103,15 @@ fn motions_vote_after_works() {
 		assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, false));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![1], end })
+			Some(Votes {
+				index: 0,
+				threshold: 2,
+				ayes: vec![],
+				nays: vec![1],
+				end,
+				aye_weight: 0,
+				nay_weight: 1
+			})
 		);
 		// Try to cast a duplicate nay vote.
 		assert_noop!(
@@ -1046,7 +1162,15 @@ fn motions_all_first_vote_free_works() {
 		));
 		assert_eq!(
 			Voting::<Test, Instance1>::get(&hash),
-			Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![], end })
+			Some(Votes {
+				index: 0,
+				threshold: 2,
+				ayes: vec![],
+				nays: vec![],
+				end,
+				aye_weight: 0,
+				nay_weight: 0
+			})
 		);
 
 		// For the motion, acc 2's first vote, expecting Ok with Pays::No.
@@ -1758,3 +1882,238 @@ fn constant_deposit_work() {
 	assert_eq!(<Constant12 as Convert<_, u128>>::convert(1), 12);
 	assert_eq!(<Constant12 as Convert<_, u128>>::convert(2), 12);
 }
+
+#[test]
+fn weighted_votes_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		// Member 1 carries seven votes, out of a total of nine.
+		MemberWeights::set(vec![(1, 7)]);
+		let proposal = RuntimeCall::Democracy(mock_democracy::Call::external_propose_majority {});
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().call_weight;
+		let hash: H256 = proposal.blake2_256().into();
+		let end = 4;
+		// The threshold is expressed in weight, so may exceed the member count.
+		assert_ok!(Collective::propose(
+			RuntimeOrigin::signed(2),
+			5,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, false));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+		assert_eq!(
+			Voting::<Test, Instance1>::get(&hash),
+			Some(Votes {
+				index: 0,
+				threshold: 5,
+				ayes: vec![1],
+				nays: vec![2],
+				end,
+				aye_weight: 7,
+				nay_weight: 1
+			})
+		);
+		// A single member reaches the threshold, and the origin backs the call with 7 / 9.
+		assert_ok!(Collective::close(
+			RuntimeOrigin::signed(3),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		assert_eq!(
+			System::events(),
+			vec![
+				record(RuntimeEvent::Collective(CollectiveEvent::Proposed {
+					account: 2,
+					proposal_index: 0,
+					proposal_hash: hash,
+					threshold: 5
+				})),
+				record(RuntimeEvent::Collective(CollectiveEvent::Voted {
+					account: 2,
+					proposal_hash: hash,
+					voted: false,
+					yes: 0,
+					no: 1
+				})),
+				record(RuntimeEvent::Collective(CollectiveEvent::Voted {
+					account: 1,
+					proposal_hash: hash,
+					voted: true,
+					yes: 7,
+					no: 1
+				})),
+				record(RuntimeEvent::Collective(CollectiveEvent::Closed {
+					proposal_hash: hash,
+					yes: 7,
+					no: 1
+				})),
+				record(RuntimeEvent::Collective(CollectiveEvent::Approved { proposal_hash: hash })),
+				record(RuntimeEvent::Democracy(mock_democracy::Event::<Test>::ExternalProposed)),
+				record(RuntimeEvent::Collective(CollectiveEvent::Executed {
+					proposal_hash: hash,
+					result: Ok(())
+				})),
+			]
+		);
+	});
+}
+
+#[test]
+fn weighted_direct_execution_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// Member 1 carries seven votes and member 3 none, out of a total of eight.
+		MemberWeights::set(vec![(1, 7), (3, 0)]);
+		let proposal = RuntimeCall::Democracy(mock_democracy::Call::external_propose_majority {});
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash: H256 = proposal.blake2_256().into();
+
+		// One vote out of eight does not reach the three quarters the call needs.
+		assert_ok!(Collective::propose(
+			RuntimeOrigin::signed(2),
+			1,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Executed {
+			proposal_hash: hash,
+			result: Err(DispatchError::BadOrigin),
+		}));
+
+		// Seven do.
+		assert_ok!(Collective::propose(
+			RuntimeOrigin::signed(1),
+			1,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Executed {
+			proposal_hash: hash,
+			result: Ok(()),
+		}));
+
+		// A member without weight cannot execute anything.
+		assert_noop!(
+			Collective::propose(RuntimeOrigin::signed(3), 1, Box::new(proposal), proposal_len),
+			Error::<Test, Instance1>::NoWeight
+		);
+	});
+}
+
+#[test]
+fn member_weight_lookups_are_charged() {
+	ExtBuilder::default().build_and_execute(|| {
+		let cost = Weight::from_parts(1_000, 100);
+		MemberWeightLookupCost::set(cost);
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().call_weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Collective::propose(
+			RuntimeOrigin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+
+		// The weights of all three members may be looked up when voting.
+		let post_info = Collective::vote(RuntimeOrigin::signed(1), hash, 0, false).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::vote(3) + cost * 3));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, false));
+
+		// Closing looks up the three members, the votes are tallied already.
+		let post_info =
+			Collective::close(RuntimeOrigin::signed(3), hash, 0, proposal_weight, proposal_len)
+				.unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::close_early_disapproved(3, 1) + cost * 3)
+		);
+	});
+}
+
+#[test]
+fn weighted_abstentions_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		// Member 1 carries three votes, out of a total of five.
+		MemberWeights::set(vec![(1, 3)]);
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().call_weight;
+		let hash: H256 = proposal.blake2_256().into();
+		assert_ok!(Collective::propose(
+			RuntimeOrigin::signed(2),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, false));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 0, false));
+		// Two votes against out of five cannot block a threshold of three.
+		assert_noop!(
+			Collective::close(RuntimeOrigin::signed(2), hash, 0, proposal_weight, proposal_len),
+			Error::<Test, Instance1>::TooEarly
+		);
+
+		// The abstaining member's weight follows the default vote.
+		System::set_block_number(4);
+		assert_ok!(Collective::close(
+			RuntimeOrigin::signed(2),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		System::assert_has_event(RuntimeEvent::Collective(CollectiveEvent::Closed {
+			proposal_hash: hash,
+			yes: 0,
+			no: 5,
+		}));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Disapproved {
+			proposal_hash: hash,
+		}));
+	});
+}
+
+#[test]
+fn member_weight_adapters_work() {
+	parameter_types! {
+		pub static Ranks: Vec<(AccountId, u16)> = vec![(1, 0), (2, 3), (3, 8)];
+	}
+	struct TestRanks;
+	impl RankedMembers for TestRanks {
+		type AccountId = AccountId;
+		type Rank = u16;
+		fn min_rank() -> u16 {
+			0
+		}
+		fn rank_of(who: &AccountId) -> Option<u16> {
+			Ranks::get().iter().find(|(m, _)| m == who).map(|(_, r)| *r)
+		}
+		fn induct(_: &AccountId) -> DispatchResult {
+			Ok(())
+		}
+		fn promote(_: &AccountId) -> DispatchResult {
+			Ok(())
+		}
+		fn demote(_: &AccountId) -> DispatchResult {
+			Ok(())
+		}
+	}
+
+	assert_eq!(<() as MemberWeight<AccountId>>::member_weight(&4), 1);
+
+	type ByRank = RankWeight<TestRanks, ()>;
+	assert_eq!(ByRank::member_weight(&1), 1);
+	assert_eq!(ByRank::member_weight(&2), 4);
+	assert_eq!(ByRank::member_weight(&3), 9);
+	assert_eq!(ByRank::member_weight(&4), 0);
+
+	type Quadratic = QuadraticWeight<RankWeight<TestRanks, ()>>;
+	assert_eq!(Quadratic::member_weight(&1), 1);
+	assert_eq!(Quadratic::member_weight(&2), 2);
+	assert_eq!(Quadratic::member_weight(&3), 3);
+	assert_eq!(Quadratic::member_weight(&4), 0);
+}
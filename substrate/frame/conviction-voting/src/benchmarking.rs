@@ -24,11 +24,13 @@ use assert_matches::assert_matches;
 use frame_benchmarking::v1::{account, benchmarks_instance_pallet, whitelist_account};
 use frame_support::{
 	dispatch::RawOrigin,
+	migrations::SteppedMigration,
 	traits::{
 		fungible,
 		tokens::{Fortitude::Polite, Preservation::Expendable},
-		Currency, Get,
+		Currency, Get, GetStorageVersion,
 	},
+	weights::WeightMeter,
 };
 use sp_runtime::traits::Bounded;
 
@@ -210,35 +212,39 @@ benchmarks_instance_pallet! {
 
 	undelegate {
 		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+		let d in 1 .. T::MaxDelegations::get();
 
 		let all_polls = fill_voting::<T, I>().1;
 		let class = T::Polls::max_ongoing().0;
 		let polls = &all_polls[&class];
-		let voter = funded_account::<T, I>("voter", 0);
-		let voter_lookup = T::Lookup::unlookup(voter.clone());
 		let caller = funded_account::<T, I>("caller", 0);
 		whitelist_account!(caller);
 
 		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
 		let delegate_vote = account_vote::<T, I>(delegated_balance);
 
-		ConvictionVoting::<T, I>::delegate(
-			RawOrigin::Signed(caller.clone()).into(),
-			class.clone(),
-			voter_lookup,
-			Conviction::Locked1x,
-			delegated_balance,
-		)?;
-
-		// We need to create delegations
-		for i in polls.iter().take(r as usize) {
-			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		// Split the delegation over `d` delegates, each of which voted on the same `r` polls.
+		for i in 0..d {
+			let voter = funded_account::<T, I>("voter", i);
+			ConvictionVoting::<T, I>::delegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				T::Lookup::unlookup(voter.clone()),
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+			for poll in polls.iter().take(r as usize) {
+				ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *poll, delegate_vote)?;
+			}
+			assert_matches!(
+				VotingFor::<T, I>::get(&voter, &class),
+				Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+			);
 		}
 		assert_matches!(
-			VotingFor::<T, I>::get(&voter, &class),
-			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Delegating(Delegating { targets, .. }) if targets.len() == d as usize
 		);
-		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Delegating(_));
 	}: _(RawOrigin::Signed(caller.clone()), class.clone())
 	verify {
 		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
@@ -279,6 +285,70 @@ benchmarks_instance_pallet! {
 		assert_eq!(orig_usable, <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(&caller, Expendable, Polite));
 	}
 
+	vote_override {
+		let d in 1 .. T::MaxDelegations::get();
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let index = all_polls[&class][0];
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// Split the delegation over `d` delegates, all of which voted on the poll.
+		for i in 0..d {
+			let voter = funded_account::<T, I>("voter", i);
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), index, delegate_vote)?;
+			ConvictionVoting::<T, I>::delegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				T::Lookup::unlookup(voter),
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Delegating(Delegating { targets, .. }) if targets.len() == d as usize
+		);
+	}: vote(RawOrigin::Signed(caller.clone()), index, delegate_vote)
+	verify {
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Delegating(Delegating { overrides, .. }) if overrides.len() == 1
+		);
+	}
+
+	set_delegate_policy {
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+		let class = T::Polls::max_ongoing().0;
+		let policy = T::Hash::default();
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), Some(policy))
+	verify {
+		assert_eq!(DelegatePolicies::<T, I>::get(&caller, &class), Some(policy));
+	}
+
+	migration_v1_step {
+		let (caller, class) =
+			migration::v1::LazyMigrationV0ToV1::<T, I>::setup_benchmark_env_for_migration();
+		let mut meter = WeightMeter::new();
+	}: {
+		migration::v1::LazyMigrationV0ToV1::<T, I>::step(None, &mut meter)
+			.map_err(|_| "migration step failed")?;
+	}
+	verify {
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Delegating(Delegating { targets, .. }) if targets.len() == 1
+		);
+		assert_eq!(ConvictionVoting::<T, I>::on_chain_storage_version(), 1);
+		// The weight is consumed once for the record and once for finding there is no other.
+		assert_eq!(meter.consumed(), T::WeightInfo::migration_v1_step() * 2);
+	}
+
 	impl_benchmark_test_suite!(
 		ConvictionVoting,
 		crate::tests::new_test_ext(),
@@ -29,6 +29,7 @@
 
 extern crate alloc;
 
+use alloc::vec::Vec;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -36,6 +37,7 @@ use frame_support::{
 		fungible, Currency, Get, LockIdentifier, LockableCurrency, PollStatus, Polling,
 		ReservableCurrency, WithdrawReasons,
 	},
+	weights::Weight,
 };
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
@@ -43,6 +45,7 @@ use sp_runtime::{
 };
 
 mod conviction;
+pub mod migration;
 mod types;
 mod vote;
 pub mod weights;
@@ -50,8 +53,8 @@ pub mod weights;
 pub use self::{
 	conviction::Conviction,
 	pallet::*,
-	types::{Delegations, Tally, UnvoteScope},
-	vote::{AccountVote, Casting, Delegating, Vote, Voting},
+	types::{Delegations, ExcludeBalance, Tally, UnvoteScope, VotingHooks},
+	vote::{AccountVote, Casting, Delegating, Delegation, Vote, Voting},
 	weights::WeightInfo,
 };
 use sp_runtime::traits::BlockNumberProvider;
@@ -76,10 +79,17 @@ pub type VotingOf<T, I = ()> = Voting<
 	BlockNumberFor<T, I>,
 	PollIndexOf<T, I>,
 	<T as Config<I>>::MaxVotes,
+	<T as Config<I>>::MaxDelegations,
 >;
 #[allow(dead_code)]
-type DelegatingOf<T, I = ()> =
-	Delegating<BalanceOf<T, I>, <T as frame_system::Config>::AccountId, BlockNumberFor<T, I>>;
+type DelegatingOf<T, I = ()> = Delegating<
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T, I>,
+	PollIndexOf<T, I>,
+	<T as Config<I>>::MaxVotes,
+	<T as Config<I>>::MaxDelegations,
+>;
 pub type TallyOf<T, I = ()> = Tally<BalanceOf<T, I>, <T as Config<I>>::MaxTurnout>;
 pub type VotesOf<T, I = ()> = BalanceOf<T, I>;
 pub type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
@@ -92,7 +102,8 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::{
-			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, ValueQuery,
+			DispatchResultWithPostInfo, IsType, OptionQuery, StorageDoubleMap, StorageMap,
+			StorageVersion, ValueQuery,
 		},
 		traits::ClassCountOf,
 		Twox64Concat,
@@ -100,7 +111,11 @@ pub mod pallet {
 	use frame_system::pallet_prelude::{ensure_signed, OriginFor};
 	use sp_runtime::BoundedVec;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -134,6 +149,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVotes: Get<u32>;
 
+		/// The maximum number of accounts an account may delegate parts of its voting power to
+		/// within a single class.
+		///
+		/// Also used to compute the weight of votes overriding delegations.
+		#[pallet::constant]
+		type MaxDelegations: Get<u32>;
+
 		/// The minimum period of vote locking.
 		///
 		/// It should be no shorter than enactment period to ensure that in the case of an approval,
@@ -142,6 +164,11 @@ pub mod pallet {
 		type VoteLockingPeriod: Get<BlockNumberFor<Self, I>>;
 		/// Provider for the block number. Normally this is the `frame_system` pallet.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Hooks called when voting, e.g. to exclude balance which must not back votes.
+		///
+		/// Use `()` if no funds need to be excluded.
+		type VotingHooks: VotingHooks<Self::AccountId, PollIndexOf<Self, I>, BalanceOf<Self, I>>;
 	}
 
 	/// All voting for a particular voter in a particular voting class. We store the balance for the
@@ -169,6 +196,32 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The delegated voting power taken out of the vote of an account on a poll, because some of
+	/// its delegators voted on the poll themselves.
+	#[pallet::storage]
+	pub type RetractedDelegations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		PollIndexOf<T, I>,
+		Delegations<BalanceOf<T, I>>,
+		ValueQuery,
+	>;
+
+	/// The voting policy a delegate published for a class of polls, as the hash of a document
+	/// describing it.
+	#[pallet::storage]
+	pub type DelegatePolicies<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		T::Hash,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -176,6 +229,11 @@ pub mod pallet {
 		Delegated(T::AccountId, T::AccountId),
 		/// An \[account\] has cancelled a previous delegation operation.
 		Undelegated(T::AccountId),
+		/// An account has stopped delegating part of its voting power to `target`, while still
+		/// delegating to others.
+		UndelegatedFrom { who: T::AccountId, target: T::AccountId },
+		/// A delegate has published or cleared their voting policy for a class.
+		DelegatePolicySet { who: T::AccountId, class: ClassOf<T, I>, policy: Option<T::Hash> },
 		/// An account has voted
 		Voted { who: T::AccountId, vote: AccountVote<BalanceOf<T, I>> },
 		/// A vote has been removed
@@ -211,6 +269,8 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// Maximum number of delegations reached.
+		MaxDelegationsReached,
 	}
 
 	#[pallet::call]
@@ -218,14 +278,22 @@ pub mod pallet {
 		/// Vote in a poll. If `vote.is_aye()`, the vote is to enact the proposal;
 		/// otherwise it is a vote to keep the status quo.
 		///
+		/// If the account is delegating in the class of the poll, the vote overrides its delegates
+		/// on this poll only: none of the delegated voting power is counted for them until the
+		/// vote is removed.
+		///
 		/// The dispatch origin of this call must be _Signed_.
 		///
 		/// - `poll_index`: The index of the poll to vote for.
 		/// - `vote`: The vote configuration.
 		///
-		/// Weight: `O(R)` where R is the number of polls the voter has voted on.
+		/// Weight: `O(R)` where R is the number of polls the voter has voted on, or `O(D)` where D
+		///   is the number of delegations being overridden.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::vote_new().max(T::WeightInfo::vote_existing()))]
+		#[pallet::weight(T::WeightInfo::vote_new()
+			.max(T::WeightInfo::vote_existing())
+			.max(T::WeightInfo::vote_override(T::MaxDelegations::get()))
+			.saturating_add(T::VotingHooks::weight()))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			#[pallet::compact] poll_index: PollIndexOf<T, I>,
@@ -241,8 +309,12 @@ pub mod pallet {
 		/// The balance delegated is locked for as long as it's delegated, and thereafter for the
 		/// time appropriate for the conviction's lock period.
 		///
+		/// The voting power may be split between several delegates by calling this once for each
+		/// of them, each time with a part of the balance and its own conviction. All parts
+		/// together must not be more than the account's current balance.
+		///
 		/// The dispatch origin of this call must be _Signed_, and the signing account must either:
-		///   - be delegating already; or
+		///   - be delegating already, to other accounts and without overriding votes; or
 		///   - have no voting activity (if there is, then it will need to be removed through
 		///     `remove_vote`).
 		///
@@ -251,8 +323,7 @@ pub mod pallet {
 		///   to this function are required.
 		/// - `conviction`: The conviction that will be attached to the delegated votes. When the
 		///   account is undelegated, the funds will be locked for the corresponding period.
-		/// - `balance`: The amount of the account's balance to be used in delegating. This must not
-		///   be more than the account's current balance.
+		/// - `balance`: The amount of the account's balance to be used in delegating to `to`.
 		///
 		/// Emits `Delegated`.
 		///
@@ -261,7 +332,8 @@ pub mod pallet {
 		// NOTE: weight must cover an incorrect voting of origin with max votes, this is ensure
 		// because a valid delegation cover decoding a direct voting with max votes.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::delegate(T::MaxVotes::get()))]
+		#[pallet::weight(T::WeightInfo::delegate(T::MaxVotes::get())
+			.saturating_add(T::VotingHooks::weight()))]
 		pub fn delegate(
 			origin: OriginFor<T>,
 			class: ClassOf<T, I>,
@@ -273,7 +345,7 @@ pub mod pallet {
 			let to = T::Lookup::lookup(to)?;
 			let votes = Self::try_delegate(who, class, to, conviction, balance)?;
 
-			Ok(Some(T::WeightInfo::delegate(votes)).into())
+			Ok(Some(T::WeightInfo::delegate(votes).saturating_add(T::VotingHooks::weight())).into())
 		}
 
 		/// Undelegate the voting power of the sending account for a particular class of polls.
@@ -282,25 +354,26 @@ pub mod pallet {
 		/// of the conviction with which the delegation was issued has passed.
 		///
 		/// The dispatch origin of this call must be _Signed_ and the signing account must be
-		/// currently delegating.
+		/// currently delegating, without any votes overriding its delegates.
 		///
-		/// - `class`: The class of polls to remove the delegation from.
+		/// - `class`: The class of polls to remove the delegations from.
 		///
 		/// Emits `Undelegated`.
 		///
-		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
-		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		/// Weight: `O(D + R)` where D is the number of delegations and R is the number of polls
+		///   the voters delegating to have voted on. Weight is initially charged as if maximum
+		///   delegations and votes, but is refunded later.
 		// NOTE: weight must cover an incorrect voting of origin with max votes, this is ensure
 		// because a valid delegation cover decoding a direct voting with max votes.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::undelegate(T::MaxVotes::get().into()))]
+		#[pallet::weight(T::WeightInfo::undelegate(T::MaxVotes::get(), T::MaxDelegations::get(),))]
 		pub fn undelegate(
 			origin: OriginFor<T>,
 			class: ClassOf<T, I>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let votes = Self::try_undelegate(who, class)?;
-			Ok(Some(T::WeightInfo::undelegate(votes)).into())
+			let votes = Self::try_undelegate(who, class, None)?;
+			Ok(Some(Self::undelegate_weight(votes)).into())
 		}
 
 		/// Remove the lock caused by prior voting/delegating which has expired within a particular
@@ -356,7 +429,8 @@ pub mod pallet {
 		/// Weight: `O(R + log R)` where R is the number of polls that `target` has voted on.
 		///   Weight is calculated for the maximum number of vote.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::remove_vote())]
+		#[pallet::weight(T::WeightInfo::remove_vote()
+			.max(T::WeightInfo::vote_override(T::MaxDelegations::get())))]
 		pub fn remove_vote(
 			origin: OriginFor<T>,
 			class: Option<ClassOf<T, I>>,
@@ -383,7 +457,8 @@ pub mod pallet {
 		/// Weight: `O(R + log R)` where R is the number of polls that `target` has voted on.
 		///   Weight is calculated for the maximum number of vote.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::remove_other_vote())]
+		#[pallet::weight(T::WeightInfo::remove_other_vote()
+			.max(T::WeightInfo::vote_override(T::MaxDelegations::get())))]
 		pub fn remove_other_vote(
 			origin: OriginFor<T>,
 			target: AccountIdLookupOf<T>,
@@ -396,6 +471,62 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Undelegate the part of the voting power of the sending account delegated to `target`
+		/// for a particular class of polls, keeping its other delegations in place.
+		///
+		/// All of the delegated balance stays locked until the lock period of the conviction of
+		/// this delegation has passed, since the lock cannot tell apart the funds backing the
+		/// remaining delegations.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating to `target`, without any votes overriding its delegates.
+		///
+		/// - `class`: The class of polls to remove the delegation from.
+		/// - `target`: The account delegated to.
+		///
+		/// Emits `UndelegatedFrom`, or `Undelegated` if it was the last delegation.
+		///
+		/// Weight: `O(R)` where R is the number of polls `target` has voted on. Weight is
+		///   initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::undelegate(T::MaxVotes::get(), 1))]
+		pub fn undelegate_from(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let votes = Self::try_undelegate(who, class, Some(target))?;
+			Ok(Some(Self::undelegate_weight(votes)).into())
+		}
+
+		/// Publish the voting policy of the sending account for a particular class of polls, so
+		/// that others can decide whether to delegate to it.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `class`: The class of polls the policy applies to.
+		/// - `policy`: The hash of a document describing the policy, or `None` to clear it.
+		///
+		/// Emits `DelegatePolicySet`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_delegate_policy())]
+		pub fn set_delegate_policy(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			policy: Option<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
+			match policy {
+				Some(policy) => DelegatePolicies::<T, I>::insert(&who, &class, policy),
+				None => DelegatePolicies::<T, I>::remove(&who, &class),
+			}
+			Self::deposit_event(Event::DelegatePolicySet { who, class, policy });
+			Ok(())
+		}
 	}
 }
 
@@ -406,36 +537,63 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		poll_index: PollIndexOf<T, I>,
 		vote: AccountVote<BalanceOf<T, I>>,
 	) -> DispatchResult {
-		ensure!(
-			vote.balance() <= T::Currency::total_balance(who),
-			Error::<T, I>::InsufficientFunds
-		);
+		ensure!(vote.balance() <= Self::voting_balance(who), Error::<T, I>::InsufficientFunds);
+		T::VotingHooks::on_before_vote(who, poll_index, &vote)?;
 		T::Polls::try_access_poll(poll_index, |poll_status| {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T, I>::NotOngoing)?;
-			VotingFor::<T, I>::try_mutate(who, &class, |voting| {
-				if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
-					match votes.binary_search_by_key(&poll_index, |i| i.0) {
-						Ok(i) => {
-							// Shouldn't be possible to fail, but we handle it gracefully.
-							tally.remove(votes[i].1).ok_or(ArithmeticError::Underflow)?;
-							if let Some(approve) = votes[i].1.as_standard() {
-								tally.reduce(approve, *delegations);
-							}
-							votes[i].1 = vote;
-						},
-						Err(i) => {
-							votes
-								.try_insert(i, (poll_index, vote))
-								.map_err(|_| Error::<T, I>::MaxVotesReached)?;
-						},
-					}
-					// Shouldn't be possible to fail, but we handle it gracefully.
-					tally.add(vote).ok_or(ArithmeticError::Overflow)?;
-					if let Some(approve) = vote.as_standard() {
-						tally.increase(approve, *delegations);
-					}
-				} else {
-					return Err(Error::<T, I>::AlreadyDelegating.into())
+			VotingFor::<T, I>::try_mutate(who, &class, |voting| -> DispatchResult {
+				match voting {
+					Voting::Casting(Casting { ref mut votes, delegations, .. }) => {
+						// delegators who voted on this poll themselves don't count for us.
+						let delegations = delegations
+							.saturating_sub(RetractedDelegations::<T, I>::get(who, poll_index));
+						match votes.binary_search_by_key(&poll_index, |i| i.0) {
+							Ok(i) => {
+								// Shouldn't be possible to fail, but we handle it gracefully.
+								tally.remove(votes[i].1).ok_or(ArithmeticError::Underflow)?;
+								if let Some(approve) = votes[i].1.as_standard() {
+									tally.reduce(approve, delegations);
+								}
+								votes[i].1 = vote;
+							},
+							Err(i) => {
+								votes
+									.try_insert(i, (poll_index, vote))
+									.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+							},
+						}
+						// Shouldn't be possible to fail, but we handle it gracefully.
+						tally.add(vote).ok_or(ArithmeticError::Overflow)?;
+						if let Some(approve) = vote.as_standard() {
+							tally.increase(approve, delegations);
+						}
+					},
+					Voting::Delegating(Delegating { ref targets, ref mut overrides, .. }) => {
+						match overrides.binary_search_by_key(&poll_index, |i| i.0) {
+							Ok(i) => {
+								// Shouldn't be possible to fail, but we handle it gracefully.
+								tally.remove(overrides[i].1).ok_or(ArithmeticError::Underflow)?;
+								overrides[i].1 = vote;
+							},
+							Err(i) => {
+								overrides
+									.try_insert(i, (poll_index, vote))
+									.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+								// take our voting power back from the delegates on this poll.
+								for d in targets.iter() {
+									Self::retract_delegation(
+										&d.target,
+										&class,
+										poll_index,
+										tally,
+										d.conviction.votes(d.balance),
+									);
+								}
+							},
+						}
+						// Shouldn't be possible to fail, but we handle it gracefully.
+						tally.add(vote).ok_or(ArithmeticError::Overflow)?;
+					},
 				}
 				// Extend the lock to `balance` (rather than setting it) since we don't know what
 				// other votes are in place.
@@ -451,6 +609,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - The poll has finished and the voter lost their direction.
 	/// - The poll has finished and the voter's lock period is up.
 	///
+	/// If the vote overrode delegations, the delegated voting power is given back to the
+	/// delegates on the poll.
+	///
 	/// This will generally be combined with a call to `unlock`.
 	fn try_remove_vote(
 		who: &T::AccountId,
@@ -461,48 +622,135 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let class = class_hint
 			.or_else(|| Some(T::Polls::as_ongoing(poll_index)?.1))
 			.ok_or(Error::<T, I>::ClassNeeded)?;
-		VotingFor::<T, I>::try_mutate(who, class, |voting| {
-			if let Voting::Casting(Casting { ref mut votes, delegations, ref mut prior }) = voting {
-				let i = votes
-					.binary_search_by_key(&poll_index, |i| i.0)
-					.map_err(|_| Error::<T, I>::NotVoter)?;
-				let v = votes.remove(i);
-
-				T::Polls::try_access_poll(poll_index, |poll_status| match poll_status {
-					PollStatus::Ongoing(tally, _) => {
-						ensure!(matches!(scope, UnvoteScope::Any), Error::<T, I>::NoPermission);
-						// Shouldn't be possible to fail, but we handle it gracefully.
-						tally.remove(v.1).ok_or(ArithmeticError::Underflow)?;
-						if let Some(approve) = v.1.as_standard() {
-							tally.reduce(approve, *delegations);
-						}
-						Self::deposit_event(Event::VoteRemoved { who: who.clone(), vote: v.1 });
-						Ok(())
-					},
-					PollStatus::Completed(end, approved) => {
-						if let Some((lock_periods, balance)) = v.1.locked_if(approved) {
-							let unlock_at = end.saturating_add(
-								T::VoteLockingPeriod::get().saturating_mul(lock_periods.into()),
-							);
-							let now = T::BlockNumberProvider::current_block_number();
-							if now < unlock_at {
-								ensure!(
-									matches!(scope, UnvoteScope::Any),
-									Error::<T, I>::NoPermissionYet
+		VotingFor::<T, I>::try_mutate(who, &class, |voting| {
+			let (votes, prior, delegations, targets) = match voting {
+				Voting::Casting(Casting { ref mut votes, delegations, ref mut prior }) =>
+					(votes, prior, *delegations, None),
+				Voting::Delegating(Delegating {
+					ref targets,
+					ref mut overrides,
+					delegations,
+					ref mut prior,
+				}) => (overrides, prior, *delegations, Some(targets)),
+			};
+			let i = votes
+				.binary_search_by_key(&poll_index, |i| i.0)
+				.map_err(|_| Error::<T, I>::NotVoter)?;
+			let v = votes.remove(i);
+
+			T::Polls::try_access_poll(poll_index, |poll_status| match poll_status {
+				PollStatus::Ongoing(tally, _) => {
+					ensure!(matches!(scope, UnvoteScope::Any), Error::<T, I>::NoPermission);
+					// Shouldn't be possible to fail, but we handle it gracefully.
+					tally.remove(v.1).ok_or(ArithmeticError::Underflow)?;
+					match targets {
+						Some(targets) =>
+							for d in targets.iter() {
+								Self::restore_delegation(
+									&d.target,
+									&class,
+									poll_index,
+									Some(&mut *tally),
+									d.conviction.votes(d.balance),
 								);
-								prior.accumulate(unlock_at, balance)
-							}
+							},
+						None =>
+							if let Some(approve) = v.1.as_standard() {
+								let delegations = delegations.saturating_sub(
+									RetractedDelegations::<T, I>::get(who, poll_index),
+								);
+								tally.reduce(approve, delegations);
+							},
+					}
+					Self::deposit_event(Event::VoteRemoved { who: who.clone(), vote: v.1 });
+					Ok(())
+				},
+				PollStatus::Completed(end, approved) => {
+					if let Some((lock_periods, balance)) = v.1.locked_if(approved) {
+						let unlock_at = end.saturating_add(
+							T::VoteLockingPeriod::get().saturating_mul(lock_periods.into()),
+						);
+						let now = T::BlockNumberProvider::current_block_number();
+						if now < unlock_at {
+							ensure!(
+								matches!(scope, UnvoteScope::Any),
+								Error::<T, I>::NoPermissionYet
+							);
+							prior.accumulate(unlock_at, balance)
 						}
-						Ok(())
-					},
-					PollStatus::None => Ok(()), // Poll was cancelled.
-				})
-			} else {
-				Ok(())
-			}
+					}
+					for d in targets.iter().flat_map(|t| t.iter()) {
+						Self::restore_delegation(
+							&d.target,
+							&class,
+							poll_index,
+							None,
+							d.conviction.votes(d.balance),
+						);
+					}
+					Ok(())
+				},
+				PollStatus::None => {
+					// Poll was cancelled.
+					for d in targets.iter().flat_map(|t| t.iter()) {
+						Self::restore_delegation(
+							&d.target,
+							&class,
+							poll_index,
+							None,
+							d.conviction.votes(d.balance),
+						);
+					}
+					Ok(())
+				},
+			})
 		})
 	}
 
+	/// Take `amount` of delegated voting power away from `target` on a poll, since one of its
+	/// delegators voted on the poll directly.
+	fn retract_delegation(
+		target: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		tally: &mut TallyOf<T, I>,
+		amount: Delegations<BalanceOf<T, I>>,
+	) {
+		RetractedDelegations::<T, I>::mutate(target, poll_index, |retracted| {
+			*retracted = retracted.saturating_add(amount)
+		});
+		if let Voting::Casting(Casting { votes, .. }) = VotingFor::<T, I>::get(target, class) {
+			if let Ok(i) = votes.binary_search_by_key(&poll_index, |i| i.0) {
+				if let Some(approve) = votes[i].1.as_standard() {
+					tally.reduce(approve, amount);
+				}
+			}
+		}
+	}
+
+	/// Give `amount` of delegated voting power retracted on a poll back to `target`. The tally is
+	/// only given if the poll is still ongoing.
+	fn restore_delegation(
+		target: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		tally: Option<&mut TallyOf<T, I>>,
+		amount: Delegations<BalanceOf<T, I>>,
+	) {
+		RetractedDelegations::<T, I>::mutate_exists(target, poll_index, |retracted| {
+			let left = retracted.unwrap_or_default().saturating_sub(amount);
+			*retracted = (left != Default::default()).then_some(left);
+		});
+		let Some(tally) = tally else { return };
+		if let Voting::Casting(Casting { votes, .. }) = VotingFor::<T, I>::get(target, class) {
+			if let Ok(i) = votes.binary_search_by_key(&poll_index, |i| i.0) {
+				if let Some(approve) = votes[i].1.as_standard() {
+					tally.increase(approve, amount);
+				}
+			}
+		}
+	}
+
 	/// Return the number of votes for `who`.
 	fn increase_upstream_delegation(
 		who: &T::AccountId,
@@ -559,7 +807,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
-	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`.
+	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`,
+	/// in addition to any other delegations of `who` in the class.
 	///
 	/// Return the upstream number of votes.
 	fn try_delegate(
@@ -571,79 +820,134 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> Result<u32, DispatchError> {
 		ensure!(who != target, Error::<T, I>::Nonsense);
 		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
-		ensure!(balance <= T::Currency::total_balance(&who), Error::<T, I>::InsufficientFunds);
+		ensure!(balance <= Self::voting_balance(&who), Error::<T, I>::InsufficientFunds);
+		T::VotingHooks::on_before_delegate(&who, &target, balance)?;
 		let votes =
 			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
-				let old = core::mem::replace(
-					voting,
-					Voting::Delegating(Delegating {
-						balance,
-						target: target.clone(),
-						conviction,
-						delegations: Default::default(),
-						prior: Default::default(),
-					}),
-				);
-				match old {
-					Voting::Delegating(Delegating { .. }) =>
-						return Err(Error::<T, I>::AlreadyDelegating.into()),
+				let mut delegating = match core::mem::take(voting) {
+					Voting::Delegating(delegating) => {
+						// delegations can't change under votes overriding them.
+						ensure!(delegating.overrides.is_empty(), Error::<T, I>::AlreadyVoting);
+						ensure!(
+							delegating.targets.iter().all(|d| d.target != target),
+							Error::<T, I>::AlreadyDelegating
+						);
+						delegating
+					},
 					Voting::Casting(Casting { votes, delegations, prior }) => {
 						// here we just ensure that we're currently idling with no votes recorded.
 						ensure!(votes.is_empty(), Error::<T, I>::AlreadyVoting);
-						voting.set_common(delegations, prior);
+						Delegating {
+							targets: Default::default(),
+							overrides: Default::default(),
+							delegations,
+							prior,
+						}
 					},
-				}
+				};
+				delegating
+					.targets
+					.try_push(Delegation { target: target.clone(), balance, conviction })
+					.map_err(|_| Error::<T, I>::MaxDelegationsReached)?;
+				let total = delegating.balance();
+				ensure!(total <= Self::voting_balance(&who), Error::<T, I>::InsufficientFunds);
+				*voting = Voting::Delegating(delegating);
 
 				let votes =
 					Self::increase_upstream_delegation(&target, &class, conviction.votes(balance));
-				// Extend the lock to `balance` (rather than setting it) since we don't know what
+				// Extend the lock to `total` (rather than setting it) since we don't know what
 				// other votes are in place.
-				Self::extend_lock(&who, &class, balance);
+				Self::extend_lock(&who, &class, total);
 				Ok(votes)
 			})?;
 		Self::deposit_event(Event::<T, I>::Delegated(who, target));
 		Ok(votes)
 	}
 
-	/// Attempt to end the current delegation.
+	/// Attempt to end the current delegation to `target`, or all current delegations if `None`.
 	///
-	/// Return the number of votes of upstream.
-	fn try_undelegate(who: T::AccountId, class: ClassOf<T, I>) -> Result<u32, DispatchError> {
-		let votes =
-			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
-				match core::mem::replace(voting, Voting::default()) {
-					Voting::Delegating(Delegating {
-						balance,
-						target,
-						conviction,
-						delegations,
-						mut prior,
-					}) => {
-						// remove any delegation votes to our current target.
-						let votes = Self::reduce_upstream_delegation(
-							&target,
-							&class,
-							conviction.votes(balance),
-						);
-						let now = T::BlockNumberProvider::current_block_number();
-						let lock_periods = conviction.lock_periods().into();
-						prior.accumulate(
-							now.saturating_add(
-								T::VoteLockingPeriod::get().saturating_mul(lock_periods),
-							),
-							balance,
-						);
-						voting.set_common(delegations, prior);
-
-						Ok(votes)
+	/// Return the number of votes of upstream, for each delegation ended.
+	fn try_undelegate(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: Option<T::AccountId>,
+	) -> Result<Vec<u32>, DispatchError> {
+		let (votes, remaining) = VotingFor::<T, I>::try_mutate(
+			&who,
+			&class,
+			|voting| -> Result<(Vec<u32>, bool), DispatchError> {
+				let Voting::Delegating(delegating) = voting else {
+					return Err(Error::<T, I>::NotDelegating.into())
+				};
+				ensure!(delegating.overrides.is_empty(), Error::<T, I>::AlreadyVoting);
+				let total = delegating.balance();
+				let removed = match target {
+					Some(ref target) => {
+						let i = delegating
+							.targets
+							.iter()
+							.position(|d| &d.target == target)
+							.ok_or(Error::<T, I>::NotDelegating)?;
+						alloc::vec![delegating.targets.remove(i)]
 					},
-					Voting::Casting(_) => Err(Error::<T, I>::NotDelegating.into()),
+					None => core::mem::take(&mut delegating.targets).into_inner(),
+				};
+
+				let now = T::BlockNumberProvider::current_block_number();
+				let mut votes = Vec::with_capacity(removed.len());
+				for Delegation { target, balance, conviction } in removed {
+					// remove any delegation votes to this target.
+					votes.push(Self::reduce_upstream_delegation(
+						&target,
+						&class,
+						conviction.votes(balance),
+					));
+					// the lock can't tell apart the funds behind each delegation, so all of them
+					// stay locked for the period of this one.
+					let lock_periods = conviction.lock_periods().into();
+					delegating.prior.accumulate(
+						now.saturating_add(
+							T::VoteLockingPeriod::get().saturating_mul(lock_periods),
+						),
+						total,
+					);
 				}
-			})?;
-		Self::deposit_event(Event::<T, I>::Undelegated(who));
+
+				let remaining = !delegating.targets.is_empty();
+				if !remaining {
+					let (delegations, prior) = (delegating.delegations, delegating.prior);
+					*voting = Voting::default();
+					voting.set_common(delegations, prior);
+				}
+				Ok((votes, remaining))
+			},
+		)?;
+		match target {
+			Some(target) if remaining =>
+				Self::deposit_event(Event::<T, I>::UndelegatedFrom { who, target }),
+			_ => Self::deposit_event(Event::<T, I>::Undelegated(who)),
+		}
 		Ok(votes)
 	}
 
+	/// The actual weight of undelegating, given the number of upstream votes of each delegation
+	/// ended.
+	///
+	/// The delegates all vote on the ongoing polls of the same class, so the polls touched are
+	/// bounded by the delegate with the most votes rather than by their sum.
+	fn undelegate_weight(votes: Vec<u32>) -> Weight {
+		let most_votes = votes.iter().copied().max().unwrap_or_default();
+		T::WeightInfo::undelegate(most_votes, votes.len() as u32)
+	}
+
+	/// The balance of `who` which may be used to vote or delegate.
+	///
+	/// This is the total balance, including any held funds, less whatever
+	/// [`Config::VotingHooks`] excludes.
+	pub fn voting_balance(who: &T::AccountId) -> BalanceOf<T, I> {
+		T::Currency::total_balance(who).saturating_sub(T::VotingHooks::excluded_balance(who))
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -0,0 +1,303 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the conviction voting pallet.
+
+use super::*;
+use crate::vote::PriorLock;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Initial version of storage types.
+pub mod v0 {
+	use super::*;
+
+	pub type VotingOf<T, I> = Voting<
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T, I>,
+		PollIndexOf<T, I>,
+		<T as Config<I>>::MaxVotes,
+	>;
+
+	/// Information concerning the delegation of some voting power, to a single target.
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Delegating<Balance, AccountId, BlockNumber> {
+		/// The amount of balance delegated.
+		pub balance: Balance,
+		/// The account to which the voting power is delegated.
+		pub target: AccountId,
+		/// The conviction with which the voting power is delegated.
+		pub conviction: Conviction,
+		/// The total amount of delegations that this account has received.
+		pub delegations: Delegations<Balance>,
+		/// Any pre-existing locks from past voting/delegating activity.
+		pub prior: PriorLock<BlockNumber, Balance>,
+	}
+
+	/// An indicator for what an account is doing; it can either be delegating or voting.
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(MaxVotes))]
+	#[codec(mel_bound(
+		Balance: MaxEncodedLen, AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen,
+		PollIndex: MaxEncodedLen,
+	))]
+	pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+	where
+		MaxVotes: Get<u32>,
+	{
+		/// The account is voting directly.
+		Casting(Casting<Balance, BlockNumber, PollIndex, MaxVotes>),
+		/// The account is delegating `balance` of its balance to a `target` account with
+		/// `conviction`.
+		Delegating(Delegating<Balance, AccountId, BlockNumber>),
+	}
+}
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::conviction-voting::migration::v1";
+
+	/// The prefix of the identifier of this migration, followed by the name of the pallet instance.
+	const PALLET_MIGRATIONS_ID: &[u8; 24] = b"pallet-conviction-voting";
+
+	/// The voting records in their v0 format.
+	#[frame_support::storage_alias]
+	pub type VotingFor<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		v0::VotingOf<T, I>,
+	>;
+
+	/// Turns the single delegation of each delegating account into a list of delegations holding
+	/// just that one, without any overriding votes.
+	///
+	/// The number of voting records is unbounded, so they are migrated over multiple blocks through
+	/// `pallet-migrations`. The migration does nothing unless the on-chain storage version is 0.
+	pub struct LazyMigrationV0ToV1<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> SteppedMigration for LazyMigrationV0ToV1<T, I> {
+		/// The keys of the last migrated voting record.
+		type Cursor = (T::AccountId, ClassOf<T, I>);
+		type Identifier = BoundedVec<u8, ConstU32<64>>;
+
+		fn id() -> Self::Identifier {
+			// Every instance of the pallet needs to be migrated on its own.
+			let id = (PALLET_MIGRATIONS_ID, Pallet::<T, I>::name(), 0u16, 1u16).encode();
+			BoundedVec::truncate_from(id)
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T, I>::on_chain_storage_version() != 0 {
+				log::warn!(target: TARGET, "skipping migration from v0 to v1.");
+				return Ok(None)
+			}
+
+			let required = T::WeightInfo::migration_v1_step();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required })
+			}
+
+			while meter.try_consume(required).is_ok() {
+				match Self::migrate_next(cursor.as_ref()) {
+					Some(next) => cursor = Some(next),
+					None => {
+						StorageVersion::new(1).put::<Pallet<T, I>>();
+						log::info!(target: TARGET, "migrated all voting records.");
+						return Ok(None)
+					},
+				}
+			}
+
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let voting_count = VotingFor::<T, I>::iter_keys().count();
+			log::info!(
+				target: TARGET,
+				"pre-upgrade state contains '{}' voting records.",
+				voting_count
+			);
+			Ok((voting_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(on_chain_version == 1, "must upgrade from version 0 to 1.");
+			let pre_voting_count: u32 = Decode::decode(&mut &state[..])
+				.expect("failed to decode the state from pre-upgrade.");
+			let post_voting_count = crate::VotingFor::<T, I>::iter().count() as u32;
+			ensure!(post_voting_count == pre_voting_count, "must migrate all voting records.");
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> LazyMigrationV0ToV1<T, I> {
+		/// Migrate the voting record following `last`, or the first one if `last` is `None`.
+		///
+		/// Returns the keys of the migrated record, or `None` if all records were migrated.
+		pub(crate) fn migrate_next(
+			last: Option<&(T::AccountId, ClassOf<T, I>)>,
+		) -> Option<(T::AccountId, ClassOf<T, I>)> {
+			let mut iter = match last {
+				Some((who, class)) =>
+					VotingFor::<T, I>::iter_from(VotingFor::<T, I>::hashed_key_for(who, class)),
+				None => VotingFor::<T, I>::iter(),
+			};
+			let (who, class, old) = iter.next()?;
+			// The encoding of casting accounts did not change.
+			if let v0::Voting::Delegating(v0::Delegating {
+				balance,
+				target,
+				conviction,
+				delegations,
+				prior,
+			}) = old
+			{
+				crate::VotingFor::<T, I>::insert(
+					&who,
+					&class,
+					Voting::Delegating(Delegating {
+						targets: BoundedVec::truncate_from(alloc::vec![Delegation {
+							target,
+							balance,
+							conviction,
+						}]),
+						overrides: Default::default(),
+						delegations,
+						prior,
+					}),
+				);
+			}
+			Some((who, class))
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<T: Config<I>, I: 'static> LazyMigrationV0ToV1<T, I> {
+		/// Put storage back to version 0 with a single delegating record, carrying delegations
+		/// and a prior lock so that its encoding has full size.
+		///
+		/// Returns the keys of the record.
+		pub(crate) fn setup_benchmark_env_for_migration() -> (T::AccountId, ClassOf<T, I>) {
+			let who: T::AccountId = frame_benchmarking::account("delegator", 0, 0);
+			let target: T::AccountId = frame_benchmarking::account("target", 0, 0);
+			let class = T::Polls::max_ongoing().0;
+			let mut prior = PriorLock::default();
+			prior.accumulate(1u32.into(), 100u32.into());
+			let old = v0::Voting::Delegating(v0::Delegating {
+				balance: 100u32.into(),
+				target,
+				conviction: Conviction::Locked1x,
+				delegations: Delegations { votes: 100u32.into(), capital: 100u32.into() },
+				prior,
+			});
+			VotingFor::<T, I>::insert(&who, &class, old);
+			StorageVersion::new(0).put::<Pallet<T, I>>();
+			(who, class)
+		}
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::{migrations::SteppedMigration, storage::unhashed, weights::WeightMeter};
+
+	#[test]
+	fn migration_v0_to_v1_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			let mut prior = PriorLock::default();
+			prior.accumulate(5, 10);
+			let delegating =
+				v0::Voting::<u64, u64, u64, u8, ConstU32<3>>::Delegating(v0::Delegating {
+					balance: 20,
+					target: 2,
+					conviction: Conviction::Locked2x,
+					delegations: Delegations { votes: 5, capital: 3 },
+					prior,
+				});
+			let casting = v0::Voting::<u64, u64, u64, u8, ConstU32<3>>::Casting(Casting {
+				votes: Default::default(),
+				delegations: Default::default(),
+				prior,
+			});
+			unhashed::put_raw(&VotingFor::<Test>::hashed_key_for(1, 0), &delegating.encode());
+			unhashed::put_raw(&VotingFor::<Test>::hashed_key_for(3, 0), &casting.encode());
+
+			// Only one record is migrated per step if there is only weight for one.
+			let one_record = <() as WeightInfo>::migration_v1_step();
+			let mut cursor = None;
+			for _ in 0..2 {
+				cursor = v1::LazyMigrationV0ToV1::<Test>::step(
+					cursor,
+					&mut WeightMeter::with_limit(one_record),
+				)
+				.unwrap();
+				assert!(cursor.is_some());
+			}
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 0);
+			let cursor = v1::LazyMigrationV0ToV1::<Test>::step(
+				cursor,
+				&mut WeightMeter::with_limit(one_record),
+			);
+			assert!(cursor.unwrap().is_none());
+
+			assert_eq!(
+				VotingFor::<Test>::get(1, 0),
+				Voting::Delegating(Delegating {
+					targets: BoundedVec::truncate_from(alloc::vec![Delegation {
+						target: 2,
+						balance: 20,
+						conviction: Conviction::Locked2x,
+					}]),
+					overrides: Default::default(),
+					delegations: Delegations { votes: 5, capital: 3 },
+					prior,
+				})
+			);
+			assert_eq!(
+				VotingFor::<Test>::get(3, 0),
+				Voting::Casting(Casting {
+					votes: Default::default(),
+					delegations: Default::default(),
+					prior,
+				})
+			);
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		});
+	}
+}
@@ -20,9 +20,12 @@
 use std::collections::BTreeMap;
 
 use frame_support::{
-	assert_noop, assert_ok, derive_impl, parameter_types,
+	assert_noop, assert_ok, derive_impl,
+	dispatch::GetDispatchInfo,
+	parameter_types,
 	traits::{ConstU32, ConstU64, Contains, Polling, VoteTally},
 };
+use sp_core::H256;
 use sp_runtime::BuildStorage;
 
 use super::*;
@@ -146,15 +149,43 @@ impl Polling<TallyOf<Test>> for TestPolls {
 	}
 }
 
+parameter_types! {
+	pub static ExcludedBalance: BTreeMap<u64, u64> = BTreeMap::new();
+	pub static BlockedVoters: Vec<u64> = vec![];
+}
+
+pub struct TestVotingHooks;
+impl VotingHooks<u64, u8, u64> for TestVotingHooks {
+	fn on_before_vote(who: &u64, _: u8, _: &AccountVote<u64>) -> DispatchResult {
+		ensure!(!BlockedVoters::get().contains(who), DispatchError::Other("blocked"));
+		Ok(())
+	}
+
+	fn on_before_delegate(who: &u64, _: &u64, _: u64) -> DispatchResult {
+		ensure!(!BlockedVoters::get().contains(who), DispatchError::Other("blocked"));
+		Ok(())
+	}
+
+	fn excluded_balance(who: &u64) -> u64 {
+		ExcludedBalance::get().get(who).copied().unwrap_or_default()
+	}
+
+	fn weight() -> Weight {
+		Weight::from_parts(1_000, 100)
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Self>;
 	type VoteLockingPeriod = ConstU64<3>;
 	type MaxVotes = ConstU32<3>;
+	type MaxDelegations = ConstU32<3>;
 	type WeightInfo = ();
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = TestPolls;
 	type BlockNumberProvider = System;
+	type VotingHooks = TestVotingHooks;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -827,11 +858,11 @@ fn errors_with_vote_work() {
 		);
 
 		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 10));
-		assert_noop!(
-			Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)),
-			Error::<Test>::AlreadyDelegating
-		);
+		// Voting while delegating overrides the delegation, which then can't be removed.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)));
+		assert_noop!(Voting::undelegate(RuntimeOrigin::signed(1), 0), Error::<Test>::AlreadyVoting);
 
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 3));
 		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
 		Polls::set(
 			vec![
@@ -913,3 +944,206 @@ fn errors_with_remove_vote_work() {
 		);
 	});
 }
+
+#[test]
+fn voting_hooks_work() {
+	new_test_ext().execute_with(|| {
+		// Held funds still back votes.
+		assert_ok!(Balances::reserve(&1, 4));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)));
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), None, 3));
+
+		// Excluded funds do not.
+		ExcludedBalance::set(vec![(1, 3)].into_iter().collect());
+		assert_eq!(Voting::voting_balance(&1), 7);
+		assert_noop!(
+			Voting::vote(RuntimeOrigin::signed(1), 3, aye(8, 0)),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 8),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(7, 0)));
+		assert_eq!(tally(3), Tally::from_parts(0, 0, 7));
+
+		// The hook may reject a vote.
+		BlockedVoters::set(vec![2]);
+		assert_noop!(
+			Voting::vote(RuntimeOrigin::signed(2), 3, aye(10, 0)),
+			DispatchError::Other("blocked")
+		);
+		// And a delegation.
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::None, 10),
+			DispatchError::Other("blocked")
+		);
+
+		// A larger exclusion does not reach back to a vote already cast.
+		ExcludedBalance::set(vec![(1, 10)].into_iter().collect());
+		assert_eq!(tally(3), Tally::from_parts(0, 0, 7));
+	});
+}
+
+#[test]
+fn voting_hooks_weight_is_charged() {
+	let hooks = <TestVotingHooks as VotingHooks<u64, u8, u64>>::weight();
+	let vote = Call::<Test>::vote { poll_index: 3, vote: aye(10, 0) }.get_dispatch_info();
+	assert_eq!(
+		vote.call_weight,
+		<() as WeightInfo>::vote_new()
+			.max(<() as WeightInfo>::vote_existing())
+			.max(<() as WeightInfo>::vote_override(3))
+			.saturating_add(hooks)
+	);
+	let delegate =
+		Call::<Test>::delegate { class: 0, to: 2, conviction: Conviction::None, balance: 10 }
+			.get_dispatch_info();
+	assert_eq!(delegate.call_weight, <() as WeightInfo>::delegate(3).saturating_add(hooks));
+}
+
+#[test]
+fn split_delegation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 1)));
+
+		// 5 splits its whole balance between 2 and 3.
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 2, Conviction::Locked1x, 30));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 3, Conviction::Locked1x, 20));
+		assert_eq!(tally(0), Tally::from_parts(40, 30, 40));
+		assert_eq!(Balances::usable_balance(5), 0);
+
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(5), 0, 2, Conviction::Locked1x, 0),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(5), 0, 4, Conviction::Locked1x, 1),
+			Error::<Test>::InsufficientFunds
+		);
+
+		// Dropping one delegate keeps the other, but the whole balance stays locked.
+		assert_ok!(Voting::undelegate_from(RuntimeOrigin::signed(5), 0, 3));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::UndelegatedFrom {
+			who: 5,
+			target: 3,
+		}));
+		assert_eq!(tally(0), Tally::from_parts(40, 10, 40));
+		assert_noop!(
+			Voting::undelegate_from(RuntimeOrigin::signed(5), 0, 3),
+			Error::<Test>::NotDelegating
+		);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 0);
+
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 20);
+
+		// Dropping the last delegate ends the delegation.
+		assert_ok!(Voting::undelegate_from(RuntimeOrigin::signed(5), 0, 2));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::Undelegated(5)));
+		assert!(matches!(VotingFor::<Test>::get(5, 0), Voting::Casting(_)));
+		assert_eq!(tally(0), Tally::from_parts(10, 10, 10));
+
+		run_to(7);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 50);
+	});
+}
+
+#[test]
+fn max_delegations_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(6), 0, 2, Conviction::None, 10));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(6), 0, 3, Conviction::None, 10));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(6), 0, 4, Conviction::None, 10));
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(6), 0, 5, Conviction::None, 10),
+			Error::<Test>::MaxDelegationsReached
+		);
+
+		// Undelegating drops all of them.
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(6), 0));
+		assert_eq!(VotingFor::<Test>::get(2, 0), Voting::default());
+		assert_eq!(VotingFor::<Test>::get(4, 0), Voting::default());
+	});
+}
+
+#[test]
+fn overriding_delegations_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![(0, Ongoing(Tally::new(0), 0)), (1, Ongoing(Tally::new(0), 0))]
+				.into_iter()
+				.collect(),
+		);
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 2, Conviction::Locked1x, 30));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 3, Conviction::Locked1x, 20));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, aye(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 1)));
+		assert_eq!(tally(0), Tally::from_parts(40, 30, 40));
+
+		// 5 votes itself on poll 0, taking its voting power back from both delegates there only.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(5), 0, nay(50, 1)));
+		assert_eq!(tally(0), Tally::from_parts(10, 60, 10));
+		assert_eq!(tally(1), Tally::from_parts(40, 0, 40));
+		assert_eq!(RetractedDelegations::<Test>::get(2, 0), Delegations { votes: 30, capital: 30 });
+
+		// Delegates voting anew on the poll don't get the overridden voting power either.
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(3), Some(0), 0));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, aye(10, 1)));
+		assert_eq!(tally(0), Tally::from_parts(20, 50, 20));
+
+		// The override may be changed.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(5), 0, aye(50, 1)));
+		assert_eq!(tally(0), Tally::from_parts(70, 0, 70));
+
+		// Delegations can't change under it.
+		assert_noop!(Voting::undelegate(RuntimeOrigin::signed(5), 0), Error::<Test>::AlreadyVoting);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(5), 0, 4, Conviction::None, 0),
+			Error::<Test>::AlreadyVoting
+		);
+
+		// Removing the override gives the voting power back to the delegates.
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(5), Some(0), 0));
+		assert_eq!(tally(0), Tally::from_parts(70, 0, 70));
+		assert!(!RetractedDelegations::<Test>::contains_key(2, 0));
+		assert!(!RetractedDelegations::<Test>::contains_key(3, 0));
+
+		// An override of a poll which then completes is cleaned up as well.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(5), 1, nay(50, 1)));
+		assert_eq!(tally(1), Tally::from_parts(10, 50, 10));
+		Polls::set(vec![(1, Completed(1, false))].into_iter().collect());
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(5), Some(0), 1));
+		assert!(!RetractedDelegations::<Test>::contains_key(2, 1));
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(5), 0));
+	});
+}
+
+#[test]
+fn delegate_policy_works() {
+	new_test_ext().execute_with(|| {
+		let policy = H256::repeat_byte(1);
+		assert_noop!(
+			Voting::set_delegate_policy(RuntimeOrigin::signed(2), 3, Some(policy)),
+			Error::<Test>::BadClass
+		);
+
+		assert_ok!(Voting::set_delegate_policy(RuntimeOrigin::signed(2), 0, Some(policy)));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::DelegatePolicySet {
+			who: 2,
+			class: 0,
+			policy: Some(policy),
+		}));
+		assert_eq!(DelegatePolicies::<Test>::get(2, 0), Some(policy));
+
+		assert_ok!(Voting::set_delegate_policy(RuntimeOrigin::signed(2), 0, None));
+		assert_eq!(DelegatePolicies::<Test>::get(2, 0), None);
+	});
+}
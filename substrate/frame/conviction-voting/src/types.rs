@@ -20,11 +20,13 @@
 use codec::{Codec, Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::{fmt::Debug, marker::PhantomData};
 use frame_support::{
-	traits::VoteTally, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	traits::{Get, VoteTally},
+	weights::Weight,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{Saturating, Zero},
+	traits::{Convert, Saturating, Zero},
 	RuntimeDebug,
 };
 
@@ -258,3 +260,76 @@ pub enum UnvoteScope {
 	/// Permitted to do only the changes that do not need the owner's permission.
 	OnlyExpired,
 }
+
+/// Hooks into the voting process of the pallet.
+///
+/// These allow the runtime to account for funds of a voter which are also committed elsewhere,
+/// such as stake delegated to a nomination pool that is about to be slashed.
+///
+/// The hooks only run when a vote or delegation is made. Votes and delegations already in place
+/// are not revisited when the excluded balance later grows, so they keep counting in full until
+/// the voter votes or delegates again.
+pub trait VotingHooks<AccountId, Index, Balance> {
+	/// Called before `who` records `vote` on poll `index`. An error rejects the vote.
+	fn on_before_vote(who: &AccountId, index: Index, vote: &AccountVote<Balance>)
+		-> DispatchResult;
+
+	/// Called before `who` delegates `balance` to `target`. An error rejects the delegation.
+	fn on_before_delegate(who: &AccountId, target: &AccountId, balance: Balance) -> DispatchResult;
+
+	/// The part of the balance of `who` which may not back votes or delegations.
+	fn excluded_balance(who: &AccountId) -> Balance;
+
+	/// An upper bound on the weight of `excluded_balance` together with one of the `on_before_*`
+	/// hooks, which is added to the weight of `vote` and `delegate`.
+	fn weight() -> Weight;
+}
+
+impl<AccountId, Index, Balance: Zero> VotingHooks<AccountId, Index, Balance> for () {
+	fn on_before_vote(_: &AccountId, _: Index, _: &AccountVote<Balance>) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_before_delegate(_: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn excluded_balance(_: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// [`VotingHooks`] which accepts every vote and delegation, but excludes the balance reported by
+/// `Excluded` for the voter. `ExcludedWeight` is the weight of a single `Excluded` conversion.
+///
+/// For instance, `Excluded` can report the pending slash of a nomination pool member, whose
+/// delegated stake is held on their own account.
+pub struct ExcludeBalance<Excluded, ExcludedWeight>(PhantomData<(Excluded, ExcludedWeight)>);
+
+impl<AccountId, Index, Balance, Excluded, ExcludedWeight> VotingHooks<AccountId, Index, Balance>
+	for ExcludeBalance<Excluded, ExcludedWeight>
+where
+	AccountId: Clone,
+	Excluded: Convert<AccountId, Balance>,
+	ExcludedWeight: Get<Weight>,
+{
+	fn on_before_vote(_: &AccountId, _: Index, _: &AccountVote<Balance>) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_before_delegate(_: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn excluded_balance(who: &AccountId) -> Balance {
+		Excluded::convert(who.clone())
+	}
+
+	fn weight() -> Weight {
+		ExcludedWeight::get()
+	}
+}
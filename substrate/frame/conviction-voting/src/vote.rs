@@ -161,22 +161,55 @@ impl<BlockNumber: Ord + Copy + Zero, Balance: Ord + Copy + Zero> PriorLock<Block
 	}
 }
 
-/// Information concerning the delegation of some voting power.
+/// A part of an account's voting power delegated to a single target.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct Delegating<Balance, AccountId, BlockNumber> {
-	/// The amount of balance delegated.
-	pub balance: Balance,
+pub struct Delegation<Balance, AccountId> {
 	/// The account to which the voting power is delegated.
 	pub target: AccountId,
+	/// The amount of balance delegated.
+	pub balance: Balance,
 	/// The conviction with which the voting power is delegated. When this gets undelegated, the
 	/// relevant lock begins.
 	pub conviction: Conviction,
+}
+
+/// Information concerning the delegation of some voting power.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxVotes, MaxDelegations))]
+#[codec(mel_bound(
+	Balance: MaxEncodedLen, AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen,
+	PollIndex: MaxEncodedLen,
+))]
+pub struct Delegating<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
+where
+	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
+{
+	/// The parts of the voting power delegated, at most one per target. Their balances add up, so
+	/// the voting power can be split between several delegates.
+	pub targets: BoundedVec<Delegation<Balance, AccountId>, MaxDelegations>,
+	/// Votes cast directly on particular polls. On these polls the delegates are overridden and
+	/// none of the delegated voting power is counted for them.
+	pub overrides: BoundedVec<(PollIndex, AccountVote<Balance>), MaxVotes>,
 	/// The total amount of delegations that this account has received, post-conviction-weighting.
 	pub delegations: Delegations<Balance>,
 	/// Any pre-existing locks from past voting/delegating activity.
 	pub prior: PriorLock<BlockNumber, Balance>,
 }
 
+impl<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
+	Delegating<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
+where
+	Balance: Saturating + Zero + Copy,
+	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
+{
+	/// The total balance delegated across all targets.
+	pub fn balance(&self) -> Balance {
+		self.targets.iter().fold(Zero::zero(), |a, d| a.saturating_add(d.balance))
+	}
+}
+
 /// Information concerning the direct vote-casting of some voting power.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxVotes))]
@@ -195,25 +228,28 @@ where
 
 /// An indicator for what an account is doing; it can either be delegating or voting.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-#[scale_info(skip_type_params(MaxVotes))]
+#[scale_info(skip_type_params(MaxVotes, MaxDelegations))]
 #[codec(mel_bound(
 	Balance: MaxEncodedLen, AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen,
 	PollIndex: MaxEncodedLen,
 ))]
-pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	/// The account is voting directly.
 	Casting(Casting<Balance, BlockNumber, PollIndex, MaxVotes>),
-	/// The account is delegating `balance` of its balance to a `target` account with `conviction`.
-	Delegating(Delegating<Balance, AccountId, BlockNumber>),
+	/// The account is delegating parts of its balance to one or more `targets`, possibly
+	/// overriding them on some polls.
+	Delegating(Delegating<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>),
 }
 
-impl<Balance: Default, AccountId, BlockNumber: Zero, PollIndex, MaxVotes> Default
-	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+impl<Balance: Default, AccountId, BlockNumber: Zero, PollIndex, MaxVotes, MaxDelegations> Default
+	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	fn default() -> Self {
 		Voting::Casting(Casting {
@@ -224,10 +260,12 @@ where
 	}
 }
 
-impl<Balance, AccountId, BlockNumber, PollIndex, MaxVotes> AsMut<PriorLock<BlockNumber, Balance>>
-	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+impl<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
+	AsMut<PriorLock<BlockNumber, Balance>>
+	for Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	fn as_mut(&mut self) -> &mut PriorLock<BlockNumber, Balance> {
 		match self {
@@ -243,9 +281,11 @@ impl<
 		AccountId,
 		PollIndex,
 		MaxVotes,
-	> Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+		MaxDelegations,
+	> Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes, MaxDelegations>
 where
 	MaxVotes: Get<u32>,
+	MaxDelegations: Get<u32>,
 {
 	pub fn rejig(&mut self, now: BlockNumber) {
 		AsMut::<PriorLock<BlockNumber, Balance>>::as_mut(self).rejig(now);
@@ -256,7 +296,12 @@ where
 		match self {
 			Voting::Casting(Casting { votes, prior, .. }) =>
 				votes.iter().map(|i| i.1.balance()).fold(prior.locked(), |a, i| a.max(i)),
-			Voting::Delegating(Delegating { balance, prior, .. }) => *balance.max(&prior.locked()),
+			// the parts delegated are disjoint, while direct votes may reuse the same funds.
+			Voting::Delegating(delegating) => delegating
+				.overrides
+				.iter()
+				.map(|i| i.1.balance())
+				.fold(delegating.balance().max(delegating.prior.locked()), |a, i| a.max(i)),
 		}
 	}
 
@@ -77,8 +77,11 @@ pub trait WeightInfo {
 	fn remove_vote() -> Weight;
 	fn remove_other_vote() -> Weight;
 	fn delegate(r: u32, ) -> Weight;
-	fn undelegate(r: u32, ) -> Weight;
+	fn undelegate(r: u32, d: u32, ) -> Weight;
 	fn unlock() -> Weight;
+	fn vote_override(d: u32, ) -> Weight;
+	fn set_delegate_policy() -> Weight;
+	fn migration_v1_step() -> Weight;
 }
 
 /// Weights for `pallet_conviction_voting` using the Substrate node and recommended hardware.
@@ -195,7 +198,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
-	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
+	/// Storage: `ConvictionVoting::VotingFor` (r:9 w:9)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
@@ -204,19 +207,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[0, 1]`.
-	fn undelegate(r: u32, ) -> Weight {
+	/// The range of component `d` is `[1, 8]`.
+	fn undelegate(r: u32, d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `350 + r * (1264 ±0)`
-		//  Estimated: `109992 + r * (109992 ±0)`
+		//  Measured:  `350 + r * (1264 ±0) + d * (154 ±0)`
+		//  Estimated: `109992 + r * (109992 ±0) + d * (29716 ±0)`
 		// Minimum execution time: 26_552_000 picoseconds.
-		Weight::from_parts(28_629_093, 109992)
+		Weight::from_parts(10_524_093, 109992)
 			// Standard Error: 178_796
-			.saturating_add(Weight::from_parts(38_342_206, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_parts(306_737_648, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(18_105_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
@@ -237,6 +245,56 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:9 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27641), added: 30116, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::RetractedDelegations` (r:8 w:8)
+	/// Proof: `ConvictionVoting::RetractedDelegations` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 8]`.
+	fn vote_override(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893 + d * (391 ±0)`
+		//  Estimated: `31106 + d * (2563 ±0)`
+		// Minimum execution time: 61_241_000 picoseconds.
+		Weight::from_parts(61_248_117, 31106)
+			// Standard Error: 4_454
+			.saturating_add(Weight::from_parts(9_412_305, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::DelegatePolicies` (r:0 w:1)
+	/// Proof: `ConvictionVoting::DelegatePolicies` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	fn set_delegate_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 11_724_000 picoseconds.
+		Weight::from_parts(11_731_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConvictionVoting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `ConvictionVoting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389`
+		//  Estimated: `60422`
+		// Minimum execution time: 23_106_000 picoseconds.
+		Weight::from_parts(24_018_000, 60422)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -352,7 +410,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
-	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
+	/// Storage: `ConvictionVoting::VotingFor` (r:9 w:9)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
@@ -361,19 +419,24 @@ impl WeightInfo for () {
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[0, 1]`.
-	fn undelegate(r: u32, ) -> Weight {
+	/// The range of component `d` is `[1, 8]`.
+	fn undelegate(r: u32, d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `350 + r * (1264 ±0)`
-		//  Estimated: `109992 + r * (109992 ±0)`
+		//  Measured:  `350 + r * (1264 ±0) + d * (154 ±0)`
+		//  Estimated: `109992 + r * (109992 ±0) + d * (29716 ±0)`
 		// Minimum execution time: 26_552_000 picoseconds.
-		Weight::from_parts(28_629_093, 109992)
+		Weight::from_parts(10_524_093, 109992)
 			// Standard Error: 178_796
-			.saturating_add(Weight::from_parts(38_342_206, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_parts(306_737_648, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(18_105_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
@@ -394,4 +457,54 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:9 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27641), added: 30116, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::RetractedDelegations` (r:8 w:8)
+	/// Proof: `ConvictionVoting::RetractedDelegations` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 8]`.
+	fn vote_override(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893 + d * (391 ±0)`
+		//  Estimated: `31106 + d * (2563 ±0)`
+		// Minimum execution time: 61_241_000 picoseconds.
+		Weight::from_parts(61_248_117, 31106)
+			// Standard Error: 4_454
+			.saturating_add(Weight::from_parts(9_412_305, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::DelegatePolicies` (r:0 w:1)
+	/// Proof: `ConvictionVoting::DelegatePolicies` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	fn set_delegate_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 11_724_000 picoseconds.
+		Weight::from_parts(11_731_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConvictionVoting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `ConvictionVoting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389`
+		//  Estimated: `60422`
+		// Minimum execution time: 23_106_000 picoseconds.
+		Weight::from_parts(24_018_000, 60422)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -21,7 +21,7 @@
 use frame_support::dispatch::DispatchResult;
 use frame_system::offchain::CreateInherent;
 #[cfg(feature = "experimental")]
-use frame_system::offchain::SubmitTransaction;
+use frame_system::offchain::TaskExtrinsics;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -70,20 +70,15 @@ pub mod pallet {
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		#[cfg(feature = "experimental")]
 		fn offchain_worker(_block_number: BlockNumberFor<T>) {
-			if let Some(key) = Numbers::<T>::iter_keys().next() {
-				// Create a valid task
-				let task = Task::<T>::AddNumberIntoTotal { i: key };
-				let runtime_task = <T as Config>::RuntimeTask::from(task);
-				let call = frame_system::Call::<T>::do_task { task: runtime_task.into() };
-
-				// Submit the task as an unsigned transaction
-				let xt = <T as CreateInherent<frame_system::Call<T>>>::create_inherent(call.into());
-				let res = SubmitTransaction::<T, frame_system::Call<T>>::submit_transaction(xt);
-				match res {
-					Ok(_) => log::info!(target: LOG_TARGET, "Submitted the task."),
-					Err(e) => log::error!(target: LOG_TARGET, "Error submitting task: {:?}", e),
-				}
-			}
+			// Submit one valid task per block as an unsigned transaction, in case block authors
+			// do not include tasks on their own. Submitting every valid task would only fill the
+			// pool with transactions which later blocks have to execute or drop.
+			let tasks = <Task<T> as frame_support::traits::Task>::iter()
+				.take(1)
+				.map(|task| <T as Config>::RuntimeTask::from(task).into());
+			let weight_limit = T::WeightInfo::add_number_into_total();
+			let submitted = TaskExtrinsics::<T>::submit(tasks, weight_limit);
+			log::info!(target: LOG_TARGET, "Submitted {} tasks.", submitted);
 		}
 
 		#[cfg(not(feature = "experimental"))]
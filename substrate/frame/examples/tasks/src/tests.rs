@@ -150,10 +150,12 @@ fn task_with_offchain_worker() {
 		assert!(pool_state.read().transactions.is_empty());
 
 		Numbers::<Runtime>::insert(0, 10);
+		Numbers::<Runtime>::insert(1, 20);
 		assert_eq!(crate::Total::<Runtime>::get(), (0, 0));
 
 		advance_to(2);
 
+		// Only one task is submitted per block.
 		let tx = pool_state.write().transactions.pop().unwrap();
 		assert!(pool_state.read().transactions.is_empty());
 		let tx = Extrinsic::decode(&mut &*tx).unwrap();
@@ -161,3 +163,41 @@ fn task_with_offchain_worker() {
 		assert!(tx.is_bare());
 	});
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn task_extrinsics_are_applied_end_to_end() {
+	use crate::weights::WeightInfo;
+	use frame_support::{dispatch::GetDispatchInfo, weights::Weight};
+	use frame_system::offchain::TaskExtrinsics;
+	use sp_runtime::{
+		generic::{CheckedExtrinsic, ExtrinsicFormat},
+		traits::{Applyable, ExtrinsicLike},
+	};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Numbers::<Runtime>::insert(0, 1);
+		Numbers::<Runtime>::insert(1, 4);
+		Numbers::<Runtime>::insert(2, 9);
+
+		// Only as many tasks as fit into the weight limit are offered.
+		let task_weight = <() as WeightInfo>::add_number_into_total();
+		assert_eq!(TaskExtrinsics::<Runtime>::all(task_weight.saturating_mul(2)).len(), 2);
+
+		// Apply the extrinsics a block author would include.
+		let xts = TaskExtrinsics::<Runtime>::all(Weight::MAX);
+		assert_eq!(xts.len(), 3);
+		for xt in xts {
+			assert!(xt.is_bare());
+			let info = xt.function.get_dispatch_info();
+			let checked: CheckedExtrinsic<u64, _, ()> =
+				CheckedExtrinsic { format: ExtrinsicFormat::Bare, function: xt.function };
+			assert_ok!(checked.apply::<Runtime>(&info, 0).unwrap());
+		}
+
+		assert_eq!(crate::Total::<Runtime>::get(), (3, 14));
+		assert_eq!(Numbers::<Runtime>::iter().count(), 0);
+		assert!(TaskExtrinsics::<Runtime>::all(Weight::MAX).is_empty());
+	});
+}
@@ -117,6 +117,28 @@
 //!
 //! pub type Executive = executive::Executive<Runtime, Block, Context, Runtime, AllPalletsWithSystem, CustomOnRuntimeUpgrade>;
 //! ```
+//!
+//! ### Block composition
+//!
+//! Rules about the order or the number of extrinsics in a block, e.g. that oracle updates must
+//! come first, can be enforced with the optional generic parameter following `OnRuntimeUpgrade`.
+//! It takes a [`ValidateBlockComposition`], which checks every extrinsic that is not an inherent
+//! after its signature was checked and right before it is applied. This happens both when a block
+//! author applies the extrinsic through the `BlockBuilder_apply_extrinsic` runtime api and when a
+//! block is imported, so blocks built by honest authors always satisfy the rules. The weight of
+//! the check is registered as mandatory weight of the block.
+//!
+//! ### Hook scheduling
+//!
+//! The weight left in a block is offered to the `on_poll` and `on_idle` hooks of the pallets one
+//! after another, so the pallets called first can starve the others. How the weight is
+//! distributed is decided by the optional [`HookScheduler`] parameter following
+//! `BlockComposition`. By default the `on_idle` hooks start at a different pallet in every block
+//! while the `on_poll` hooks are called in the order of the pallets. Use
+//! [`RotatingStart`](frame_support::traits::RotatingStart) to rotate both, or
+//! [`WeightShares`](frame_support::traits::WeightShares) to limit pallets to a share of the
+//! weight. [`Executive::simulate_hooks`] reports which pallets got how much weight over a number
+//! of blocks, e.g. through the `HookSimulation` runtime api of `frame-benchmarking`.
 
 #[cfg(doc)]
 #[cfg_attr(doc, aquamarine::aquamarine)]
@@ -161,9 +183,10 @@ use frame_support::{
 	migrations::MultiStepMigrator,
 	pallet_prelude::InvalidTransaction,
 	traits::{
-		BeforeAllRuntimeMigrations, EnsureInherentsAreFirst, ExecuteBlock, OffchainWorker,
-		OnFinalize, OnIdle, OnInitialize, OnPoll, OnRuntimeUpgrade, PostInherents,
-		PostTransactions, PreInherents,
+		BeforeAllRuntimeMigrations, EnsureInherentsAreFirst, ExecuteBlock, HookScheduler,
+		OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnPoll, OnRuntimeUpgrade,
+		PalletsHooksAccess, PostInherents, PostTransactions, PreInherents,
+		ValidateBlockComposition,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -174,7 +197,7 @@ use sp_runtime::{
 		self, Applyable, CheckEqual, Checkable, Dispatchable, Header, NumberFor, One,
 		ValidateUnsigned, Zero,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
 	ApplyExtrinsicResult, ExtrinsicInclusionMode,
 };
 
@@ -207,6 +230,10 @@ pub type OriginOf<E, C> = <CallOf<E, C> as Dispatchable>::RuntimeOrigin;
 ///   used to call hooks e.g. `on_initialize`.
 /// - `OnRuntimeUpgrade`: Custom logic that should be called after a runtime upgrade. Modules are
 ///   already called by `AllPalletsWithSystem`. It will be called before all modules will be called.
+/// - `BlockComposition`: Validates the composition of every block before each extrinsic that is not
+///   an inherent is applied, see [`ValidateBlockComposition`].
+/// - `HookScheduler`: Distributes the remaining weight of a block between the `on_poll` and
+///   `on_idle` hooks of the pallets, see [`HookScheduler`].
 pub struct Executive<
 	System,
 	Block,
@@ -214,6 +241,8 @@ pub struct Executive<
 	UnsignedValidator,
 	AllPalletsWithSystem,
 	OnRuntimeUpgrade = (),
+	BlockComposition = (),
+	HookScheduler = (),
 >(
 	PhantomData<(
 		System,
@@ -222,6 +251,8 @@ pub struct Executive<
 		UnsignedValidator,
 		AllPalletsWithSystem,
 		OnRuntimeUpgrade,
+		BlockComposition,
+		HookScheduler,
 	)>,
 );
 
@@ -239,10 +270,22 @@ impl<
 			+ OnIdle<BlockNumberFor<System>>
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>
-			+ OnPoll<BlockNumberFor<System>>,
+			+ OnPoll<BlockNumberFor<System>>
+			+ PalletsHooksAccess<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
+		BlockComposition: ValidateBlockComposition<CheckedOf<Block::Extrinsic, Context>>,
+		CHookScheduler: HookScheduler<BlockNumberFor<System>>,
 	> ExecuteBlock<Block>
-	for Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+	for Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		BlockComposition,
+		CHookScheduler,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -259,6 +302,8 @@ where
 			UnsignedValidator,
 			AllPalletsWithSystem,
 			COnRuntimeUpgrade,
+			BlockComposition,
+			CHookScheduler,
 		>::execute_block(block);
 	}
 }
@@ -279,10 +324,23 @@ impl<
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>
 			+ OnPoll<BlockNumberFor<System>>
+			+ PalletsHooksAccess<BlockNumberFor<System>>
 			+ TryState<BlockNumberFor<System>>
 			+ TryDecodeEntireStorage,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+		BlockComposition: ValidateBlockComposition<CheckedOf<Block::Extrinsic, Context>>,
+		CHookScheduler: HookScheduler<BlockNumberFor<System>>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		BlockComposition,
+		CHookScheduler,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -335,6 +393,9 @@ where
 			} else {
 				uxt.unchecked_into_checked_i_know_what_i_am_doing(&Default::default())
 			}?;
+			if !is_inherent {
+				Self::validate_block_composition(&xt)?;
+			}
 
 			let dispatch_info = xt.get_dispatch_info();
 			if !is_inherent && !<frame_system::Pallet<System>>::inherents_applied() {
@@ -513,9 +574,22 @@ impl<
 			+ OnIdle<BlockNumberFor<System>>
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>
-			+ OnPoll<BlockNumberFor<System>>,
+			+ OnPoll<BlockNumberFor<System>>
+			+ PalletsHooksAccess<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+		BlockComposition: ValidateBlockComposition<CheckedOf<Block::Extrinsic, Context>>,
+		CHookScheduler: HookScheduler<BlockNumberFor<System>>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		BlockComposition,
+		CHookScheduler,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -728,10 +802,13 @@ where
 		let remaining_weight = max_weight.saturating_sub(weight.total());
 
 		if remaining_weight.all_gt(Weight::zero()) {
-			let used_weight = <AllPalletsWithSystem as OnIdle<BlockNumberFor<System>>>::on_idle(
+			let used_weight = CHookScheduler::on_idle(
 				block_number,
 				remaining_weight,
-			);
+				&AllPalletsWithSystem::pallets_hooks(),
+			)
+			.into_iter()
+			.fold(Weight::zero(), Weight::saturating_add);
 			<frame_system::Pallet<System>>::register_extra_weight_unchecked(
 				used_weight,
 				DispatchClass::Mandatory,
@@ -751,9 +828,10 @@ where
 
 		if remaining.all_gt(Weight::zero()) {
 			let mut meter = WeightMeter::with_limit(remaining);
-			<AllPalletsWithSystem as OnPoll<BlockNumberFor<System>>>::on_poll(
+			CHookScheduler::on_poll(
 				block_number,
 				&mut meter,
+				&AllPalletsWithSystem::pallets_hooks(),
 			);
 			<frame_system::Pallet<System>>::register_extra_weight_unchecked(
 				meter.consumed(),
@@ -762,6 +840,39 @@ where
 		}
 	}
 
+	/// Simulate the `on_poll` and `on_idle` hooks of `blocks` empty blocks following the current
+	/// block.
+	///
+	/// Returns the name of every pallet with the weight it consumed in its `on_poll` and `on_idle`
+	/// hooks over all simulated blocks. The storage changes of the hooks are not reverted, so this
+	/// should only be called where they are discarded afterwards, e.g. in a runtime api.
+	pub fn simulate_hooks(blocks: u32) -> alloc::vec::Vec<(&'static str, Weight, Weight)> {
+		let pallets = AllPalletsWithSystem::pallets_hooks();
+		let block_weights = <System::BlockWeights as frame_support::traits::Get<_>>::get();
+		let remaining = block_weights.max_block.saturating_sub(block_weights.base_block);
+		let mut block_number = <frame_system::Pallet<System>>::block_number();
+		let mut totals = alloc::vec![(Weight::zero(), Weight::zero()); pallets.len()];
+
+		for _ in 0..blocks {
+			block_number += One::one();
+			<frame_system::Pallet<System>>::set_block_number(block_number);
+
+			let mut meter = WeightMeter::with_limit(remaining);
+			let polled = CHookScheduler::on_poll(block_number, &mut meter, &pallets);
+			let idled = CHookScheduler::on_idle(block_number, meter.remaining(), &pallets);
+			for (total, weights) in totals.iter_mut().zip(polled.into_iter().zip(idled)) {
+				total.0.saturating_accrue(weights.0);
+				total.1.saturating_accrue(weights.1);
+			}
+		}
+
+		pallets
+			.iter()
+			.zip(totals)
+			.map(|(p, (poll, idle))| (p.name, poll, idle))
+			.collect()
+	}
+
 	/// Run the `on_finalize` hook of all pallet.
 	fn on_finalize_hook(block_number: NumberFor<Block>) {
 		<AllPalletsWithSystem as OnFinalize<BlockNumberFor<System>>>::on_finalize(block_number);
@@ -786,6 +897,12 @@ where
 		let xt = uxt.check(&Default::default())?;
 		let dispatch_info = xt.get_dispatch_info();
 
+		// Check that the extrinsic may be applied at this point of the block. Block authors skip
+		// rejected extrinsics, while they make an imported block invalid.
+		if !is_inherent {
+			Self::validate_block_composition(&xt)?;
+		}
+
 		if !is_inherent && !<frame_system::Pallet<System>>::inherents_applied() {
 			Self::inherents_applied();
 		}
@@ -812,6 +929,18 @@ where
 		Ok(r.map(|_| ()).map_err(|e| e.error))
 	}
 
+	/// Validate that `xt` may be applied next and register the weight of doing so.
+	fn validate_block_composition(
+		xt: &CheckedOf<Block::Extrinsic, Context>,
+	) -> Result<(), TransactionValidityError> {
+		BlockComposition::validate_next(xt)?;
+		<frame_system::Pallet<System>>::register_extra_weight_unchecked(
+			BlockComposition::weight(xt),
+			DispatchClass::Mandatory,
+		);
+		Ok(())
+	}
+
 	fn final_checks(header: &frame_system::pallet_prelude::HeaderFor<System>) {
 		sp_tracing::enter_span!(sp_tracing::Level::TRACE, "final_checks");
 		// remove temporaries
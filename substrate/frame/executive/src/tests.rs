@@ -474,6 +474,7 @@ type UncheckedXt = sp_runtime::generic::UncheckedExtrinsic<
 	sp_runtime::testing::UintAuthorityId,
 	TxExtension,
 >;
+type CheckedXt = sp_runtime::generic::CheckedExtrinsic<u64, RuntimeCall, TxExtension>;
 type TestBlock = Block<UncheckedXt>;
 
 // Will contain `true` when the custom runtime logic was called.
@@ -501,6 +502,39 @@ type Executive = super::Executive<
 	CustomOnRuntimeUpgrade,
 >;
 
+// Contains the number of the last block with a balance transfer.
+const TRANSFER_BLOCK_KEY: &[u8] = b":transfer:block";
+
+/// Allows at most one balance transfer per block.
+struct OneTransferPerBlock;
+impl ValidateBlockComposition<CheckedXt> for OneTransferPerBlock {
+	fn validate_next(xt: &CheckedXt) -> Result<(), TransactionValidityError> {
+		let RuntimeCall::Balances(BalancesCall::transfer_allow_death { .. }) = xt.function else {
+			return Ok(())
+		};
+		let block_number = System::block_number();
+		if frame_support::storage::unhashed::get(TRANSFER_BLOCK_KEY) == Some(block_number) {
+			return Err(InvalidTransaction::ExhaustsResources.into())
+		}
+		frame_support::storage::unhashed::put(TRANSFER_BLOCK_KEY, &block_number);
+		Ok(())
+	}
+
+	fn weight(_: &CheckedXt) -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
+type ComposedExecutive = super::Executive<
+	Runtime,
+	Block<UncheckedXt>,
+	ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	CustomOnRuntimeUpgrade,
+	OneTransferPerBlock,
+>;
+
 parameter_types! {
 	pub static SystemCallbacksCalled: u32 = 0;
 }
@@ -1549,3 +1583,72 @@ fn is_inherent_works() {
 	let ext = UncheckedXt::new_bare(RuntimeCall::Custom2(custom2::Call::allowed_unsigned {}));
 	assert!(!Runtime::is_inherent(&ext), "Unsigned ext are not automatically inherents");
 }
+
+#[test]
+fn block_composition_is_validated_when_authoring() {
+	let xt1 = UncheckedXt::new_signed(call_transfer(33, 0), 1, 1.into(), tx_ext(0, 0));
+	let xt2 = UncheckedXt::new_signed(call_transfer(33, 0), 1, 1.into(), tx_ext(1, 0));
+
+	new_test_ext(1).execute_with(|| {
+		ComposedExecutive::initialize_block(&Header::new_from_number(1));
+		let mandatory = *System::block_weight().get(DispatchClass::Mandatory);
+		assert_ok!(ComposedExecutive::apply_extrinsic(xt1).unwrap());
+		// The weight of the check was registered.
+		assert_eq!(
+			*System::block_weight().get(DispatchClass::Mandatory),
+			mandatory + <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+		);
+		assert_err!(
+			ComposedExecutive::apply_extrinsic(xt2.clone()),
+			TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources)
+		);
+		// The rejected extrinsic was not noted.
+		assert_eq!(System::extrinsic_index(), Some(1));
+		ComposedExecutive::finalize_block();
+
+		// It can be included in the next block.
+		MockedSystemCallbacks::reset();
+		ComposedExecutive::initialize_block(&Header::new_from_number(2));
+		assert_ok!(ComposedExecutive::apply_extrinsic(xt2).unwrap());
+	});
+}
+
+#[test]
+#[should_panic(expected = "Transaction would exhaust the block limits")]
+fn block_import_with_invalid_composition_fails() {
+	let xt1 = UncheckedXt::new_signed(call_transfer(33, 0), 1, 1.into(), tx_ext(0, 0));
+	let xt2 = UncheckedXt::new_signed(call_transfer(33, 0), 1, 1.into(), tx_ext(1, 0));
+
+	// Build the block without validating its composition.
+	let header = new_test_ext(1).execute_with(|| {
+		Executive::initialize_block(&Header::new_from_number(1));
+		Executive::apply_extrinsic(xt1.clone()).unwrap().unwrap();
+		Executive::apply_extrinsic(xt2.clone()).unwrap().unwrap();
+		Executive::finalize_block()
+	});
+
+	new_test_ext(1).execute_with(|| {
+		ComposedExecutive::execute_block(Block::new(header, vec![xt1, xt2]));
+	});
+}
+
+#[test]
+fn simulate_hooks_reports_weight_per_pallet() {
+	new_test_ext(1).execute_with(|| {
+		// `Custom2` expects to be idle only after the transactions.
+		SystemCallbacksCalled::set(3);
+
+		let zero = Weight::zero();
+		assert_eq!(
+			Executive::simulate_hooks(3),
+			vec![
+				("System", zero, zero),
+				("Balances", zero, zero),
+				("TransactionPayment", zero, zero),
+				("Custom", zero, Weight::from_parts(3 * 175, 0)),
+				("Custom2", zero, zero),
+			]
+		);
+		assert_eq!(System::block_number(), 3);
+	});
+}
@@ -26,7 +26,10 @@ use alloc::{vec, vec::Vec};
 use frame_benchmarking::{account, v2::*, whitelisted_caller, BenchmarkError};
 use frame_support::{
 	assert_ok, ensure,
-	traits::{EnsureOrigin, Get, OnFinalize, OnInitialize},
+	traits::{
+		fungible::{Inspect, Mutate},
+		EnsureOrigin, Get, OnFinalize, OnInitialize,
+	},
 };
 use frame_system::RawOrigin;
 use sp_io::crypto::{sr25519_generate, sr25519_sign};
@@ -131,6 +134,37 @@ fn bounded_username<T: Config>(username: Vec<u8>, suffix: Vec<u8>) -> Username<T
 	Username::<T>::try_from(full_username).expect("test usernames should fit within bounds")
 }
 
+// Sets up a username authority with lease terms and grants a leased username to `owner`.
+fn setup_leased_username<T: Config>(
+	owner: &T::AccountId,
+) -> Result<(T::AccountId, Username<T>), BenchmarkError> {
+	let auth_origin =
+		T::UsernameAuthorityOrigin::try_successful_origin().expect("can generate origin");
+	let authority: T::AccountId = account("authority", 0, SEED);
+	let authority_lookup = T::Lookup::unlookup(authority.clone());
+	let suffix = bench_suffix();
+	let _ = T::Currency::make_free_balance_be(&authority, BalanceOf::<T>::max_value());
+	Identity::<T>::add_username_authority(auth_origin, authority_lookup, suffix.clone(), 10)?;
+
+	let fee = T::LeaseCurrency::minimum_balance();
+	let terms = LeaseTerms { period: 10u32.into(), fee };
+	Identity::<T>::set_lease_terms(
+		RawOrigin::Signed(authority.clone()).into(),
+		suffix.clone(),
+		Some(terms),
+	)?;
+
+	let username = bounded_username::<T>(bench_username(), suffix);
+	let username_deposit = T::UsernameDeposit::get();
+	T::Currency::reserve(&authority, username_deposit)?;
+	Identity::<T>::insert_username(
+		owner,
+		username.clone(),
+		Provider::AuthorityDeposit(username_deposit),
+	);
+	Ok((authority, username))
+}
+
 #[benchmarks(
 	where
 		<T as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
@@ -873,6 +907,89 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_lease_terms() -> Result<(), BenchmarkError> {
+		// Set up a username authority.
+		let auth_origin =
+			T::UsernameAuthorityOrigin::try_successful_origin().expect("can generate origin");
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let authority_lookup = T::Lookup::unlookup(authority.clone());
+		let suffix = bench_suffix();
+
+		Identity::<T>::add_username_authority(auth_origin, authority_lookup, suffix.clone(), 10)?;
+		let terms = LeaseTerms { period: 10u32.into(), fee: T::LeaseCurrency::minimum_balance() };
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(authority), suffix.clone(), Some(terms.clone()));
+
+		let suffix: Suffix<T> = suffix.try_into().unwrap();
+		assert_last_event::<T>(Event::<T>::LeaseTermsSet { suffix, terms: Some(terms) }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn renew_username() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, username) = setup_leased_username::<T>(&caller)?;
+		let fee = T::LeaseCurrency::minimum_balance();
+		let _ = T::LeaseCurrency::set_balance(&caller, fee.saturating_mul(100u32.into()));
+		let expiry = UsernameLeaseExpiry::<T>::get(&username).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), username.clone());
+
+		let expiry = expiry + 10u32.into();
+		assert_last_event::<T>(Event::<T>::UsernameRenewed { username, expiry, fee }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_username() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, username) = setup_leased_username::<T>(&caller)?;
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), username.clone(), dest_lookup);
+
+		assert_last_event::<T>(
+			Event::<T>::UsernameTransferQueued { username, from: caller, to: dest }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_username_transfer() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let (_, username) = setup_leased_username::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+		PendingUsernameTransfers::<T>::insert(&username, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), username.clone());
+
+		assert_last_event::<T>(Event::<T>::PrimaryUsernameSet { who: caller, username }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reclaim_username() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let (authority, username) = setup_leased_username::<T>(&owner)?;
+		PendingUsernameTransfers::<T>::insert(&username, &authority);
+		let expiry = UsernameLeaseExpiry::<T>::get(&username).unwrap();
+		frame_system::Pallet::<T>::set_block_number(expiry + T::UsernameGracePeriod::get());
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), username.clone());
+
+		assert_last_event::<T>(Event::<T>::UsernameReclaimed { username, owner }.into());
+		assert!(T::Currency::reserved_balance(&authority).is_zero());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn migration_v2_authority_step() -> Result<(), BenchmarkError> {
 		let setup = LazyMigrationV1ToV2::<T>::setup_benchmark_env_for_migration();
@@ -63,6 +63,17 @@
 //! only map to a single username, known as the _primary_. This primary username will be the result
 //! of a lookup in the [UsernameOf] map for any given account.
 //!
+//! #### Username Leases
+//!
+//! An authority may set [`LeaseTerms`] for its suffix, in which case the usernames it grants from
+//! then on are leased for a period of blocks instead of being granted indefinitely. Anyone can
+//! renew a leased username by paying the renewal fee to the authority in `Config::LeaseCurrency`.
+//! Once a lease has expired, the username can still be renewed during `UsernameGracePeriod`, after
+//! which it can be reclaimed, returning the deposit or allocation to the authority.
+//!
+//! The owner of a username which has not expired can transfer it to another account, which must
+//! accept the transfer.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -77,6 +88,10 @@
 //! * `remove_expired_approval` - Remove a username that was issued but never accepted.
 //! * `set_primary_username` - Set a given username as an account's primary.
 //! * `remove_username` - Remove a username after its grace period has ended.
+//! * `renew_username` - Extend the lease of a username, paying the authority's renewal fee.
+//! * `transfer_username` - Offer to transfer an owned username to another account.
+//! * `accept_username_transfer` - Accept a username transfer offered to the account.
+//! * `reclaim_username` - Remove a username whose lease has expired past the grace period.
 //!
 //! #### For General Users with Sub-Identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -93,6 +108,7 @@
 //! #### For Username Authorities
 //! * `set_username_for` - Set a username for a given account. The account must approve it.
 //! * `unbind_username` - Start the grace period for a username.
+//! * `set_lease_terms` - Set or clear the lease terms of the usernames granted under a suffix.
 //!
 //! #### For Superusers
 //! * `add_registrar` - Add a new registrar to the system.
@@ -123,7 +139,8 @@ use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
 	traits::{
-		BalanceStatus, Currency, Defensive, Get, OnUnbalanced, ReservableCurrency, StorageVersion,
+		fungible, tokens::Preservation, BalanceStatus, Currency, Defensive, Get, OnUnbalanced,
+		ReservableCurrency, StorageVersion,
 	},
 	BoundedVec,
 };
@@ -133,7 +150,8 @@ use sp_runtime::traits::{
 	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
 };
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Data, IdentityInformationProvider, Judgement, LeaseTerms, RegistrarIndex, RegistrarInfo,
+	Registration,
 };
 pub use weights::WeightInfo;
 
@@ -144,6 +162,10 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 type ProviderOf<T> = Provider<BalanceOf<T>>;
+type LeaseBalanceOf<T> = <<T as Config>::LeaseCurrency as fungible::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+type LeaseTermsOf<T> = LeaseTerms<BlockNumberFor<T>, LeaseBalanceOf<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -158,6 +180,9 @@ pub mod pallet {
 		/// The currency trait.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The currency in which username lease renewal fees are paid to authorities.
+		type LeaseCurrency: fungible::Mutate<Self::AccountId>;
+
 		/// The amount held on deposit for a registered identity.
 		#[pallet::constant]
 		type BasicDeposit: Get<BalanceOf<Self>>;
@@ -337,6 +362,24 @@ pub mod pallet {
 	pub type UnbindingUsernames<T: Config> =
 		StorageMap<_, Blake2_128Concat, Username<T>, BlockNumberFor<T>, OptionQuery>;
 
+	/// The lease terms set by the authority of a suffix. Usernames granted under a suffix without
+	/// lease terms are not leased.
+	#[pallet::storage]
+	pub type UsernameLeaseTerms<T: Config> =
+		StorageMap<_, Twox64Concat, Suffix<T>, LeaseTermsOf<T>, OptionQuery>;
+
+	/// The block in which the lease of a username expires. Usernames that are not leased have no
+	/// entry in this map.
+	#[pallet::storage]
+	pub type UsernameLeaseExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, BlockNumberFor<T>, OptionQuery>;
+
+	/// Usernames that their owner has offered to transfer, mapped to the account that must accept
+	/// the transfer through [accept_username_transfer](`Call::accept_username_transfer`).
+	#[pallet::storage]
+	pub type PendingUsernameTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, T::AccountId, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -400,6 +443,12 @@ pub mod pallet {
 		/// The action cannot be performed because of insufficient privileges (e.g. authority
 		/// trying to unbind a username provided by the system).
 		InsufficientPrivileges,
+		/// The username is not leased.
+		NotLeased,
+		/// The lease of the username has expired.
+		LeaseExpired,
+		/// The lease period must not be zero.
+		ZeroLeasePeriod,
 	}
 
 	#[pallet::event]
@@ -451,6 +500,16 @@ pub mod pallet {
 		UsernameRemoved { username: Username<T> },
 		/// A username has been killed.
 		UsernameKilled { username: Username<T> },
+		/// The lease terms of the usernames granted under `suffix` were set or cleared.
+		LeaseTermsSet { suffix: Suffix<T>, terms: Option<LeaseTermsOf<T>> },
+		/// The lease of a username was renewed until `expiry` for `fee`.
+		UsernameRenewed { username: Username<T>, expiry: BlockNumberFor<T>, fee: LeaseBalanceOf<T> },
+		/// The owner of a username offered to transfer it, and `to` must accept the transfer.
+		UsernameTransferQueued { username: Username<T>, from: T::AccountId, to: T::AccountId },
+		/// A username was transferred to a new owner.
+		UsernameTransferred { username: Username<T>, from: T::AccountId, to: T::AccountId },
+		/// A username whose lease expired was reclaimed by its authority.
+		UsernameReclaimed { username: Username<T>, owner: T::AccountId },
 	}
 
 	#[pallet::call]
@@ -1307,6 +1366,8 @@ pub mod pallet {
 					*maybe_primary = None;
 				}
 			});
+			UsernameLeaseExpiry::<T>::remove(&username);
+			PendingUsernameTransfers::<T>::remove(&username);
 			match username_info.provider {
 				Provider::AuthorityDeposit(username_deposit) => {
 					let suffix = Self::suffix_of_username(&username)
@@ -1350,6 +1411,8 @@ pub mod pallet {
 				}
 			});
 			let _ = UnbindingUsernames::<T>::take(&username);
+			UsernameLeaseExpiry::<T>::remove(&username);
+			PendingUsernameTransfers::<T>::remove(&username);
 			let actual_weight = match username_info.provider {
 				Provider::AuthorityDeposit(username_deposit) => {
 					let suffix =
@@ -1375,6 +1438,180 @@ pub mod pallet {
 			Self::deposit_event(Event::UsernameKilled { username });
 			Ok((Some(actual_weight), Pays::No).into())
 		}
+
+		/// Set or clear the lease terms of the usernames granted under `suffix`. Must be called by
+		/// the authority of the suffix.
+		///
+		/// The terms apply to usernames granted from then on and to the renewal of existing leases.
+		/// Clearing them leaves existing leases in place, but they can no longer be renewed.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_lease_terms())]
+		pub fn set_lease_terms(
+			origin: OriginFor<T>,
+			suffix: Vec<u8>,
+			terms: Option<LeaseTermsOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let suffix = Suffix::<T>::try_from(suffix).map_err(|_| Error::<T>::InvalidSuffix)?;
+			let authority_account = AuthorityOf::<T>::get(&suffix)
+				.map(|auth_info| auth_info.account_id)
+				.ok_or(Error::<T>::NotUsernameAuthority)?;
+			ensure!(sender == authority_account, Error::<T>::NotUsernameAuthority);
+			if let Some(terms) = &terms {
+				ensure!(!terms.period.is_zero(), Error::<T>::ZeroLeasePeriod);
+			}
+			UsernameLeaseTerms::<T>::set(&suffix, terms.clone());
+			Self::deposit_event(Event::LeaseTermsSet { suffix, terms });
+			Ok(())
+		}
+
+		/// Renew the lease of a username for another lease period, paying the renewal fee to the
+		/// authority. The username may be renewed by any account until its grace period has ended.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::renew_username())]
+		pub fn renew_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let expiry = UsernameLeaseExpiry::<T>::get(&username).ok_or(Error::<T>::NotLeased)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now < expiry.saturating_add(T::UsernameGracePeriod::get()),
+				Error::<T>::LeaseExpired
+			);
+			let suffix = Self::suffix_of_username(&username).ok_or(Error::<T>::InvalidUsername)?;
+			let terms = UsernameLeaseTerms::<T>::get(&suffix).ok_or(Error::<T>::NotLeased)?;
+			let authority_account = AuthorityOf::<T>::get(&suffix)
+				.map(|auth_info| auth_info.account_id)
+				.ok_or(Error::<T>::NotUsernameAuthority)?;
+			<T::LeaseCurrency as fungible::Mutate<_>>::transfer(
+				&sender,
+				&authority_account,
+				terms.fee,
+				Preservation::Preserve,
+			)?;
+			// A lease renewed during its grace period starts over from the current block.
+			let expiry = expiry.max(now).saturating_add(terms.period);
+			UsernameLeaseExpiry::<T>::insert(&username, expiry);
+			Self::deposit_event(Event::UsernameRenewed { username, expiry, fee: terms.fee });
+			Ok(())
+		}
+
+		/// Offer to transfer a username owned by the sender to `dest`, which must accept it with
+		/// [accept_username_transfer](crate::Call::accept_username_transfer). Leased usernames
+		/// can only be transferred before their lease expires.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::transfer_username())]
+		pub fn transfer_username(
+			origin: OriginFor<T>,
+			username: Username<T>,
+			dest: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let username_info =
+				UsernameInfoOf::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(who == username_info.owner, Error::<T>::InvalidUsername);
+			ensure!(who != dest, Error::<T>::InvalidTarget);
+			Self::ensure_transferable(&username)?;
+			PendingUsernameTransfers::<T>::insert(&username, &dest);
+			Self::deposit_event(Event::UsernameTransferQueued { username, from: who, to: dest });
+			Ok(())
+		}
+
+		/// Accept the transfer of a username offered to the sender. The username becomes the
+		/// primary username of the sender if it has none.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::accept_username_transfer())]
+		pub fn accept_username_transfer(
+			origin: OriginFor<T>,
+			username: Username<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dest =
+				PendingUsernameTransfers::<T>::take(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(who == dest, Error::<T>::InvalidUsername);
+			Self::ensure_transferable(&username)?;
+			let from = UsernameInfoOf::<T>::try_mutate(
+				&username,
+				|maybe_info| -> Result<T::AccountId, DispatchError> {
+					let username_info = maybe_info.as_mut().ok_or(Error::<T>::NoUsername)?;
+					Ok(core::mem::replace(&mut username_info.owner, who.clone()))
+				},
+			)?;
+			// If this was the primary username of the previous owner, remove it.
+			UsernameOf::<T>::mutate(&from, |maybe_primary| {
+				if maybe_primary.as_ref().map_or(false, |primary| *primary == username) {
+					*maybe_primary = None;
+				}
+			});
+			let new_is_primary = !UsernameOf::<T>::contains_key(&who);
+			if new_is_primary {
+				UsernameOf::<T>::insert(&who, username.clone());
+			}
+			Self::deposit_event(Event::UsernameTransferred {
+				username: username.clone(),
+				from,
+				to: who.clone(),
+			});
+			if new_is_primary {
+				Self::deposit_event(Event::PrimaryUsernameSet { who, username });
+			}
+			Ok(())
+		}
+
+		/// Permanently delete a username whose lease expired longer than the grace period ago,
+		/// returning the deposit or allocation used to grant it to the authority. Caller is
+		/// refunded the fee if the removal was successful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::reclaim_username())]
+		pub fn reclaim_username(
+			origin: OriginFor<T>,
+			username: Username<T>,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let expiry = UsernameLeaseExpiry::<T>::take(&username).ok_or(Error::<T>::NotLeased)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= expiry.saturating_add(T::UsernameGracePeriod::get()),
+				Error::<T>::TooEarly
+			);
+			let username_info = UsernameInfoOf::<T>::take(&username)
+				.defensive_proof("a leased username must exist")
+				.ok_or(Error::<T>::NoUsername)?;
+			// If this is the primary username, remove the entry from the account -> username map.
+			UsernameOf::<T>::mutate(&username_info.owner, |maybe_primary| {
+				if maybe_primary.as_ref().map_or(false, |primary| *primary == username) {
+					*maybe_primary = None;
+				}
+			});
+			let _ = UnbindingUsernames::<T>::take(&username);
+			PendingUsernameTransfers::<T>::remove(&username);
+			let suffix = Self::suffix_of_username(&username)
+				.defensive_proof("registered username must be valid")
+				.ok_or(Error::<T>::InvalidUsername)?;
+			match username_info.provider {
+				Provider::AuthorityDeposit(username_deposit) => {
+					if let Some(authority_account) =
+						AuthorityOf::<T>::get(&suffix).map(|auth_info| auth_info.account_id)
+					{
+						let err_amount =
+							T::Currency::unreserve(&authority_account, username_deposit);
+						debug_assert!(err_amount.is_zero());
+					}
+				},
+				Provider::Allocation => {
+					// The allocation is returned to the authority, so it can grant the username
+					// again.
+					AuthorityOf::<T>::mutate(&suffix, |maybe_authority| {
+						if let Some(properties) = maybe_authority {
+							properties.allocation.saturating_inc();
+						}
+					});
+				},
+				Provider::System => return Err(Error::<T>::InsufficientPrivileges.into()),
+			}
+			Self::deposit_event(Event::UsernameReclaimed { username, owner: username_info.owner });
+			Ok(Pays::No.into())
+		}
 	}
 }
 
@@ -1517,6 +1754,15 @@ impl<T: Config> Pallet<T> {
 		if new_is_primary {
 			UsernameOf::<T>::insert(&who, primary_username);
 		}
+		// Usernames granted by an authority under a suffix with lease terms are leased.
+		if !matches!(provider, Provider::System) {
+			if let Some(terms) = Self::suffix_of_username(&username)
+				.and_then(|suffix| UsernameLeaseTerms::<T>::get(&suffix))
+			{
+				let now = frame_system::Pallet::<T>::block_number();
+				UsernameLeaseExpiry::<T>::insert(&username, now.saturating_add(terms.period));
+			}
+		}
 		let username_info = UsernameInformation { owner: who.clone(), provider };
 		// Enter in username map.
 		UsernameInfoOf::<T>::insert(username.clone(), username_info);
@@ -1526,6 +1772,17 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Ensure that a username can change owners, i.e. that it is not being unbound and that its
+	/// lease, if any, has not expired.
+	fn ensure_transferable(username: &Username<T>) -> DispatchResult {
+		ensure!(!UnbindingUsernames::<T>::contains_key(username), Error::<T>::AlreadyUnbinding);
+		if let Some(expiry) = UsernameLeaseExpiry::<T>::get(username) {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < expiry, Error::<T>::LeaseExpired);
+		}
+		Ok(())
+	}
+
 	/// A username was granted by an authority, but must be accepted by `who`. Put the username
 	/// into a queue for acceptance.
 	pub fn queue_acceptance(who: &T::AccountId, username: Username<T>, provider: ProviderOf<T>) {
@@ -74,6 +74,7 @@ parameter_types! {
 impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type LeaseCurrency = Balances;
 	type Slashed = ();
 	type BasicDeposit = ConstU64<100>;
 	type ByteDeposit = ConstU64<10>;
@@ -2235,3 +2236,265 @@ fn unbind_dangling_username_defensive_should_panic() {
 		);
 	});
 }
+
+#[test]
+fn username_leases_should_work() {
+	new_test_ext().execute_with(|| {
+		// Set up authority.
+		let initial_authority_balance = 1000;
+		let authority = account(100);
+		Balances::make_free_balance_be(&authority, initial_authority_balance);
+		let suffix: Vec<u8> = b"test".to_vec();
+		let allocation: u32 = 10;
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			authority.clone(),
+			suffix.clone(),
+			allocation
+		));
+		let username_deposit = <Test as Config>::UsernameDeposit::get();
+		let [who, other] = [account(101), account(102)];
+		Balances::make_free_balance_be(&who, 100);
+
+		// Usernames granted before the authority sets lease terms are not leased.
+		let unleased = test_username_of(b"1".to_vec(), suffix.clone());
+		assert_ok!(Identity::set_username_for(
+			RuntimeOrigin::signed(authority.clone()),
+			other.clone(),
+			unleased.clone().into(),
+			None,
+			true,
+		));
+		assert_ok!(Identity::accept_username(
+			RuntimeOrigin::signed(other.clone()),
+			unleased.clone()
+		));
+		assert!(UsernameLeaseExpiry::<Test>::get(&unleased).is_none());
+
+		// Only the authority can set lease terms, and the period cannot be zero.
+		let terms = LeaseTerms { period: 10, fee: 5 };
+		assert_noop!(
+			Identity::set_lease_terms(
+				RuntimeOrigin::signed(who.clone()),
+				suffix.clone(),
+				Some(terms.clone())
+			),
+			Error::<Test>::NotUsernameAuthority
+		);
+		assert_noop!(
+			Identity::set_lease_terms(
+				RuntimeOrigin::signed(authority.clone()),
+				suffix.clone(),
+				Some(LeaseTerms { period: 0, fee: 5 })
+			),
+			Error::<Test>::ZeroLeasePeriod
+		);
+		assert_ok!(Identity::set_lease_terms(
+			RuntimeOrigin::signed(authority.clone()),
+			suffix.clone(),
+			Some(terms.clone())
+		));
+		System::assert_last_event(
+			Event::<Test>::LeaseTermsSet {
+				suffix: suffix.clone().try_into().unwrap(),
+				terms: Some(terms),
+			}
+			.into(),
+		);
+		assert_noop!(
+			Identity::renew_username(RuntimeOrigin::signed(other.clone()), unleased),
+			Error::<Test>::NotLeased
+		);
+
+		// The lease starts when the username is accepted.
+		let username = test_username_of(b"2".to_vec(), suffix.clone());
+		assert_ok!(Identity::set_username_for(
+			RuntimeOrigin::signed(authority.clone()),
+			who.clone(),
+			username.clone().into(),
+			None,
+			false,
+		));
+		assert_ok!(Identity::accept_username(RuntimeOrigin::signed(who.clone()), username.clone()));
+		assert_eq!(UsernameLeaseExpiry::<Test>::get(&username), Some(11));
+		let allocated = test_username_of(b"3".to_vec(), suffix.clone());
+		assert_ok!(Identity::set_username_for(
+			RuntimeOrigin::signed(authority.clone()),
+			other.clone(),
+			allocated.clone().into(),
+			None,
+			true,
+		));
+		assert_ok!(Identity::accept_username(
+			RuntimeOrigin::signed(other.clone()),
+			allocated.clone()
+		));
+		assert_eq!(
+			AuthorityOf::<Test>::get(&Suffix::<Test>::try_from(suffix.clone()).unwrap())
+				.unwrap()
+				.allocation,
+			8
+		);
+
+		// Renewing before the lease expires extends it, and the fee goes to the authority.
+		System::set_block_number(5);
+		assert_ok!(Identity::renew_username(RuntimeOrigin::signed(who.clone()), username.clone()));
+		System::assert_last_event(
+			Event::<Test>::UsernameRenewed { username: username.clone(), expiry: 21, fee: 5 }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(&who), 95);
+		assert_eq!(
+			Balances::free_balance(&authority),
+			initial_authority_balance - username_deposit + 5
+		);
+
+		// The lease can still be renewed during the grace period, starting from the current block.
+		System::set_block_number(22);
+		assert_noop!(
+			Identity::reclaim_username(RuntimeOrigin::signed(account(0)), username.clone()),
+			Error::<Test>::TooEarly
+		);
+		assert_ok!(Identity::renew_username(RuntimeOrigin::signed(who.clone()), username.clone()));
+		assert_eq!(UsernameLeaseExpiry::<Test>::get(&username), Some(32));
+
+		// Once the grace period has ended, the username can only be reclaimed.
+		System::set_block_number(34);
+		assert_noop!(
+			Identity::renew_username(RuntimeOrigin::signed(who.clone()), username.clone()),
+			Error::<Test>::LeaseExpired
+		);
+		assert_ok!(Identity::reclaim_username(RuntimeOrigin::signed(account(0)), username.clone()));
+		System::assert_last_event(
+			Event::<Test>::UsernameReclaimed { username: username.clone(), owner: who.clone() }
+				.into(),
+		);
+		assert!(UsernameInfoOf::<Test>::get(&username).is_none());
+		assert!(UsernameOf::<Test>::get(&who).is_none());
+		assert!(UsernameLeaseExpiry::<Test>::get(&username).is_none());
+		// The deposit is returned to the authority.
+		assert_eq!(Balances::free_balance(&authority), initial_authority_balance + 10);
+		assert_eq!(Balances::reserved_balance(&authority), 0);
+
+		// Reclaiming a username granted from an allocation returns the allocation.
+		assert_ok!(Identity::reclaim_username(RuntimeOrigin::signed(account(0)), allocated));
+		assert_eq!(
+			AuthorityOf::<Test>::get(&Suffix::<Test>::try_from(suffix).unwrap())
+				.unwrap()
+				.allocation,
+			9
+		);
+		assert_eq!(UsernameOf::<Test>::get(&other), Some(unleased.clone()));
+	});
+}
+
+#[test]
+fn username_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		// Set up authority with lease terms.
+		let authority = account(100);
+		Balances::make_free_balance_be(&authority, 1000);
+		let suffix: Vec<u8> = b"test".to_vec();
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			authority.clone(),
+			suffix.clone(),
+			10
+		));
+		assert_ok!(Identity::set_lease_terms(
+			RuntimeOrigin::signed(authority.clone()),
+			suffix.clone(),
+			Some(LeaseTerms { period: 10, fee: 5 })
+		));
+		let [who, dest] = [account(101), account(102)];
+		Balances::make_free_balance_be(&who, 100);
+		Balances::make_free_balance_be(&dest, 100);
+		let username = test_username_of(b"1".to_vec(), suffix);
+		assert_ok!(Identity::set_username_for(
+			RuntimeOrigin::signed(authority.clone()),
+			who.clone(),
+			username.clone().into(),
+			None,
+			true,
+		));
+		assert_ok!(Identity::accept_username(RuntimeOrigin::signed(who.clone()), username.clone()));
+
+		// Only the owner can transfer a username, and not to themselves.
+		assert_noop!(
+			Identity::transfer_username(
+				RuntimeOrigin::signed(dest.clone()),
+				username.clone(),
+				dest.clone()
+			),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::transfer_username(
+				RuntimeOrigin::signed(who.clone()),
+				username.clone(),
+				who.clone()
+			),
+			Error::<Test>::InvalidTarget
+		);
+		assert_ok!(Identity::transfer_username(
+			RuntimeOrigin::signed(who.clone()),
+			username.clone(),
+			dest.clone()
+		));
+		System::assert_last_event(
+			Event::<Test>::UsernameTransferQueued {
+				username: username.clone(),
+				from: who.clone(),
+				to: dest.clone(),
+			}
+			.into(),
+		);
+
+		// Only the recipient can accept the transfer.
+		assert_noop!(
+			Identity::accept_username_transfer(
+				RuntimeOrigin::signed(who.clone()),
+				username.clone()
+			),
+			Error::<Test>::InvalidUsername
+		);
+		assert_ok!(Identity::accept_username_transfer(
+			RuntimeOrigin::signed(dest.clone()),
+			username.clone()
+		));
+		assert!(PendingUsernameTransfers::<Test>::get(&username).is_none());
+		assert_eq!(UsernameInfoOf::<Test>::get(&username).unwrap().owner, dest);
+		assert!(UsernameOf::<Test>::get(&who).is_none());
+		assert_eq!(UsernameOf::<Test>::get(&dest), Some(username.clone()));
+		// The lease is unchanged.
+		assert_eq!(UsernameLeaseExpiry::<Test>::get(&username), Some(11));
+
+		// A transfer cannot complete once the lease has expired, until it is renewed.
+		assert_ok!(Identity::transfer_username(
+			RuntimeOrigin::signed(dest.clone()),
+			username.clone(),
+			who.clone()
+		));
+		System::set_block_number(11);
+		assert_noop!(
+			Identity::accept_username_transfer(
+				RuntimeOrigin::signed(who.clone()),
+				username.clone()
+			),
+			Error::<Test>::LeaseExpired
+		);
+		assert_ok!(Identity::renew_username(RuntimeOrigin::signed(who.clone()), username.clone()));
+		assert_ok!(Identity::accept_username_transfer(
+			RuntimeOrigin::signed(who.clone()),
+			username.clone()
+		));
+		assert_eq!(UsernameOf::<Test>::get(&who), Some(username.clone()));
+
+		// Unbinding usernames cannot be transferred.
+		assert_ok!(Identity::unbind_username(RuntimeOrigin::signed(authority), username.clone()));
+		assert_noop!(
+			Identity::transfer_username(RuntimeOrigin::signed(who), username, dest),
+			Error::<Test>::AlreadyUnbinding
+		);
+	});
+}
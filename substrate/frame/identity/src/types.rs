@@ -376,6 +376,17 @@ pub struct UsernameInformation<Account, Balance> {
 	pub provider: Provider<Balance>,
 }
 
+/// The terms under which an authority leases the usernames it grants.
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, PartialEq, Eq, Debug,
+)]
+pub struct LeaseTerms<BlockNumber, Balance> {
+	/// The number of blocks for which a username is granted or renewed.
+	pub period: BlockNumber,
+	/// The fee paid to the authority for each renewal.
+	pub fee: Balance,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -99,6 +99,26 @@
 //! migration via [`SteppedMigration::max_steps`]. The pallet **ONLY** guarantees progress if this
 //! is set to sensible limits (Goal 7).
 //!
+//! ### Dependencies and interleaving
+//!
+//! A migration can declare the identifiers of other migrations that must complete before it
+//! starts via [`SteppedMigration::dependencies`]. These must either come earlier in the
+//! [`Config::Migrations`] tuple or have completed in a past upgrade, which is checked when the
+//! migration starts. A migration with incomplete dependencies is skipped with a
+//! `MigrationDependencyMissing` event and not added to the `Historic` set, so that a later upgrade
+//! can run it again once its dependencies are in place. This can for example happen after the
+//! dependency was removed from the `Historic` set by [`clear_historic`][Pallet::clear_historic].
+//! Migrations that depend on a skipped migration are skipped as well.
+//!
+//! By default, migrations are executed strictly one after another. Setting
+//! [`Config::MaxActiveMigrations`] to a value larger than one interleaves up to that many
+//! migrations within the same block. The cursor then becomes [`MigrationCursor::Interleaved`] and
+//! tracks the set of active migrations. New migrations are started in tuple order once a slot is
+//! free and none of their dependencies are still in progress. Each active migration makes at most
+//! one step per block and all of them share the [`Config::MaxServiceWeight`]. A migration that
+//! reports insufficient weight is moved to the front of the set and gets the whole block next
+//! time. Failures are handled exactly as in the sequential case.
+//!
 //! ### Scenario: Governance cleanup
 //!
 //! Every now and then, governance can make use of the [`clear_historic`][Pallet::clear_historic]
@@ -185,26 +205,40 @@ use sp_runtime::Saturating;
 	scale_info::TypeInfo,
 	MaxEncodedLen,
 )]
-pub enum MigrationCursor<Cursor, BlockNumber> {
+#[scale_info(skip_type_params(MaxActive))]
+pub enum MigrationCursor<Cursor, BlockNumber, MaxActive = ConstU32<MAX_ACTIVE_MIGRATIONS>> {
 	/// Points to the currently active migration and its inner cursor.
 	Active(ActiveCursor<Cursor, BlockNumber>),
 
 	/// Migration got stuck and cannot proceed. This is bad.
 	Stuck,
+
+	/// Points to a set of migrations that are interleaved with each other.
+	Interleaved(InterleavedCursor<Cursor, BlockNumber, MaxActive>),
 }
 
-impl<Cursor, BlockNumber> MigrationCursor<Cursor, BlockNumber> {
+impl<Cursor, BlockNumber, MaxActive> MigrationCursor<Cursor, BlockNumber, MaxActive> {
 	/// Try to return self as an [`ActiveCursor`].
 	pub fn as_active(&self) -> Option<&ActiveCursor<Cursor, BlockNumber>> {
 		match self {
 			MigrationCursor::Active(active) => Some(active),
+			MigrationCursor::Stuck | MigrationCursor::Interleaved(_) => None,
+		}
+	}
+
+	/// The index of the first migration that is currently in progress, if any.
+	pub fn active_index(&self) -> Option<u32> {
+		match self {
+			MigrationCursor::Active(active) => Some(active.index),
+			MigrationCursor::Interleaved(interleaved) =>
+				interleaved.active.first().map(|active| active.index),
 			MigrationCursor::Stuck => None,
 		}
 	}
 }
 
-impl<Cursor, BlockNumber> From<ActiveCursor<Cursor, BlockNumber>>
-	for MigrationCursor<Cursor, BlockNumber>
+impl<Cursor, BlockNumber, MaxActive> From<ActiveCursor<Cursor, BlockNumber>>
+	for MigrationCursor<Cursor, BlockNumber, MaxActive>
 {
 	fn from(active: ActiveCursor<Cursor, BlockNumber>) -> Self {
 		MigrationCursor::Active(active)
@@ -243,6 +277,44 @@ impl<Cursor, BlockNumber> ActiveCursor<Cursor, BlockNumber> {
 	}
 }
 
+/// The maximal number of migrations that can be in progress at the same time.
+///
+/// This is the hard upper bound for [`Config::MaxActiveMigrations`].
+pub const MAX_ACTIVE_MIGRATIONS: u32 = 8;
+
+/// Points to the set of migrations that are currently interleaved.
+///
+/// At most `MaxActive` migrations can be in progress at the same time. This bounds the encoded
+/// length of the cursor, so that it should be set to [`Config::MaxActiveMigrations`].
+#[derive(
+	Debug,
+	Clone,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxActive))]
+pub struct InterleavedCursor<Cursor, BlockNumber, MaxActive = ConstU32<MAX_ACTIVE_MIGRATIONS>> {
+	/// The index of the next migration in the MBM tuple that was not started yet.
+	pub next_index: u32,
+	/// The migrations that are in progress, in the order in which they are stepped.
+	pub active: BoundedVec<ActiveCursor<Cursor, BlockNumber>, MaxActive>,
+}
+
+/// [`Config::MaxActiveMigrations`] as bound for the cursor types.
+#[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct MaxActiveMigrationsOf<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> Get<u32> for MaxActiveMigrationsOf<T> {
+	fn get() -> u32 {
+		T::MaxActiveMigrations::get()
+	}
+}
+
 /// How to clear the records of historic migrations.
 #[derive(
 	Debug, Clone, Eq, PartialEq, Encode, Decode, DecodeWithMemTracking, scale_info::TypeInfo,
@@ -282,7 +354,7 @@ impl<Id> HistoricCleanupSelector<Id> {
 }
 
 /// Convenience alias for [`MigrationCursor`].
-pub type CursorOf<T> = MigrationCursor<RawCursorOf<T>, BlockNumberFor<T>>;
+pub type CursorOf<T> = MigrationCursor<RawCursorOf<T>, BlockNumberFor<T>, MaxActiveMigrationsOf<T>>;
 
 /// Convenience alias for the raw inner cursor of a migration.
 pub type RawCursorOf<T> = BoundedVec<u8, <T as Config>::CursorMaxLen>;
@@ -293,6 +365,10 @@ pub type IdentifierOf<T> = BoundedVec<u8, <T as Config>::IdentifierMaxLen>;
 /// Convenience alias for [`ActiveCursor`].
 pub type ActiveCursorOf<T> = ActiveCursor<RawCursorOf<T>, BlockNumberFor<T>>;
 
+/// Convenience alias for [`InterleavedCursor`].
+pub type InterleavedCursorOf<T> =
+	InterleavedCursor<RawCursorOf<T>, BlockNumberFor<T>, MaxActiveMigrationsOf<T>>;
+
 /// Trait for a tuple of No-OP migrations with one element.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait MockedMigrations: SteppedMigrations {
@@ -374,6 +450,15 @@ pub mod pallet {
 		/// The maximum weight to spend each block to execute migrations.
 		type MaxServiceWeight: Get<Weight>;
 
+		/// The maximal number of migrations that are interleaved within the same block.
+		///
+		/// A value of one executes all migrations strictly in sequence. Larger values allow
+		/// migrations to start before the previous ones completed, as long as none of their
+		/// [`SteppedMigration::dependencies`] are still in progress. Must be between one and
+		/// [`MAX_ACTIVE_MIGRATIONS`].
+		#[pallet::constant]
+		type MaxActiveMigrations: Get<u32>;
+
 		/// Weight information for the calls and functions of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -413,11 +498,12 @@ pub mod pallet {
 			type MigrationStatusHandler = ();
 			type FailedMigrationHandler = FreezeChainOnFailedMigration;
 			type MaxServiceWeight = TestMaxServiceWeight;
+			type MaxActiveMigrations = ConstU32<1>;
 			type WeightInfo = ();
 		}
 	}
 
-	/// The currently active migration to run and its cursor.
+	/// The currently active migrations to run and their cursors.
 	///
 	/// `None` indicates that no migration is running.
 	#[pallet::storage]
@@ -456,6 +542,15 @@ pub mod pallet {
 			/// The index of the skipped migration within the [`Config::Migrations`] list.
 			index: u32,
 		},
+		/// A migration was skipped since one of its dependencies did not complete.
+		///
+		/// The migration is not added to the historic set and can be run by a later upgrade.
+		MigrationDependencyMissing {
+			/// The index of the skipped migration within the [`Config::Migrations`] list.
+			index: u32,
+			/// The identifier of the dependency that did not complete.
+			dependency: Vec<u8>,
+		},
 		/// A migration progressed.
 		MigrationAdvanced {
 			/// The index of the migration within the [`Config::Migrations`] list.
@@ -522,6 +617,15 @@ pub mod pallet {
 				assert!(want.all_lte(max), "Service weight is larger than a block: {want} > {max}");
 			}
 
+			// The number of interleaved migrations fits into the cursor.
+			{
+				let active = T::MaxActiveMigrations::get();
+				assert!(
+					(1..=MAX_ACTIVE_MIGRATIONS).contains(&active),
+					"MaxActiveMigrations must be within 1..={MAX_ACTIVE_MIGRATIONS}: {active}",
+				);
+			}
+
 			// Cursor MEL
 			{
 				let mel = T::Migrations::cursor_max_encoded_len();
@@ -643,8 +747,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(cursor) = Cursor::<T>::get() {
 			log::error!("Ongoing migrations interrupted - chain stuck");
 
-			let maybe_index = cursor.as_active().map(|c| c.index);
-			Self::upgrade_failed(maybe_index);
+			Self::upgrade_failed(cursor.active_index());
 			return T::WeightInfo::onboard_new_mbms()
 		}
 
@@ -652,15 +755,23 @@ impl<T: Config> Pallet<T> {
 		log::debug!("Onboarding {migrations} new MBM migrations");
 
 		if migrations > 0 {
-			// Set the cursor to the first migration:
-			Cursor::<T>::set(Some(
-				ActiveCursor {
-					index: 0,
-					inner_cursor: None,
-					started_at: System::<T>::block_number(),
-				}
-				.into(),
-			));
+			if T::MaxActiveMigrations::get() > 1 {
+				// Migrations are onboarded into the set once the process makes progress:
+				Cursor::<T>::set(Some(MigrationCursor::Interleaved(InterleavedCursor {
+					next_index: 0,
+					active: Default::default(),
+				})));
+			} else {
+				// Set the cursor to the first migration:
+				Cursor::<T>::set(Some(
+					ActiveCursor {
+						index: 0,
+						inner_cursor: None,
+						started_at: System::<T>::block_number(),
+					}
+					.into(),
+				));
+			}
 			Self::deposit_event(Event::UpgradeStarted { migrations });
 			T::MigrationStatusHandler::started();
 		}
@@ -686,6 +797,10 @@ impl<T: Config> Pallet<T> {
 				log::error!("Migration stuck. Governance intervention required.");
 				return meter.consumed()
 			},
+			Some(MigrationCursor::Interleaved(cursor)) => {
+				log::debug!("Progressing {} interleaved MBMs", cursor.active.len());
+				return Self::progress_interleaved_mbms(cursor, meter)
+			},
 		};
 		debug_assert!(Self::ongoing());
 
@@ -710,6 +825,123 @@ impl<T: Config> Pallet<T> {
 		meter.consumed()
 	}
 
+	/// Tries to make progress on a set of interleaved Multi-Block-Migrations.
+	///
+	/// Each active migration is stepped at most once per block. Completed migrations free their
+	/// slot for the next ones in the tuple, which are onboarded in order once none of their
+	/// dependencies are in progress anymore.
+	fn progress_interleaved_mbms(cursor: InterleavedCursorOf<T>, mut meter: WeightMeter) -> Weight {
+		let max_active = T::MaxActiveMigrations::get().clamp(1, MAX_ACTIVE_MIGRATIONS) as usize;
+		let mut next_index = cursor.next_index;
+		// Migrations that still need to be stepped in this block.
+		let mut waiting = cursor.active.into_inner();
+		// Migrations that asked to be retried since they did not get enough weight.
+		let mut retrying = Vec::new();
+		// Migrations that advanced in this block.
+		let mut advanced = Vec::new();
+		let mut is_first = true;
+
+		// As in the sequential case, we allow no more than 8 MBMs to finish in a single block.
+		for _ in 0..8 {
+			while waiting.len() + retrying.len() + advanced.len() < max_active &&
+				next_index < T::Migrations::len()
+			{
+				let in_progress = waiting.iter().chain(&retrying).chain(&advanced);
+				if Self::has_dependency_in_progress(next_index, in_progress) {
+					break
+				}
+
+				waiting.push(ActiveCursor {
+					index: next_index,
+					inner_cursor: None,
+					started_at: System::<T>::block_number(),
+				});
+				next_index.saturating_inc();
+			}
+
+			let mut finished = false;
+			while !waiting.is_empty() {
+				if !meter.can_consume(Self::exec_migration_max_weight()) {
+					break
+				}
+
+				let cursor = waiting.remove(0);
+				let before = cursor.clone();
+				let is_first = core::mem::take(&mut is_first);
+				match Self::exec_migration(cursor, is_first, &mut meter) {
+					None => return meter.consumed(),
+					Some(ControlFlow::Continue(_)) => {
+						// Stop stepping so that its slot can be handed to the next migration.
+						finished = true;
+						break
+					},
+					Some(ControlFlow::Break(cursor)) if cursor == before => retrying.push(cursor),
+					Some(ControlFlow::Break(cursor)) => advanced.push(cursor),
+				}
+			}
+
+			if !finished {
+				break
+			}
+		}
+
+		if waiting.is_empty() &&
+			retrying.is_empty() &&
+			advanced.is_empty() &&
+			next_index >= T::Migrations::len()
+		{
+			Self::deposit_event(Event::UpgradeCompleted);
+			Cursor::<T>::kill();
+			T::MigrationStatusHandler::completed();
+			return meter.consumed()
+		}
+
+		// Retried migrations go first to get the whole block, followed by the ones that did not
+		// make progress in this block.
+		retrying.append(&mut waiting);
+		retrying.append(&mut advanced);
+		let Ok(active) = BoundedVec::try_from(retrying) else {
+			defensive!("At most `MaxActiveMigrations` migrations are onboarded; qed");
+			Self::upgrade_failed(None);
+			return meter.consumed()
+		};
+
+		Cursor::<T>::set(Some(MigrationCursor::Interleaved(InterleavedCursor {
+			next_index,
+			active,
+		})));
+
+		meter.consumed()
+	}
+
+	/// Whether any dependency of the `index`th migration is among the `in_progress` ones.
+	fn has_dependency_in_progress<'a>(
+		index: u32,
+		mut in_progress: impl Iterator<Item = &'a ActiveCursorOf<T>>,
+	) -> bool {
+		let dependencies = T::Migrations::nth_dependencies(index).unwrap_or_default();
+		if dependencies.is_empty() {
+			return false
+		}
+
+		in_progress.any(|active| {
+			T::Migrations::nth_id(active.index).is_some_and(|id| dependencies.contains(&id))
+		})
+	}
+
+	/// The first dependency of the `index`th migration that did not complete, if any.
+	///
+	/// Consumes the weight of looking up all dependencies.
+	fn missing_dependency(index: u32, meter: &mut WeightMeter) -> Option<Vec<u8>> {
+		let dependencies = T::Migrations::nth_dependencies(index).unwrap_or_default();
+		meter.consume(T::DbWeight::get().reads(dependencies.len() as u64));
+
+		dependencies.into_iter().find(|id| {
+			!IdentifierOf::<T>::try_from(id.clone())
+				.is_ok_and(|id| Historic::<T>::contains_key(&id))
+		})
+	}
+
 	/// Try to make progress on the current migration.
 	///
 	/// Returns whether processing should continue or break for this block. The return value means:
@@ -750,6 +982,18 @@ impl<T: Config> Pallet<T> {
 			return Some(ControlFlow::Continue(cursor))
 		}
 
+		if cursor.inner_cursor.is_none() {
+			if let Some(dependency) = Self::missing_dependency(cursor.index, meter) {
+				log::error!("Migration #{} has incomplete dependencies - skipping", cursor.index);
+				Self::deposit_event(Event::MigrationDependencyMissing {
+					index: cursor.index,
+					dependency,
+				});
+				cursor.goto_next_migration(System::<T>::block_number());
+				return Some(ControlFlow::Continue(cursor))
+			}
+		}
+
 		let max_steps = T::Migrations::nth_max_steps(cursor.index);
 
 		// If this is the first time running this migration, exec the pre-upgrade hook.
@@ -54,6 +54,7 @@ impl crate::Config for Test {
 	type Migrations = MockedMigrations;
 	type MigrationStatusHandler = MockedMigrationStatusHandler;
 	type FailedMigrationHandler = MockedFailedMigrationHandler;
+	type MaxActiveMigrations = MaxActiveMigrations;
 }
 
 frame_support::parameter_types! {
@@ -65,6 +66,8 @@ frame_support::parameter_types! {
 	pub static UpgradesFailed: Vec<Option<u32>> = vec![];
 	/// Return value of [`MockedFailedMigrationHandler::failed`].
 	pub static FailedUpgradeResponse: FailedMigrationHandling = FailedMigrationHandling::KeepStuck;
+	/// The number of migrations that can be interleaved.
+	pub static MaxActiveMigrations: u32 = 1;
 }
 
 /// Records all started and completed upgrades in `UpgradesStarted` and `UpgradesCompleted`.
@@ -60,6 +60,8 @@ pub fn mocked_id(kind: MockedMigrationKind, steps: u32) -> MockedIdentifier {
 frame_support::parameter_types! {
 	/// The configs for the migrations to run.
 	storage MIGRATIONS: Vec<(MockedMigrationKind, u32)> = vec![];
+	/// The dependencies of the migrations by their index.
+	storage DEPENDENCIES: Vec<(u32, MockedIdentifier)> = vec![];
 }
 
 /// Allows to set the migrations to run at runtime instead of compile-time.
@@ -123,6 +125,16 @@ impl SteppedMigrations for MockedMigrations {
 		MIGRATIONS::get().get(n as usize).map(|(_, s)| Some(*s))
 	}
 
+	fn nth_dependencies(n: u32) -> Option<Vec<Vec<u8>>> {
+		(n < Self::len()).then(|| {
+			DEPENDENCIES::get()
+				.into_iter()
+				.filter(|(m, _)| *m == n)
+				.map(|(_, id)| id.into_inner())
+				.collect()
+		})
+	}
+
 	#[cfg(feature = "try-runtime")]
 	fn nth_pre_upgrade(n: u32) -> Option<Result<Vec<u8>, sp_runtime::TryRuntimeError>> {
 		let (kind, _) = MIGRATIONS::get()[n as usize];
@@ -162,6 +174,13 @@ impl MockedMigrations {
 	pub fn set(migrations: Vec<(MockedMigrationKind, u32)>) {
 		MIGRATIONS::set(&migrations);
 	}
+
+	/// Let the `n`th migration depend on the migration with identifier `id`.
+	pub fn add_dependency(n: u32, id: MockedIdentifier) {
+		let mut dependencies = DEPENDENCIES::get();
+		dependencies.push((n, id));
+		DEPENDENCIES::set(&dependencies);
+	}
 }
 
 impl crate::MockedMigrations for MockedMigrations {
@@ -22,7 +22,7 @@ use frame_support::{pallet_prelude::Weight, traits::OnRuntimeUpgrade};
 use crate::{
 	mock::{Test as T, *},
 	mock_helpers::{MockedMigrationKind::*, *},
-	Cursor, Event, FailedMigrationHandling, MigrationCursor,
+	Cursor, Event, FailedMigrationHandling, Historic, MigrationCursor,
 };
 
 #[docify::export]
@@ -367,6 +367,175 @@ fn migration_timeout_errors() {
 	});
 }
 
+#[test]
+fn interleaved_migrations_work() {
+	use Event::*;
+	test_closure(|| {
+		MaxActiveMigrations::set(2);
+		MockedMigrations::set(vec![(SucceedAfter, 0), (SucceedAfter, 1), (SucceedAfter, 2)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(2);
+
+		// The first one completed and freed its slot for the third one.
+		let Some(MigrationCursor::Interleaved(interleaved)) = Cursor::<T>::get() else {
+			panic!("Cursor must be interleaved")
+		};
+		assert_eq!(interleaved.next_index, 3);
+		assert_eq!(interleaved.active.iter().map(|c| c.index).collect::<Vec<_>>(), vec![1, 2]);
+
+		run_to_block(10);
+
+		assert_eq!(
+			historic(),
+			vec![
+				mocked_id(SucceedAfter, 0),
+				mocked_id(SucceedAfter, 1),
+				mocked_id(SucceedAfter, 2),
+			]
+		);
+		// One block faster than `simple_multiple_works`.
+		assert_events(vec![
+			UpgradeStarted { migrations: 3 },
+			MigrationCompleted { index: 0, took: 0 },
+			MigrationAdvanced { index: 1, took: 0 },
+			MigrationAdvanced { index: 2, took: 0 },
+			MigrationCompleted { index: 1, took: 1 },
+			MigrationAdvanced { index: 2, took: 1 },
+			MigrationCompleted { index: 2, took: 2 },
+			UpgradeCompleted,
+		]);
+		assert_eq!(upgrades_started_completed_failed(), (1, 1, 0));
+		assert!(Cursor::<T>::get().is_none());
+	});
+}
+
+#[test]
+fn interleaved_migrations_wait_for_dependencies() {
+	use Event::*;
+	test_closure(|| {
+		MaxActiveMigrations::set(3);
+		MockedMigrations::set(vec![(SucceedAfter, 1), (SucceedAfter, 0), (SucceedAfter, 2)]);
+		MockedMigrations::add_dependency(1, mocked_id(SucceedAfter, 1));
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+
+		// The second one only starts once the first one completed. The third one waits for the
+		// second one to start since migrations are started in order.
+		assert_events(vec![
+			UpgradeStarted { migrations: 3 },
+			MigrationAdvanced { index: 0, took: 0 },
+			MigrationCompleted { index: 0, took: 1 },
+			MigrationCompleted { index: 1, took: 0 },
+			MigrationAdvanced { index: 2, took: 0 },
+			MigrationAdvanced { index: 2, took: 1 },
+			MigrationCompleted { index: 2, took: 2 },
+			UpgradeCompleted,
+		]);
+		assert_eq!(upgrades_started_completed_failed(), (1, 1, 0));
+	});
+}
+
+/// A migration whose dependency neither ran before nor is part of the upgrade is skipped, together
+/// with the migrations that depend on it.
+#[test]
+fn missing_dependency_skips_migration() {
+	use Event::*;
+	test_closure(|| {
+		MockedMigrations::set(vec![(SucceedAfter, 0), (SucceedAfter, 1), (SucceedAfter, 2)]);
+		MockedMigrations::add_dependency(0, mocked_id(SucceedAfter, 5));
+		MockedMigrations::add_dependency(1, mocked_id(SucceedAfter, 0));
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 2)]);
+		assert_events(vec![
+			UpgradeStarted { migrations: 3 },
+			MigrationDependencyMissing {
+				index: 0,
+				dependency: mocked_id(SucceedAfter, 5).into_inner(),
+			},
+			MigrationDependencyMissing {
+				index: 1,
+				dependency: mocked_id(SucceedAfter, 0).into_inner(),
+			},
+			MigrationAdvanced { index: 2, took: 0 },
+			MigrationAdvanced { index: 2, took: 1 },
+			MigrationCompleted { index: 2, took: 2 },
+			UpgradeCompleted,
+		]);
+		assert_eq!(upgrades_started_completed_failed(), (1, 1, 0));
+
+		// A later upgrade runs the skipped migrations once the dependency is in place.
+		Historic::<T>::insert(mocked_id(SucceedAfter, 5), ());
+		Migrations::on_runtime_upgrade();
+		run_to_block(20);
+
+		assert_eq!(
+			historic(),
+			vec![
+				mocked_id(SucceedAfter, 0),
+				mocked_id(SucceedAfter, 1),
+				mocked_id(SucceedAfter, 2),
+				mocked_id(SucceedAfter, 5)
+			]
+		);
+		assert_eq!(upgrades_started_completed_failed(), (1, 1, 0));
+	});
+}
+
+#[test]
+#[cfg_attr(feature = "try-runtime", should_panic)]
+fn failing_interleaved_migration_sets_cursor_to_stuck() {
+	test_closure(|| {
+		MaxActiveMigrations::set(2);
+		MockedMigrations::set(vec![(SucceedAfter, 2), (FailAfter, 0)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+
+		assert!(historic().is_empty());
+		assert_events(vec![
+			Event::UpgradeStarted { migrations: 2 },
+			Event::MigrationAdvanced { index: 0, took: 0 },
+			Event::MigrationFailed { index: 1, took: 0 },
+			Event::UpgradeFailed,
+		]);
+		assert_eq!(UpgradesFailed::take(), vec![Some(1)]);
+		assert_eq!(Cursor::<T>::get(), Some(MigrationCursor::Stuck));
+	});
+}
+
+/// An interleaved migration that reports not getting enough weight gets the whole next block.
+#[test]
+#[cfg_attr(feature = "try-runtime", should_panic)]
+fn high_weight_interleaved_migration_retries_first() {
+	test_closure(|| {
+		MaxActiveMigrations::set(2);
+		MockedMigrations::set(vec![(SucceedAfter, 1), (HighWeightAfter(Weight::zero()), 0)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+
+		assert_events::<Event<T>>(vec![
+			Event::UpgradeStarted { migrations: 2 },
+			Event::MigrationAdvanced { index: 0, took: 0 },
+			// `took=1` means that it was retried once, as the first one in the block.
+			Event::MigrationFailed { index: 1, took: 1 },
+			Event::UpgradeFailed,
+		]);
+		assert_eq!(upgrades_started_completed_failed(), (1, 0, 1));
+		assert_eq!(Cursor::<T>::get(), Some(MigrationCursor::Stuck));
+	});
+}
+
 #[cfg(feature = "try-runtime")]
 #[test]
 fn try_runtime_success_case() {
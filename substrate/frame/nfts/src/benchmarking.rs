@@ -64,6 +64,20 @@ fn add_collection_metadata<T: Config<I>, I: 'static>() -> (T::AccountId, Account
 	(caller, caller_lookup)
 }
 
+fn add_collection_royalty<T: Config<I>, I: 'static>() -> T::AccountId {
+	let collection = T::Helper::collection(0);
+	let caller = Collection::<T, I>::get(collection).unwrap().owner;
+	let recipient: T::AccountId = account("recipient", 0, SEED);
+	T::Currency::make_free_balance_be(&recipient, T::Currency::minimum_balance());
+	let royalty = Royalty { recipient: T::Lookup::unlookup(recipient.clone()), basis_points: 500 };
+	assert_ok!(Nfts::<T, I>::set_collection_royalty(
+		SystemOrigin::Signed(caller).into(),
+		collection,
+		Some(royalty),
+	));
+	recipient
+}
+
 fn mint_item<T: Config<I>, I: 'static>(
 	index: u16,
 ) -> (T::ItemId, T::AccountId, AccountIdLookupOf<T>) {
@@ -631,6 +645,34 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let royalty = Royalty { recipient: recipient_lookup, basis_points: 500 };
+	}: _(SystemOrigin::Signed(caller.clone()), collection, Some(royalty))
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet {
+			collection,
+			royalty: Some(Royalty { recipient, basis_points: 500 }),
+		}.into());
+	}
+
+	set_item_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let royalty = Royalty { recipient: recipient_lookup, basis_points: 500 };
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(royalty))
+	verify {
+		assert_last_event::<T, I>(Event::ItemRoyaltySet {
+			collection,
+			item,
+			royalty: Some(Royalty { recipient, basis_points: 500 }),
+		}.into());
+	}
+
 	update_mint_settings {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let mint_settings = MintSettings {
@@ -648,6 +690,7 @@ benchmarks_instance_pallet! {
 	set_price {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		add_collection_royalty::<T, I>();
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let price = ItemPrice::<T, I>::from(100u32);
@@ -666,7 +709,8 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		add_collection_royalty::<T, I>();
+		let price = ItemPrice::<T, I>::from(100u32);
 		let origin = SystemOrigin::Signed(seller.clone()).into();
 		Nfts::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
@@ -708,6 +752,7 @@ benchmarks_instance_pallet! {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
+		add_collection_royalty::<T, I>();
 		let price = ItemPrice::<T, I>::from(100u32);
 		let price_direction = PriceDirection::Receive;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
@@ -753,8 +798,10 @@ benchmarks_instance_pallet! {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
-		let price = ItemPrice::<T, I>::from(0u32);
-		let price_direction = PriceDirection::Receive;
+		add_collection_royalty::<T, I>();
+		let price = ItemPrice::<T, I>::from(100u32);
+		// The royalty of the item sent by the claimer is looked up when the swap is claimed.
+		let price_direction = PriceDirection::Send;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		let target: T::AccountId = account("target", 0, SEED);
@@ -21,10 +21,7 @@
 //! to have the functionality defined in this module.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Creates a new swap offer for the specified item.
@@ -89,6 +86,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				deadline,
 			},
 		);
+		PendingSwapRoyaltyOf::<T, I>::set(
+			&offered_collection_id,
+			&offered_item_id,
+			Self::royalty_of(&offered_collection_id, &offered_item_id),
+		);
 
 		Self::deposit_event(Event::SwapCreated {
 			offered_collection: offered_collection_id,
@@ -127,6 +129,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 
 		PendingSwapOf::<T, I>::remove(&offered_collection_id, &offered_item_id);
+		PendingSwapRoyaltyOf::<T, I>::remove(&offered_collection_id, &offered_item_id);
 
 		Self::deposit_event(Event::SwapCancelled {
 			offered_collection: offered_collection_id,
@@ -146,8 +149,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `send_item_id`, `receive_collection_id`, and `receive_item_id`. The `caller` account must be
 	/// the owner of the item specified by `send_collection_id` and `send_item_id`. If the claimed
 	/// swap has an associated `price`, it will be transferred between the owners of the two items
-	/// based on the `price.direction`. After the swap is completed, the function emits the
-	/// `SwapClaimed` event.
+	/// based on the `price.direction`, minus the royalty of the item given up by the account
+	/// receiving the price. The royalty of the offered item is the one in force when the swap was
+	/// created, while that of the item sent by the `caller` is the current one. After the swap is
+	/// completed, the function emits the `SwapClaimed` event.
 	///
 	/// - `caller`: The account claiming the swap offer, which must be the owner of the sent item.
 	/// - `send_collection_id`: The identifier of the collection containing the item being sent.
@@ -190,19 +195,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let now = T::BlockNumberProvider::current_block_number();
 		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
 
+		// The royalty is deducted from the price and is that of the item given up by the account
+		// receiving the price.
 		if let Some(ref price) = swap.price {
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
+				PriceDirection::Send => Self::do_pay_with_royalty(
+					send_collection_id,
+					send_item_id,
+					Self::royalty_of(&send_collection_id, &send_item_id),
 					&receive_item.owner,
 					&send_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
-				PriceDirection::Receive => T::Currency::transfer(
+				PriceDirection::Receive => Self::do_pay_with_royalty(
+					receive_collection_id,
+					receive_item_id,
+					PendingSwapRoyaltyOf::<T, I>::get(&receive_collection_id, &receive_item_id),
 					&send_item.owner,
 					&receive_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
 			};
 		}
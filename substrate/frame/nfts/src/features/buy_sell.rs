@@ -23,7 +23,7 @@
 use crate::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive},
+	traits::{Currency, ExistenceRequirement::KeepAlive},
 };
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -98,6 +98,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Some(ref price) = price {
 			ItemPriceOf::<T, I>::insert(&collection, &item, (price, whitelisted_buyer.clone()));
+			ItemPriceRoyaltyOf::<T, I>::set(
+				&collection,
+				&item,
+				Self::royalty_of(&collection, &item),
+			);
 			Self::deposit_event(Event::ItemPriceSet {
 				collection,
 				item,
@@ -106,6 +111,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			});
 		} else {
 			ItemPriceOf::<T, I>::remove(&collection, &item);
+			ItemPriceRoyaltyOf::<T, I>::remove(&collection, &item);
 			Self::deposit_event(Event::ItemPriceRemoved { collection, item });
 		}
 
@@ -119,7 +125,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// receive the bid price if it is equal to or higher than the item's set price. If
 	/// `whitelisted_buyer` is specified in the item's price information, only that account is
 	/// allowed to buy the item. If the item is not for sale, or the bid price is too low, the
-	/// function will return an error.
+	/// function will return an error. The royalty of the item as it stood when its price was set,
+	/// if any, is deducted from the price and paid to its recipient.
 	///
 	/// - `collection`: The identifier of the collection containing the item to be bought.
 	/// - `item`: The identifier of the item to be bought.
@@ -148,12 +155,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
-			&buyer,
-			&details.owner,
-			price_info.0,
-			ExistenceRequirement::KeepAlive,
-		)?;
+		let royalty = ItemPriceRoyaltyOf::<T, I>::get(&collection, &item);
+		Self::do_pay_with_royalty(collection, item, royalty, &buyer, &details.owner, price_info.0)?;
 
 		let old_owner = details.owner.clone();
 
@@ -0,0 +1,157 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! This module contains helper methods to configure and pay the royalties of collections and
+//! items for the NFTs pallet.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+use sp_runtime::Permill;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Set or remove the royalty paid on trades of the items of a collection.
+	///
+	/// - `maybe_check_owner`: An optional account ID used to check permissions.
+	/// - `collection`: The ID of the collection for which to set the royalty.
+	/// - `royalty`: The new royalty, or `None` to remove it.
+	///
+	/// This function checks if the setting `UnlockedRoyalties` is enabled in the collection
+	/// configuration. If it is not enabled, it returns an `Error::LockedCollectionRoyalties`. If
+	/// `maybe_check_owner` is `Some(owner)`, it checks if the caller of the function is the owner
+	/// of the collection. If all checks pass, it updates the royalty and emits a
+	/// `CollectionRoyaltySet` event.
+	pub(crate) fn do_set_collection_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		royalty: Option<RoyaltyOf<T>>,
+	) -> DispatchResult {
+		Self::ensure_royalties_modifiable(maybe_check_owner, &collection, &royalty)?;
+		CollectionRoyaltyOf::<T, I>::set(&collection, royalty.clone());
+		Self::deposit_event(Event::CollectionRoyaltySet { collection, royalty });
+		Ok(())
+	}
+
+	/// Set or remove the royalty paid on trades of an item.
+	///
+	/// - `maybe_check_owner`: An optional account ID used to check permissions.
+	/// - `collection`: The ID of the collection of the item.
+	/// - `item`: The ID of the item for which to set the royalty.
+	/// - `royalty`: The new royalty, or `None` to remove it.
+	///
+	/// The same checks as in [`Self::do_set_collection_royalty`] apply, and the item must exist.
+	/// If all checks pass, it updates the royalty and emits an `ItemRoyaltySet` event.
+	pub(crate) fn do_set_item_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		royalty: Option<RoyaltyOf<T>>,
+	) -> DispatchResult {
+		Self::ensure_royalties_modifiable(maybe_check_owner, &collection, &royalty)?;
+		ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+		ItemRoyaltyOf::<T, I>::set(&collection, &item, royalty.clone());
+		Self::deposit_event(Event::ItemRoyaltySet { collection, item, royalty });
+		Ok(())
+	}
+
+	/// Returns the royalty paid on trades of an item. The royalty of the item takes precedence
+	/// over the royalty of its collection.
+	pub fn royalty_of(collection: &T::CollectionId, item: &T::ItemId) -> Option<RoyaltyOf<T>> {
+		ItemRoyaltyOf::<T, I>::get(collection, item)
+			.or_else(|| CollectionRoyaltyOf::<T, I>::get(collection))
+	}
+
+	/// Pays `amount` from `payer` to `payee` for a trade of an item, deducting `royalty` and
+	/// paying it to the royalty recipient.
+	///
+	/// - `collection`: The identifier of the collection containing the traded item.
+	/// - `item`: The identifier of the traded item.
+	/// - `royalty`: The royalty due on the trade, if any.
+	/// - `payer`: The account that pays for the item.
+	/// - `payee`: The account that gives up the item.
+	/// - `amount`: The total amount paid, including the royalty.
+	///
+	/// A royalty which is too small to create the account of its recipient is left to the
+	/// `payee`, so that it can't keep the item from being traded.
+	///
+	/// Emits a `RoyaltyPaid` event if a non-zero royalty was paid.
+	pub(crate) fn do_pay_with_royalty(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		royalty: Option<RoyaltyOf<T>>,
+		payer: &T::AccountId,
+		payee: &T::AccountId,
+		amount: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let mut remainder = amount;
+		if let Some(royalty) = royalty {
+			let royalty_amount =
+				Permill::from_parts(u32::from(royalty.basis_points) * 100).mul_floor(amount);
+			let payable = royalty_amount >= T::Currency::minimum_balance() ||
+				!T::Currency::total_balance(&royalty.recipient).is_zero();
+			if !royalty_amount.is_zero() && payable {
+				T::Currency::transfer(payer, &royalty.recipient, royalty_amount, KeepAlive)?;
+				remainder = amount.saturating_sub(royalty_amount);
+				Self::deposit_event(Event::RoyaltyPaid {
+					collection,
+					item,
+					payer: payer.clone(),
+					recipient: royalty.recipient,
+					amount: royalty_amount,
+				});
+			}
+		}
+		T::Currency::transfer(payer, payee, remainder, KeepAlive)
+	}
+
+	/// Resolves the recipient of a royalty passed to a dispatchable.
+	pub(crate) fn lookup_royalty(
+		royalty: Royalty<AccountIdLookupOf<T>>,
+	) -> Result<RoyaltyOf<T>, DispatchError> {
+		Ok(Royalty {
+			recipient: T::Lookup::lookup(royalty.recipient)?,
+			basis_points: royalty.basis_points,
+		})
+	}
+
+	fn ensure_royalties_modifiable(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: &T::CollectionId,
+		royalty: &Option<RoyaltyOf<T>>,
+	) -> DispatchResult {
+		let collection_config = Self::get_collection_config(collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::UnlockedRoyalties),
+			Error::<T, I>::LockedCollectionRoyalties
+		);
+
+		if let Some(check_owner) = &maybe_check_owner {
+			let owner =
+				Self::collection_owner(*collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			ensure!(check_owner == &owner, Error::<T, I>::NoPermission);
+		}
+
+		if let Some(royalty) = royalty {
+			ensure!(
+				royalty.basis_points <= MAX_ROYALTY_BASIS_POINTS,
+				Error::<T, I>::RoyaltyTooHigh
+			);
+		}
+		Ok(())
+	}
+}
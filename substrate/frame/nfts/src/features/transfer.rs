@@ -100,7 +100,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// Update item details.
 		Item::<T, I>::insert(&collection, &item, &details);
 		ItemPriceOf::<T, I>::remove(&collection, &item);
+		ItemPriceRoyaltyOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
+		PendingSwapRoyaltyOf::<T, I>::remove(&collection, &item);
 
 		// Emit `Transferred` event.
 		Self::deposit_event(Event::Transferred {
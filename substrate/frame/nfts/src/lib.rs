@@ -413,6 +413,49 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The royalty paid on trades of the items of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyOf<T>, OptionQuery>;
+
+	/// The royalty paid on trades of an item, taking precedence over the collection's royalty.
+	#[pallet::storage]
+	pub type ItemRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyOf<T>,
+		OptionQuery,
+	>;
+
+	/// The royalty of an item for sale as it stood when its price was set. It is the royalty paid
+	/// when the item is bought.
+	#[pallet::storage]
+	pub type ItemPriceRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyOf<T>,
+		OptionQuery,
+	>;
+
+	/// The royalty of an item offered in a swap as it stood when the swap was created. It is the
+	/// royalty paid out of a price received by the account offering the item.
+	#[pallet::storage]
+	pub type PendingSwapRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -593,6 +636,22 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// The royalty of a `collection` was set or removed.
+		CollectionRoyaltySet { collection: T::CollectionId, royalty: Option<RoyaltyOf<T>> },
+		/// The royalty of an `item` was set or removed.
+		ItemRoyaltySet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalty: Option<RoyaltyOf<T>>,
+		},
+		/// A royalty was paid for a trade of an `item`.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			payer: T::AccountId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -687,6 +746,10 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// Collection's royalties are locked.
+		LockedCollectionRoyalties,
+		/// The royalty exceeds `MAX_ROYALTY_BASIS_POINTS`.
+		RoyaltyTooHigh,
 	}
 
 	#[pallet::call]
@@ -1930,6 +1993,57 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set (or reset) the royalty paid on trades of the items of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `royalty`: The recipient and the share of the trade price in basis points, or `None`
+		///   to remove the royalty.
+		///
+		/// Emits `CollectionRoyaltySet` event when successful.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalty: Option<Royalty<AccountIdLookupOf<T>>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let royalty = royalty.map(Self::lookup_royalty).transpose()?;
+			Self::do_set_collection_royalty(maybe_check_owner, collection, royalty)
+		}
+
+		/// Set (or reset) the royalty paid on trades of an item. It takes precedence over the
+		/// royalty of the collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to set the royalty for.
+		/// - `royalty`: The recipient and the share of the trade price in basis points, or `None`
+		///   to remove the royalty.
+		///
+		/// Emits `ItemRoyaltySet` event when successful.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_item_royalty())]
+		pub fn set_item_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalty: Option<Royalty<AccountIdLookupOf<T>>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let royalty = royalty.map(Self::lookup_royalty).transpose()?;
+			Self::do_set_item_royalty(maybe_check_owner, collection, item, royalty)
+		}
 	}
 }
 
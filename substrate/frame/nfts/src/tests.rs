@@ -3875,3 +3875,394 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&account(1)), 10);
 	});
 }
+
+#[test]
+fn royalties_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+		Balances::make_free_balance_be(&user_4, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None
+		));
+
+		let collection_royalty = Royalty { recipient: user_3.clone(), basis_points: 500 };
+
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				Some(collection_royalty.clone()),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				Some(Royalty { recipient: user_3.clone(), basis_points: 10_001 }),
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			Nfts::set_item_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id + 1,
+				Some(collection_royalty.clone()),
+			),
+			Error::<Test>::UnknownItem
+		);
+
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(collection_royalty.clone()),
+		));
+		assert_eq!(
+			CollectionRoyaltyOf::<Test>::get(collection_id),
+			Some(collection_royalty.clone())
+		);
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltySet {
+			collection: collection_id,
+			royalty: Some(collection_royalty.clone()),
+		}));
+		assert_eq!(Nfts::royalty_of(&collection_id, &item_id), Some(collection_royalty));
+
+		// the collection royalty is deducted from the sale and paid to its recipient
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_4.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 95);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 5);
+		assert_eq!(Balances::total_balance(&user_4), initial_balance - price);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			payer: user_4.clone(),
+			recipient: user_3.clone(),
+			amount: 5,
+		}));
+
+		// an item royalty overrides the collection one
+		let item_royalty = Royalty { recipient: user_1.clone(), basis_points: 1_000 };
+		assert_ok!(Nfts::set_item_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(item_royalty.clone()),
+		));
+		assert!(events().contains(&Event::<Test>::ItemRoyaltySet {
+			collection: collection_id,
+			item: item_id,
+			royalty: Some(item_royalty.clone()),
+		}));
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_4.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 10);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 5);
+		assert_eq!(Balances::total_balance(&user_4), initial_balance - 10);
+
+		// royalties can't be changed once locked
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedRoyalties.into()),
+		));
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				None,
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+		assert_noop!(
+			Nfts::set_item_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				None
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+
+		// burning the item cleans up its royalty
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id));
+		assert!(ItemRoyaltyOf::<Test>::get(collection_id, item_id).is_none());
+	});
+}
+
+#[test]
+fn claim_swap_should_pay_royalties() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 100;
+		let initial_balance = 1000;
+		let deadline = 5;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			user_2.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(Royalty { recipient: user_3.clone(), basis_points: 1_000 }),
+		));
+
+		let price_with_direction =
+			PriceWithDirection { amount: price, direction: PriceDirection::Receive };
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(price_with_direction.clone()),
+			deadline,
+		));
+		// raising the royalty doesn't affect the pending swap
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(Royalty { recipient: user_3.clone(), basis_points: 10_000 }),
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			item_1,
+			Some(price_with_direction),
+		));
+
+		// the royalty of the item the price is paid for goes to its recipient
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 90);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_3), 10);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			payer: user_2.clone(),
+			recipient: user_3.clone(),
+			amount: 10,
+		}));
+	});
+}
+
+#[test]
+fn royalties_are_fixed_when_listed() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+		Balances::make_free_balance_be(&user_4, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(Royalty { recipient: user_3.clone(), basis_points: 500 }),
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+
+		// raising the royalty doesn't affect the item already for sale
+		let raised_royalty = Royalty { recipient: user_3.clone(), basis_points: 10_000 };
+		assert_ok!(Nfts::set_item_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(raised_royalty.clone()),
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_4.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 95);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 5);
+		assert!(ItemPriceRoyaltyOf::<Test>::get(collection_id, item_id).is_none());
+
+		// but it applies to the items put up for sale afterwards
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_4.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+		assert_eq!(ItemPriceRoyaltyOf::<Test>::get(collection_id, item_id), Some(raised_royalty));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 105);
+		assert_eq!(Balances::total_balance(&user_4), initial_balance - price);
+	});
+}
+
+#[test]
+fn royalties_below_existential_deposit_are_skipped() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 100;
+		let initial_balance = 1000;
+
+		ExistentialDeposit::set(10);
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(Royalty { recipient: user_3.clone(), basis_points: 500 }),
+		));
+
+		// the royalty can't create the account of its recipient, so the seller keeps it
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + price);
+		assert_eq!(Balances::total_balance(&user_3), 0);
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::RoyaltyPaid { .. })));
+
+		// once the recipient exists, the royalty is paid
+		Balances::make_free_balance_be(&user_3, 10);
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price + 95);
+		assert_eq!(Balances::total_balance(&user_3), 15);
+	});
+}
@@ -70,6 +70,8 @@ pub type ItemTipOf<T, I = ()> = ItemTip<
 	<T as SystemConfig>::AccountId,
 	BalanceOf<T, I>,
 >;
+/// A type alias for the royalty of a collection or an item.
+pub type RoyaltyOf<T> = Royalty<<T as SystemConfig>::AccountId>;
 /// A type alias for the settings configuration of a collection.
 pub type CollectionConfigFor<T, I = ()> =
 	CollectionConfig<BalanceOf<T, I>, BlockNumberFor<T, I>, <T as Config<I>>::CollectionId>;
@@ -226,6 +228,28 @@ pub struct ItemTip<CollectionId, ItemId, AccountId, Amount> {
 	pub amount: Amount,
 }
 
+/// The maximum royalty, in basis points, that can be set for a collection or an item.
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+
+/// A royalty paid to the creator of a collection or an item whenever the item is traded.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct Royalty<AccountId> {
+	/// The account receiving the royalty.
+	pub recipient: AccountId,
+	/// The share of the trade price paid as royalty, in basis points.
+	pub basis_points: u16,
+}
+
 /// Information about the pending swap.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
@@ -310,6 +334,8 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalties of this collection and its items can be modified.
+	UnlockedRoyalties,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
@@ -25,16 +25,18 @@ use frame_support::{
 	traits::{
 		fungible::{Inspect, Mutate, Unbalanced},
 		tokens::Preservation,
-		Get, Imbalance,
+		Get, Hooks, Imbalance,
 	},
+	weights::Weight,
 };
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategy, StakeStrategyType},
-	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
-	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
-	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
-	Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	AutoCompoundMembers, BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission,
+	ClaimPermissions, Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp,
+	GlobalMaxCommission, MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond,
+	MinJoinBond, Pallet as Pools, PayoutDestinations, PoolId, PoolMembers, PoolRoles, PoolState,
+	RewardPools, SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
@@ -926,6 +928,56 @@ mod benchmarks {
 		assert_eq!(ClaimPermissions::<T>::get(joiner), ClaimPermission::Permissioned);
 	}
 
+	#[benchmark]
+	fn set_auto_compound() {
+		let min_create_bond = Pools::<T>::depositor_min_bond();
+		let (depositor, _pool_account) = create_pool_account::<T>(0, min_create_bond, None);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor.clone()), true);
+
+		assert!(AutoCompoundMembers::<T>::contains_key(depositor));
+	}
+
+	#[benchmark]
+	fn set_payout_destination() {
+		let min_create_bond = Pools::<T>::depositor_min_bond();
+		let (depositor, _pool_account) = create_pool_account::<T>(0, min_create_bond, None);
+		let destination: T::AccountId = account("destination", USER_SEED, 0);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor.clone()), Some(T::Lookup::unlookup(destination.clone())));
+
+		assert_eq!(PayoutDestinations::<T>::get(depositor), Some(destination));
+	}
+
+	#[benchmark]
+	fn auto_compound_member() {
+		let origin_weight = Pools::<T>::depositor_min_bond() * 2u32.into();
+		let scenario = ListScenario::<T>::new(origin_weight, true).unwrap();
+		let extra = (scenario.dest_weight - origin_weight).max(CurrencyOf::<T>::minimum_balance());
+
+		assert_ok!(Pools::<T>::set_auto_compound(
+			RuntimeOrigin::Signed(scenario.creator1.clone()).into(),
+			true,
+		));
+
+		// transfer exactly `extra` to the reward account of the src pool (1), to be compounded.
+		let reward_account1 = Pools::<T>::generate_reward_account(1);
+		let _ = CurrencyOf::<T>::mint_into(&reward_account1, extra);
+
+		#[block]
+		{
+			Pools::<T>::on_idle(Zero::zero(), Weight::MAX);
+		}
+
+		// commission of 50% deducted here.
+		assert!(
+			T::StakeAdapter::active_stake(Pool::from(scenario.origin1)) >=
+				scenario.dest_weight / 2u32.into()
+		);
+	}
+
 	#[benchmark]
 	fn claim_commission() {
 		let claimer: T::AccountId = account("claimer_member", USER_SEED + 4, 0);
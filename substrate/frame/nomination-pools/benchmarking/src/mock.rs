@@ -142,6 +142,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type BlockNumberProvider = System;
 	type Filter = Nothing;
+	type MaxAutoCompoundPerBlock = ConstU32<16>;
 }
 
 parameter_types! {
@@ -360,12 +360,13 @@ use core::{fmt::Debug, ops::Div};
 use frame_support::{
 	defensive, defensive_assert, ensure,
 	pallet_prelude::{MaxEncodedLen, *},
-	storage::bounded_btree_map::BoundedBTreeMap,
+	storage::{bounded_btree_map::BoundedBTreeMap, with_storage_layer},
 	traits::{
 		fungible::{Inspect, InspectFreeze, Mutate, MutateFreeze},
 		tokens::{Fortitude, Preservation},
 		Contains, Defensive, DefensiveOption, DefensiveResult, DefensiveSaturating, Get,
 	},
+	weights::WeightMeter,
 	DefaultNoBound, PalletError,
 };
 use scale_info::TypeInfo;
@@ -1635,6 +1636,26 @@ impl<T: Config> Get<u32> for TotalUnbondingPools<T> {
 	}
 }
 
+/// Converts an account to the pending slash of its pool membership, see
+/// [`Pallet::api_member_pending_slash`].
+pub struct MemberPendingSlash<T: Config>(PhantomData<T>);
+
+impl<T: Config> Convert<T::AccountId, BalanceOf<T>> for MemberPendingSlash<T> {
+	fn convert(who: T::AccountId) -> BalanceOf<T> {
+		Pallet::<T>::api_member_pending_slash(who)
+	}
+}
+
+/// The weight of [`MemberPendingSlash`]: the pool membership, the pending slash of the pool and
+/// the delegation of the member.
+pub struct MemberPendingSlashWeight<T: Config>(PhantomData<T>);
+
+impl<T: Config> Get<Weight> for MemberPendingSlashWeight<T> {
+	fn get() -> Weight {
+		T::DbWeight::get().reads(3)
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -1732,6 +1753,11 @@ pub mod pallet {
 
 		/// Restrict some accounts from participating in a nomination pool.
 		type Filter: Contains<Self::AccountId>;
+
+		/// The maximum number of members whose rewards are compounded in a single `on_idle`
+		/// sweep.
+		#[pallet::constant]
+		type MaxAutoCompoundPerBlock: Get<u32>;
 	}
 
 	/// The sum of funds across all pools.
@@ -1822,6 +1848,20 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// Pool members whose pending rewards are bonded automatically during `on_idle`.
+	#[pallet::storage]
+	pub type AutoCompoundMembers<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The last member visited by the auto-compound sweep. The next sweep resumes after it.
+	#[pallet::storage]
+	pub type AutoCompoundCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Map from a pool member account to the account their payouts are sent to, if not their own.
+	#[pallet::storage]
+	pub type PayoutDestinations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1949,6 +1989,11 @@ pub mod pallet {
 		MinBalanceExcessAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// A pool member's claim permission has been updated.
 		MemberClaimPermissionUpdated { member: T::AccountId, permission: ClaimPermission },
+		/// A pool member has opted in or out of auto-compounding their rewards.
+		MemberAutoCompoundUpdated { member: T::AccountId, enabled: bool },
+		/// A pool member's payout destination has been updated. `None` means the member's own
+		/// account.
+		MemberPayoutDestinationUpdated { member: T::AccountId, destination: Option<T::AccountId> },
 		/// A pool's metadata was updated.
 		MetadataUpdated { pool_id: PoolId, caller: T::AccountId },
 		/// A pool's nominating account (or the pool's root account) has nominated a validator set
@@ -2277,6 +2322,7 @@ pub mod pallet {
 			)?;
 			let _ = Self::do_reward_payout(
 				&member_account,
+				&Self::payout_destination(&member_account),
 				&mut member,
 				&mut bonded_pool,
 				&mut reward_pool,
@@ -2507,8 +2553,10 @@ pub mod pallet {
 			});
 
 			let post_info_weight = if member.total_points().is_zero() {
-				// remove any `ClaimPermission` associated with the member.
+				// remove any `ClaimPermission` and payout preferences associated with the member.
 				ClaimPermissions::<T>::remove(&member_account);
+				AutoCompoundMembers::<T>::remove(&member_account);
+				PayoutDestinations::<T>::remove(&member_account);
 
 				// member being reaped.
 				PoolMembers::<T>::remove(&member_account);
@@ -3235,6 +3283,74 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Opt in or out of having pending rewards bonded automatically.
+		///
+		/// While enabled, the rewards of the member are compounded during `on_idle`, at most
+		/// [`Config::MaxAutoCompoundPerBlock`] members per block and only if there is enough
+		/// weight left. Members can still claim or bond their rewards themselves at any time.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Member of a pool.
+		/// * `enabled` - Whether rewards should be compounded automatically.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(PoolMembers::<T>::contains_key(&who), Error::<T>::PoolMemberNotFound);
+
+			// ensure member is not in an un-migrated state.
+			ensure!(
+				!Self::api_member_needs_delegate_migration(who.clone()),
+				Error::<T>::NotMigrated
+			);
+
+			if enabled {
+				AutoCompoundMembers::<T>::insert(&who, ());
+			} else {
+				AutoCompoundMembers::<T>::remove(&who);
+			}
+
+			Self::deposit_event(Event::<T>::MemberAutoCompoundUpdated { member: who, enabled });
+			Ok(())
+		}
+
+		/// Send the payouts of a pool member to a different account.
+		///
+		/// Applies to payouts claimed by the member or on their behalf, including the implicit
+		/// payout when unbonding. Rewards which are bonded, either via `bond_extra` or
+		/// auto-compounding, are never redirected.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Member of a pool.
+		/// * `destination` - The account to receive payouts, or `None` for the member's own
+		///   account.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_payout_destination())]
+		pub fn set_payout_destination(
+			origin: OriginFor<T>,
+			destination: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(PoolMembers::<T>::contains_key(&who), Error::<T>::PoolMemberNotFound);
+			let destination = destination
+				.map(T::Lookup::lookup)
+				.transpose()?
+				.filter(|destination| destination != &who);
+
+			match destination {
+				Some(ref destination) => PayoutDestinations::<T>::insert(&who, destination),
+				None => PayoutDestinations::<T>::remove(&who),
+			}
+
+			Self::deposit_event(Event::<T>::MemberPayoutDestinationUpdated {
+				member: who,
+				destination,
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -3244,6 +3360,10 @@ pub mod pallet {
 			Self::do_try_state(u8::MAX)
 		}
 
+		fn on_idle(_n: SystemBlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_auto_compound_sweep(remaining_weight)
+		}
+
 		fn integrity_test() {
 			assert!(
 				T::MaxPointsToBalance::get() > 0,
@@ -3444,6 +3564,7 @@ impl<T: Config> Pallet<T> {
 	// not persist any of the mutable inputs to storage.
 	fn do_reward_payout(
 		member_account: &T::AccountId,
+		payee: &T::AccountId,
 		member: &mut PoolMember<T>,
 		bonded_pool: &mut BondedPool<T>,
 		reward_pool: &mut RewardPool<T>,
@@ -3473,7 +3594,7 @@ impl<T: Config> Pallet<T> {
 
 		T::Currency::transfer(
 			&bonded_pool.reward_account(),
-			member_account,
+			payee,
 			pending_rewards,
 			// defensive: the depositor has put existential deposit into the pool and it stays
 			// untouched, reward account shall not die.
@@ -3593,8 +3714,14 @@ impl<T: Config> Pallet<T> {
 			bonded_pool.points,
 			bonded_pool.commission.current(),
 		)?;
+		// rewards which are bonded must be paid out to the member themselves.
+		let payee = match extra {
+			BondExtra::FreeBalance(_) => Self::payout_destination(&member_account),
+			BondExtra::Rewards => member_account.clone(),
+		};
 		let claimed = Self::do_reward_payout(
 			&member_account,
+			&payee,
 			&mut member,
 			&mut bonded_pool,
 			&mut reward_pool,
@@ -3681,6 +3808,7 @@ impl<T: Config> Pallet<T> {
 
 		let _ = Self::do_reward_payout(
 			&member_account,
+			&Self::payout_destination(&member_account),
 			&mut member,
 			&mut bonded_pool,
 			&mut reward_pool,
@@ -3690,6 +3818,76 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// The account that payouts of `member_account` are sent to.
+	fn payout_destination(member_account: &T::AccountId) -> T::AccountId {
+		PayoutDestinations::<T>::get(member_account).unwrap_or_else(|| member_account.clone())
+	}
+
+	/// Bond the pending rewards of as many auto-compounding members as `remaining_weight` and
+	/// [`Config::MaxAutoCompoundPerBlock`] allow, resuming after the member visited last.
+	///
+	/// Returns the weight consumed, which is only that of the reads made if no member was visited.
+	pub(crate) fn do_auto_compound_sweep(remaining_weight: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(remaining_weight);
+		// reading and writing the cursor.
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+
+		let last = AutoCompoundCursor::<T>::get();
+		let mut members = match &last {
+			Some(last) => AutoCompoundMembers::<T>::iter_keys_from(
+				AutoCompoundMembers::<T>::hashed_key_for(last),
+			),
+			None => AutoCompoundMembers::<T>::iter_keys(),
+		};
+
+		let member_weight = T::WeightInfo::auto_compound_member();
+		let mut cursor = last.clone();
+		// the cursor, and then one member key per step.
+		let mut reads = 1;
+		for _ in 0..T::MaxAutoCompoundPerBlock::get() {
+			if !meter.can_consume(member_weight) {
+				break
+			}
+			reads += 1;
+			let Some(member_account) = members.next() else {
+				// all members were visited, start over next time.
+				cursor = None;
+				break
+			};
+			meter.consume(member_weight);
+
+			// failures, e.g. a member which is fully unbonding, are not fatal to the sweep.
+			let _ = with_storage_layer(|| Self::do_auto_compound(&member_account));
+			cursor = Some(member_account);
+		}
+
+		if cursor == last {
+			// no member was visited, so nothing was written.
+			return T::DbWeight::get().reads(reads)
+		}
+		match cursor {
+			Some(cursor) => AutoCompoundCursor::<T>::put(cursor),
+			None => AutoCompoundCursor::<T>::kill(),
+		}
+		meter.consumed()
+	}
+
+	/// Bond the pending rewards of `member_account`, if any.
+	fn do_auto_compound(member_account: &T::AccountId) -> DispatchResult {
+		if Self::api_member_needs_delegate_migration(member_account.clone()) {
+			return Err(Error::<T>::NotMigrated.into())
+		}
+		let pending = Self::api_pending_rewards(member_account.clone())
+			.ok_or(Error::<T>::PoolMemberNotFound)?;
+		if pending.is_zero() {
+			return Ok(())
+		}
+
+		Self::do_bond_extra(member_account.clone(), member_account.clone(), BondExtra::Rewards)
+	}
+
 	fn do_adjust_pool_deposit(who: T::AccountId, pool: PoolId) -> DispatchResult {
 		let bonded_pool = BondedPool::<T>::get(pool).ok_or(Error::<T>::PoolNotFound)?;
 
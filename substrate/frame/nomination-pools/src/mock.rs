@@ -463,6 +463,7 @@ parameter_types! {
 	pub static PostUnbondingPoolsWindow: u32 = 2;
 	pub static MaxMetadataLen: u32 = 2;
 	pub static CheckLevel: u8 = 255;
+	pub static MaxAutoCompoundPerBlock: u32 = 2;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
 }
 
@@ -487,6 +488,7 @@ impl pools::Config for Runtime {
 	type AdminOrigin = EnsureSignedBy<Admin, AccountId>;
 	type BlockNumberProvider = System;
 	type Filter = RestrictMock;
+	type MaxAutoCompoundPerBlock = MaxAutoCompoundPerBlock;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -7638,3 +7638,212 @@ mod filter {
 		});
 	}
 }
+
+mod auto_compound {
+	use super::*;
+
+	#[test]
+	fn set_auto_compound_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+
+			// only members can opt in.
+			assert_noop!(
+				Pools::set_auto_compound(RuntimeOrigin::signed(21), true),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+
+			// when
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), true));
+
+			// then
+			assert_eq!(AutoCompoundMembers::<Runtime>::iter_keys().collect::<Vec<_>>(), vec![20]);
+
+			// when
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), false));
+
+			// then
+			assert!(!AutoCompoundMembers::<Runtime>::contains_key(20));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::MemberAutoCompoundUpdated { member: 20, enabled: true },
+					Event::MemberAutoCompoundUpdated { member: 20, enabled: false },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn on_idle_compounds_rewards_in_bounded_batches() {
+		ExtBuilder::default()
+			.add_members(vec![(20, 20), (30, 30), (40, 40)])
+			.build_and_execute(|| {
+				// given 20, 30 and 40 opted in, while 10 did not.
+				for who in [20, 30, 40] {
+					assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(who), true));
+				}
+				// and the pool has earned 1 per point.
+				deposit_rewards(100);
+				let _ = pool_events_since_last_call();
+
+				let compounded = |who: AccountId, initial: Balance| {
+					PoolMembers::<Runtime>::get(who).unwrap().points == 2 * initial
+				};
+
+				// when no weight is left, nothing happens.
+				assert_eq!(Pools::on_idle(1, Weight::zero()), Weight::zero());
+				assert!([(20, 20), (30, 30), (40, 40)].iter().all(|(w, p)| !compounded(*w, *p)));
+
+				// when
+				Pools::on_idle(1, Weight::MAX);
+
+				// then only `MaxAutoCompoundPerBlock` members are compounded.
+				assert_eq!(
+					[(20, 20), (30, 30), (40, 40)]
+						.iter()
+						.filter(|(w, p)| compounded(*w, *p))
+						.count(),
+					2
+				);
+				assert!(AutoCompoundCursor::<Runtime>::get().is_some());
+
+				// when
+				Pools::on_idle(2, Weight::MAX);
+
+				// then the sweep resumes with the remaining member and wraps around.
+				assert!([(20, 20), (30, 30), (40, 40)].iter().all(|(w, p)| compounded(*w, *p)));
+				assert!(AutoCompoundCursor::<Runtime>::get().is_none());
+				assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 10);
+				assert_eq!(member_delegation(20), 40);
+				assert_eq!(member_delegation(30), 60);
+				assert_eq!(member_delegation(40), 80);
+
+				// the depositor can still claim their share.
+				assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(10)));
+				assert_eq!(
+					pool_events_since_last_call()
+						.into_iter()
+						.filter(|e| matches!(e, Event::PaidOut { .. }))
+						.count(),
+					4
+				);
+			});
+	}
+
+	#[test]
+	fn members_opted_out_or_without_rewards_are_skipped() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), true));
+			let _ = pool_events_since_last_call();
+
+			// when there are no rewards.
+			Pools::on_idle(1, Weight::MAX);
+
+			// then nothing is bonded.
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 20);
+			assert_eq!(pool_events_since_last_call(), vec![]);
+
+			// when the member opts out and rewards arrive.
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), false));
+			deposit_rewards(30);
+
+			// then the sweep has nothing to do and only consumes the weight of its reads: the
+			// cursor and the end of the members.
+			assert_eq!(
+				Pools::on_idle(2, Weight::MAX),
+				<Runtime as frame_system::Config>::DbWeight::get().reads(2)
+			);
+			assert!(AutoCompoundCursor::<Runtime>::get().is_none());
+
+			// and the rewards stay pending.
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 20);
+			assert_eq!(Pools::api_pending_rewards(20), Some(20));
+		});
+	}
+
+	#[test]
+	fn auto_compound_preference_is_removed_with_member() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), true));
+			assert_ok!(Pools::set_payout_destination(RuntimeOrigin::signed(20), Some(99)));
+
+			// when
+			assert_ok!(Pools::fully_unbond(RuntimeOrigin::signed(20), 20));
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0));
+
+			// then
+			assert!(!PoolMembers::<Runtime>::contains_key(20));
+			assert!(!AutoCompoundMembers::<Runtime>::contains_key(20));
+			assert!(!PayoutDestinations::<Runtime>::contains_key(20));
+		});
+	}
+}
+
+mod payout_destination {
+	use super::*;
+
+	#[test]
+	fn set_payout_destination_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+
+			assert_noop!(
+				Pools::set_payout_destination(RuntimeOrigin::signed(21), Some(99)),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+
+			// when
+			assert_ok!(Pools::set_payout_destination(RuntimeOrigin::signed(20), Some(99)));
+
+			// then
+			assert_eq!(PayoutDestinations::<Runtime>::get(20), Some(99));
+
+			// when the member's own account is set, the destination is cleared.
+			assert_ok!(Pools::set_payout_destination(RuntimeOrigin::signed(20), Some(20)));
+
+			// then
+			assert!(!PayoutDestinations::<Runtime>::contains_key(20));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::MemberPayoutDestinationUpdated { member: 20, destination: Some(99) },
+					Event::MemberPayoutDestinationUpdated { member: 20, destination: None },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn payouts_are_redirected_but_bonded_rewards_are_not() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			// given
+			Currency::set_balance(&20, 0);
+			Currency::set_balance(&99, 0);
+			assert_ok!(Pools::set_payout_destination(RuntimeOrigin::signed(20), Some(99)));
+			deposit_rewards(30);
+			let _ = pool_events_since_last_call();
+
+			// when
+			assert_ok!(Pools::claim_payout_other(RuntimeOrigin::signed(10), 20));
+
+			// then the payout lands in the destination account.
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PaidOut { member: 20, pool_id: 1, payout: 20 }]
+			);
+			assert_eq!(Currency::free_balance(&99), 20);
+			assert_eq!(Currency::free_balance(&20), 0);
+
+			// when rewards are bonded
+			deposit_rewards(30);
+			assert_ok!(Pools::bond_extra(RuntimeOrigin::signed(20), BondExtra::Rewards));
+
+			// then they are bonded for the member and not redirected.
+			assert_eq!(member_delegation(20), 40);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 40);
+			assert_eq!(Currency::free_balance(&99), 20);
+		});
+	}
+}
@@ -271,6 +271,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type BlockNumberProvider = System;
 	type Filter = Nothing;
+	type MaxAutoCompoundPerBlock = ConstU32<16>;
 }
 
 parameter_types! {
@@ -241,16 +241,33 @@ mod benchmarks {
 		// Reserve deposit for recovery
 		T::Currency::reserve(&caller, total_deposit).unwrap();
 
+		// Worst case: the vouches are all from former friends, so none of them count and the
+		// rescuer has to claim the account through the fallback.
+		let mut former_friends =
+			(0..n).map(|x| account("former_friend", x, SEED)).collect::<Vec<T::AccountId>>();
+		former_friends.sort();
+		let bounded_former_friends: FriendsOf<T> = former_friends.try_into().unwrap();
+
 		// Create an active recovery status
 		let recovery_status = ActiveRecovery {
 			created: 0u32.into(),
 			deposit: total_deposit,
-			friends: bounded_friends.clone(),
+			friends: bounded_former_friends,
 		};
 
 		// Create the active recovery storage item
 		<ActiveRecoveries<T>>::insert(&lost_account, &caller, recovery_status);
 
+		// Commit to the rescuer as the recovery fallback
+		<Fallbacks<T>>::insert(
+			&lost_account,
+			RecoveryFallback {
+				commitment: T::Hashing::hash_of(&caller),
+				delay_period: 0u32.into(),
+				deposit: T::FriendDepositFactor::get(),
+			},
+		);
+
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), lost_account_lookup);
 		assert_last_event::<T>(
@@ -353,5 +370,118 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn rotate_friends(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		// Create the recovery config with a single friend
+		let old_friends: FriendsOf<T> = vec![account("old_friend", 0, SEED)].try_into().unwrap();
+		let old_deposit = get_total_deposit::<T>(&old_friends).unwrap();
+
+		let recovery_config = RecoveryConfig {
+			delay_period: DEFAULT_DELAY.into(),
+			deposit: old_deposit,
+			friends: old_friends,
+			threshold: 1,
+		};
+
+		<Recoverable<T>>::insert(&caller, recovery_config);
+
+		// Reserve deposit for recovery
+		T::Currency::reserve(&caller, old_deposit).unwrap();
+
+		// Create the new friends
+		let friends = generate_friends::<T>(n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), friends, n as u16);
+
+		assert_last_event::<T>(
+			Event::FriendsRotated { account: caller, friends: n, threshold: n as u16 }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn cancel_recovery(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let lost_account: T::AccountId = account("lost_account", 0, SEED);
+		let lost_account_lookup = T::Lookup::unlookup(lost_account.clone());
+		let rescuer_account: T::AccountId = account("rescuer_account", 0, SEED);
+		let rescuer_account_lookup = T::Lookup::unlookup(rescuer_account.clone());
+
+		T::Currency::make_free_balance_be(&rescuer_account, BalanceOf::<T>::max_value());
+
+		// Create friends
+		let friends = add_caller_and_generate_friends::<T>(caller.clone(), n);
+		let bounded_friends: FriendsOf<T> = friends.try_into().unwrap();
+
+		// Get deposit for recovery
+		let total_deposit = get_total_deposit::<T>(&bounded_friends).unwrap();
+
+		let recovery_config = RecoveryConfig {
+			delay_period: DEFAULT_DELAY.into(),
+			deposit: total_deposit,
+			friends: bounded_friends.clone(),
+			threshold: n as u16,
+		};
+
+		// Create the recovery config storage item
+		<Recoverable<T>>::insert(&lost_account, recovery_config);
+
+		// Reserve deposit for the recovery attempt
+		T::Currency::reserve(&rescuer_account, total_deposit).unwrap();
+
+		// Create an active recovery status
+		let recovery_status = ActiveRecovery {
+			created: DEFAULT_DELAY.into(),
+			deposit: total_deposit,
+			friends: generate_friends::<T>(n - 1).try_into().unwrap(),
+		};
+
+		// Create the active recovery storage item
+		<ActiveRecoveries<T>>::insert(&lost_account, &rescuer_account, recovery_status);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), lost_account_lookup, rescuer_account_lookup);
+
+		assert_last_event::<T>(
+			Event::RecoveryCancelled { lost_account, rescuer_account, sender: caller }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_recovery_fallback() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		// Create friends
+		let friends = generate_friends::<T>(1);
+		let bounded_friends: FriendsOf<T> = friends.try_into().unwrap();
+
+		// Get deposit for recovery
+		let total_deposit = get_total_deposit::<T>(&bounded_friends).unwrap();
+
+		let recovery_config = RecoveryConfig {
+			delay_period: DEFAULT_DELAY.into(),
+			deposit: total_deposit,
+			friends: bounded_friends,
+			threshold: 1,
+		};
+
+		// Create the recovery config storage item
+		<Recoverable<T>>::insert(&caller, recovery_config);
+
+		let fallback_account: T::AccountId = account("fallback", 0, SEED);
+		let commitment = T::Hashing::hash_of(&fallback_account);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some((commitment, DEFAULT_DELAY.into())));
+
+		assert_last_event::<T>(Event::FallbackSet { account: caller }.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
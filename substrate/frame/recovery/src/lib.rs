@@ -89,7 +89,26 @@
 //! attacker is also able to get enough friends to vouch for the recovery attempt.
 //! In the case where the account owner prevents a malicious recovery process,
 //! this pallet makes it near-zero cost to re-configure the recovery settings and
-//! remove/replace friends who are acting inappropriately.
+//! remove/replace friends who are acting inappropriately with `rotate_friends`.
+//!
+//! If the account owner has lost access to their account, any friend other than
+//! the rescuer can cancel the malicious recovery attempt with `cancel_recovery`,
+//! which also transfers the recovery deposit to the account. Friends and
+//! notification services can follow the `RecoveryInitiated`, `RecoveryVouched`
+//! and `RecoveryThresholdReached` events to learn about recovery attempts early.
+//!
+//! ### Recovery Fallback
+//!
+//! The account owner may additionally commit to the hash of a new account with
+//! `set_recovery_fallback`. That account can recover the account without any
+//! friend vouching for it, once the delay period of the fallback has passed.
+//! Such a recovery attempt can still be closed by the account owner or cancelled
+//! by a friend in the meantime.
+//!
+//! The commitment is an unsalted hash of the account, so it does not keep the
+//! fallback account secret: anyone can hash known accounts and compare them with
+//! the commitment. Only use an account as fallback which may be publicly linked
+//! to the recoverable account.
 //!
 //! ### Safety Considerations
 //!
@@ -127,6 +146,8 @@
 //! #### For Friends of a Recoverable Account
 //! * `vouch_recovery` - As a `friend` of a recoverable account, vouch for a recovery attempt on the
 //!   account.
+//! * `cancel_recovery` - As a `friend` of a recoverable account, cancel a recovery attempt on the
+//!   account started by someone else.
 //!
 //! #### For a User Who Successfully Recovered an Account
 //!
@@ -141,6 +162,10 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `rotate_friends` - Replace the friends and threshold of the recovery configuration, keeping
+//!   its delay period and active recovery attempts.
+//! * `set_recovery_fallback` - Commit to the hash of an account which can recover the account
+//!   without vouches from friends.
 //!
 //! #### For Super Users
 //!
@@ -157,7 +182,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		BlockNumberProvider, CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion,
+		BlockNumberProvider, CheckedAdd, CheckedMul, Dispatchable, Hash, SaturatedConversion,
 		StaticLookup,
 	},
 	RuntimeDebug,
@@ -215,6 +240,21 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	threshold: u16,
 }
 
+/// A pre-committed account which may recover an account without the vouching of friends.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryFallback<BlockNumber, Balance, Hash> {
+	/// The hash of the account which is allowed to recover the account.
+	///
+	/// This hash is not salted and hides nothing: it can be matched against any known account.
+	commitment: Hash,
+	/// The minimum number of blocks since the start of the recovery process before the account
+	/// can be recovered through this fallback.
+	delay_period: BlockNumber,
+	/// The amount held in reserve of the `depositor`,
+	/// to be returned once this fallback is removed.
+	deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -322,6 +362,22 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// The friends and threshold of the recovery configuration of an account have changed.
+		FriendsRotated { account: T::AccountId, friends: u32, threshold: u16 },
+		/// A recovery process for lost account by rescuer account has been vouched for by enough
+		/// friends. It can be claimed by the rescuer after the delay period, unless it is closed
+		/// or cancelled before.
+		RecoveryThresholdReached { lost_account: T::AccountId, rescuer_account: T::AccountId },
+		/// A recovery process for lost account by rescuer account has been cancelled by a friend.
+		RecoveryCancelled {
+			lost_account: T::AccountId,
+			rescuer_account: T::AccountId,
+			sender: T::AccountId,
+		},
+		/// A fallback has been set for the recovery configuration of an account.
+		FallbackSet { account: T::AccountId },
+		/// The fallback of the recovery configuration of an account has been removed.
+		FallbackRemoved { account: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -358,6 +414,10 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// A friend cannot cancel a recovery process they started themselves
+		OwnRecovery,
+		/// This account has no recovery fallback
+		NoFallback,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -385,6 +445,18 @@ pub mod pallet {
 		ActiveRecovery<BlockNumberFromProviderOf<T>, BalanceOf<T>, FriendsOf<T>>,
 	>;
 
+	/// The recovery fallbacks of recoverable accounts.
+	///
+	/// The account whose hash matches the commitment may recover the account without any
+	/// friend vouching for it, after the delay period of the fallback.
+	#[pallet::storage]
+	pub type Fallbacks<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		RecoveryFallback<BlockNumberFromProviderOf<T>, BalanceOf<T>, T::Hash>,
+	>;
+
 	/// The list of allowed proxy accounts.
 	///
 	/// Map from the user who can access it to the recovered account.
@@ -581,6 +653,7 @@ pub mod pallet {
 				Self::active_recovery(&lost, &rescuer).ok_or(Error::<T>::NotStarted)?;
 			// Make sure the voter is a friend
 			ensure!(Self::is_friend(&recovery_config.friends, &who), Error::<T>::NotFriend);
+			let threshold_was_reached = Self::has_threshold(&recovery_config, &active_recovery);
 			// Either insert the vouch, or return an error that the user already vouched.
 			match active_recovery.friends.binary_search(&who) {
 				Ok(_pos) => return Err(Error::<T>::AlreadyVouched.into()),
@@ -589,13 +662,20 @@ pub mod pallet {
 					.try_insert(pos, who.clone())
 					.map_err(|_| Error::<T>::MaxFriends)?,
 			}
+			let threshold_reached = Self::has_threshold(&recovery_config, &active_recovery);
 			// Update storage with the latest details
 			<ActiveRecoveries<T>>::insert(&lost, &rescuer, active_recovery);
 			Self::deposit_event(Event::<T>::RecoveryVouched {
-				lost_account: lost,
-				rescuer_account: rescuer,
+				lost_account: lost.clone(),
+				rescuer_account: rescuer.clone(),
 				sender: who,
 			});
+			if threshold_reached && !threshold_was_reached {
+				Self::deposit_event(Event::<T>::RecoveryThresholdReached {
+					lost_account: lost,
+					rescuer_account: rescuer,
+				});
+			}
 			Ok(())
 		}
 
@@ -605,6 +685,11 @@ pub mod pallet {
 		/// who has successfully completed the account recovery process: collected
 		/// `threshold` or more vouches, waited `delay_period` blocks since initiation.
 		///
+		/// Only vouches of accounts which are still friends of the lost account count towards
+		/// the threshold. If the threshold is not met, the rescuer can still claim the account if
+		/// it matches the commitment of the recovery fallback and the delay period of the fallback
+		/// has passed as well.
+		///
 		/// Parameters:
 		/// - `account`: The lost account that you want to claim has been successfully recovered by
 		///   you.
@@ -630,11 +715,17 @@ pub mod pallet {
 				.checked_add(&recovery_config.delay_period)
 				.ok_or(ArithmeticError::Overflow)?;
 			ensure!(recoverable_block_number <= current_block_number, Error::<T>::DelayPeriod);
-			// Make sure the threshold is met
-			ensure!(
-				recovery_config.threshold as usize <= active_recovery.friends.len(),
-				Error::<T>::Threshold
-			);
+			// Make sure the threshold is met, or fall back to the pre-committed account.
+			if !Self::has_threshold(&recovery_config, &active_recovery) {
+				let fallback = Fallbacks::<T>::get(&account)
+					.filter(|f| f.commitment == T::Hashing::hash_of(&who))
+					.ok_or(Error::<T>::Threshold)?;
+				let fallback_block_number = active_recovery
+					.created
+					.checked_add(&fallback.delay_period)
+					.ok_or(ArithmeticError::Overflow)?;
+				ensure!(fallback_block_number <= current_block_number, Error::<T>::DelayPeriod);
+			}
 			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
 			// Create the recovery storage item
 			Proxy::<T>::insert(&who, &account);
@@ -690,7 +781,8 @@ pub mod pallet {
 		///
 		/// Payment: By calling this function the recoverable account will unreserve
 		/// their recovery configuration deposit.
-		/// (`ConfigDepositBase` + `FriendDepositFactor` * #_of_friends)
+		/// (`ConfigDepositBase` + `FriendDepositFactor` * #_of_friends), as well as the deposit
+		/// of the recovery fallback, if any.
 		///
 		/// The dispatch origin for this call must be _Signed_ and must be a
 		/// recoverable account (i.e. has a recovery configuration).
@@ -706,6 +798,9 @@ pub mod pallet {
 
 			// Unreserve the initial deposit for the recovery configuration.
 			T::Currency::unreserve(&who, recovery_config.deposit);
+			if let Some(fallback) = Fallbacks::<T>::take(&who) {
+				T::Currency::unreserve(&who, fallback.deposit);
+			}
 			Self::deposit_event(Event::<T>::RecoveryRemoved { lost_account: who });
 			Ok(())
 		}
@@ -732,6 +827,167 @@ pub mod pallet {
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Replace the friends and threshold of your recovery configuration, keeping its delay
+		/// period and any active recovery process.
+		///
+		/// Vouches which were made by accounts that are no longer friends stop counting towards
+		/// the threshold of active recovery processes.
+		///
+		/// Payment: The configuration deposit is adjusted to `ConfigDepositBase` +
+		/// `FriendDepositFactor` * #_of_friends, reserving or unreserving the difference.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `friends`: The new list of friends. Should be ordered and contain no duplicate values.
+		/// - `threshold`: The new number of friends that must vouch for a recovery attempt. Should
+		///   be less than or equal to the length of the list of friends.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::rotate_friends(friends.len() as u32))]
+		pub fn rotate_friends(
+			origin: OriginFor<T>,
+			friends: Vec<T::AccountId>,
+			threshold: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut recovery_config =
+				Self::recovery_config(&who).ok_or(Error::<T>::NotRecoverable)?;
+			// Check user input is valid
+			ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
+			ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
+			ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
+			let bounded_friends: FriendsOf<T> =
+				friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+			ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
+			// Total deposit is base fee + number of friends * factor fee
+			let friend_deposit = T::FriendDepositFactor::get()
+				.checked_mul(&bounded_friends.len().saturated_into())
+				.ok_or(ArithmeticError::Overflow)?;
+			let total_deposit = T::ConfigDepositBase::get()
+				.checked_add(&friend_deposit)
+				.ok_or(ArithmeticError::Overflow)?;
+			// Settle the difference with the old deposit
+			if total_deposit > recovery_config.deposit {
+				T::Currency::reserve(&who, total_deposit - recovery_config.deposit)?;
+			} else {
+				T::Currency::unreserve(&who, recovery_config.deposit - total_deposit);
+			}
+			let friends_count = bounded_friends.len() as u32;
+			recovery_config.deposit = total_deposit;
+			recovery_config.friends = bounded_friends;
+			recovery_config.threshold = threshold;
+			<Recoverable<T>>::insert(&who, recovery_config);
+
+			Self::deposit_event(Event::<T>::FriendsRotated {
+				account: who,
+				friends: friends_count,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// As a friend of a recoverable account, cancel an active recovery process for it which
+		/// was started by someone else.
+		///
+		/// Payment: By calling this function, the recoverable account will receive
+		/// the recovery deposit `RecoveryDeposit` placed by the rescuer.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a "friend"
+		/// for the recoverable account, other than the rescuer.
+		///
+		/// Parameters:
+		/// - `lost`: The lost account for which the recovery process was started.
+		/// - `rescuer`: The account trying to rescue the lost account.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::cancel_recovery(T::MaxFriends::get()))]
+		pub fn cancel_recovery(
+			origin: OriginFor<T>,
+			lost: AccountIdLookupOf<T>,
+			rescuer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost = T::Lookup::lookup(lost)?;
+			let rescuer = T::Lookup::lookup(rescuer)?;
+			// Get the recovery configuration for the lost account.
+			let recovery_config = Self::recovery_config(&lost).ok_or(Error::<T>::NotRecoverable)?;
+			// Make sure the sender is a friend, but not the one trying to recover the account.
+			ensure!(Self::is_friend(&recovery_config.friends, &who), Error::<T>::NotFriend);
+			ensure!(who != rescuer, Error::<T>::OwnRecovery);
+			// Take the active recovery process started by the rescuer for this account.
+			let active_recovery =
+				<ActiveRecoveries<T>>::take(&lost, &rescuer).ok_or(Error::<T>::NotStarted)?;
+			// Move the reserved funds from the rescuer to the lost account, as `close_recovery`
+			// would.
+			let res = T::Currency::repatriate_reserved(
+				&rescuer,
+				&lost,
+				active_recovery.deposit,
+				BalanceStatus::Free,
+			);
+			debug_assert!(res.is_ok());
+			Self::deposit_event(Event::<T>::RecoveryCancelled {
+				lost_account: lost,
+				rescuer_account: rescuer,
+				sender: who,
+			});
+			Ok(())
+		}
+
+		/// Set or remove the recovery fallback of your account.
+		///
+		/// The fallback commits to the hash of an account, which can recover your account without
+		/// any friend vouching for it, once both the `delay_period` of the fallback and of the
+		/// recovery configuration have passed since it initiated the recovery process. Until then,
+		/// you can close the process and your friends can cancel it.
+		///
+		/// The commitment is the unsalted `T::Hashing` hash of the account, so it does not hide
+		/// the fallback account: anyone can find it by hashing known accounts.
+		///
+		/// Payment: `FriendDepositFactor` balance will be reserved for storing the fallback. This
+		/// deposit is returned in full when the fallback or the recovery configuration is removed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `fallback`: The hash of the fallback account and the number of blocks after a recovery
+		///   attempt is initialized that need to pass before it can be claimed by that account, or
+		///   `None` to remove the fallback.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_recovery_fallback())]
+		pub fn set_recovery_fallback(
+			origin: OriginFor<T>,
+			fallback: Option<(T::Hash, BlockNumberFromProviderOf<T>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Recoverable<T>>::contains_key(&who), Error::<T>::NotRecoverable);
+			let old_deposit = Fallbacks::<T>::get(&who).map(|f| f.deposit);
+			match fallback {
+				Some((commitment, delay_period)) => {
+					let deposit = T::FriendDepositFactor::get();
+					let old_deposit = old_deposit.unwrap_or_default();
+					if deposit > old_deposit {
+						T::Currency::reserve(&who, deposit - old_deposit)?;
+					} else {
+						T::Currency::unreserve(&who, old_deposit - deposit);
+					}
+					Fallbacks::<T>::insert(
+						&who,
+						RecoveryFallback { commitment, delay_period, deposit },
+					);
+					Self::deposit_event(Event::<T>::FallbackSet { account: who });
+				},
+				None => {
+					let old_deposit = old_deposit.ok_or(Error::<T>::NoFallback)?;
+					Fallbacks::<T>::remove(&who);
+					T::Currency::unreserve(&who, old_deposit);
+					Self::deposit_event(Event::<T>::FallbackRemoved { account: who });
+				},
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -745,4 +1001,17 @@ impl<T: Config> Pallet<T> {
 	fn is_friend(friends: &Vec<T::AccountId>, friend: &T::AccountId) -> bool {
 		friends.binary_search(&friend).is_ok()
 	}
+
+	/// Check that enough of the current friends have vouched for an active recovery.
+	fn has_threshold(
+		recovery_config: &RecoveryConfig<BlockNumberFromProviderOf<T>, BalanceOf<T>, FriendsOf<T>>,
+		active_recovery: &ActiveRecovery<BlockNumberFromProviderOf<T>, BalanceOf<T>, FriendsOf<T>>,
+	) -> bool {
+		let vouches = active_recovery
+			.friends
+			.iter()
+			.filter(|f| Self::is_friend(&recovery_config.friends, f))
+			.count();
+		recovery_config.threshold as usize <= vouches
+	}
 }
@@ -19,7 +19,10 @@
 
 use crate::{mock::*, *};
 use frame_support::{assert_noop, assert_ok, traits::Currency};
-use sp_runtime::{bounded_vec, traits::BadOrigin};
+use sp_runtime::{
+	bounded_vec,
+	traits::{BadOrigin, BlakeTwo256, Hash},
+};
 
 #[test]
 fn basic_setup_works() {
@@ -364,6 +367,41 @@ fn vouch_recovery_works() {
 	});
 }
 
+#[test]
+fn threshold_reached_is_emitted_once() {
+	new_test_ext().execute_with(|| {
+		System::run_to_block::<AllPalletsWithSystem>(1);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 2, 10));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		let threshold_events = || {
+			System::events()
+				.into_iter()
+				.filter(|r| {
+					r.event ==
+						Event::<Test>::RecoveryThresholdReached {
+							lost_account: 5,
+							rescuer_account: 1,
+						}
+						.into()
+				})
+				.count()
+		};
+
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_eq!(threshold_events(), 0);
+		// The vouch crossing the threshold emits the event
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_eq!(threshold_events(), 1);
+		// Later vouches do not
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		System::assert_last_event(
+			Event::<Test>::RecoveryVouched { lost_account: 5, rescuer_account: 1, sender: 4 }
+				.into(),
+		);
+		assert_eq!(threshold_events(), 1);
+	});
+}
+
 #[test]
 fn claim_recovery_handles_basic_errors() {
 	new_test_ext().execute_with(|| {
@@ -491,3 +529,150 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
 	});
 }
+
+#[test]
+fn rotate_friends_works() {
+	new_test_ext().execute_with(|| {
+		// Cannot rotate the friends of an unrecoverable account
+		assert_noop!(
+			Recovery::rotate_friends(RuntimeOrigin::signed(5), vec![2, 3], 2),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 2, 10));
+		assert_eq!(Balances::reserved_balance(5), 13);
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		System::assert_last_event(
+			Event::<Test>::RecoveryThresholdReached { lost_account: 5, rescuer_account: 1 }.into(),
+		);
+		// Input is checked like in `create_recovery`
+		assert_noop!(
+			Recovery::rotate_friends(RuntimeOrigin::signed(5), vec![4, 3], 2),
+			Error::<Test>::NotSorted
+		);
+		assert_noop!(
+			Recovery::rotate_friends(RuntimeOrigin::signed(5), vec![3, 4], 3),
+			Error::<Test>::NotEnoughFriends
+		);
+		// Account 5 notices that account 2 vouched for a malicious recovery and removes it
+		assert_ok!(Recovery::rotate_friends(RuntimeOrigin::signed(5), vec![3, 4], 2));
+		System::assert_last_event(
+			Event::<Test>::FriendsRotated { account: 5, friends: 2, threshold: 2 }.into(),
+		);
+		// The deposit is adjusted to the new number of friends
+		assert_eq!(Balances::reserved_balance(5), 12);
+		let recovery_config = RecoveryConfig {
+			delay_period: 10,
+			deposit: 12,
+			friends: bounded_vec![3, 4],
+			threshold: 2,
+		};
+		assert_eq!(Recovery::recovery_config(5), Some(recovery_config));
+		// The vouch of account 2 no longer counts towards the threshold
+		System::run_to_block::<AllPalletsWithSystem>(20);
+		assert_noop!(
+			Recovery::claim_recovery(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::Threshold
+		);
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+	});
+}
+
+#[test]
+fn cancel_recovery_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Recovery::cancel_recovery(RuntimeOrigin::signed(2), 5, 1),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(2), 5));
+		// Only friends can cancel a recovery attempt
+		assert_noop!(
+			Recovery::cancel_recovery(RuntimeOrigin::signed(1), 5, 2),
+			Error::<Test>::NotFriend
+		);
+		// A friend cannot cancel their own recovery attempt
+		assert_noop!(
+			Recovery::cancel_recovery(RuntimeOrigin::signed(2), 5, 2),
+			Error::<Test>::OwnRecovery
+		);
+		// Account 3 cancels the recovery attempt of account 1 on behalf of account 5
+		assert_ok!(Recovery::cancel_recovery(RuntimeOrigin::signed(3), 5, 1));
+		System::assert_last_event(
+			Event::<Test>::RecoveryCancelled { lost_account: 5, rescuer_account: 1, sender: 3 }
+				.into(),
+		);
+		// The deposit of account 1 went to account 5
+		assert_eq!(Balances::total_balance(&1), 90);
+		assert_eq!(Balances::total_balance(&5), 110);
+		assert_eq!(Recovery::active_recovery(&5, &1), None);
+		assert_noop!(
+			Recovery::cancel_recovery(RuntimeOrigin::signed(3), 5, 1),
+			Error::<Test>::NotStarted
+		);
+		// A friend can also cancel the recovery attempt of another friend
+		assert_ok!(Recovery::cancel_recovery(RuntimeOrigin::signed(4), 5, 2));
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_eq!(Balances::total_balance(&5), 120);
+	});
+}
+
+#[test]
+fn recovery_fallback_works() {
+	new_test_ext().execute_with(|| {
+		let commitment = BlakeTwo256::hash_of(&1u64);
+		// Only recoverable accounts can set a fallback
+		assert_noop!(
+			Recovery::set_recovery_fallback(RuntimeOrigin::signed(5), Some((commitment, 20))),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_noop!(
+			Recovery::set_recovery_fallback(RuntimeOrigin::signed(5), None),
+			Error::<Test>::NoFallback
+		);
+		assert_ok!(Recovery::set_recovery_fallback(
+			RuntimeOrigin::signed(5),
+			Some((commitment, 20))
+		));
+		System::assert_last_event(Event::<Test>::FallbackSet { account: 5 }.into());
+		// Setting a fallback reserves a friend deposit
+		assert_eq!(Balances::reserved_balance(5), 14);
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(2), 5));
+		// Without vouches, the fallback delay period applies
+		System::run_to_block::<AllPalletsWithSystem>(20);
+		assert_noop!(
+			Recovery::claim_recovery(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::DelayPeriod
+		);
+		System::run_to_block::<AllPalletsWithSystem>(30);
+		// Only the committed account can use the fallback
+		assert_noop!(
+			Recovery::claim_recovery(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::Threshold
+		);
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		assert_eq!(Recovery::proxy(&1), Some(5));
+		// Account 5 removes the fallback and gets the deposit back
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 2));
+		assert_ok!(Recovery::set_recovery_fallback(RuntimeOrigin::signed(5), None));
+		System::assert_last_event(Event::<Test>::FallbackRemoved { account: 5 }.into());
+		assert_eq!(Balances::reserved_balance(5), 13);
+		// Removing the recovery configuration also removes the fallback
+		assert_ok!(Recovery::set_recovery_fallback(
+			RuntimeOrigin::signed(5),
+			Some((commitment, 20))
+		));
+		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert!(!<Fallbacks<Test>>::contains_key(&5));
+	});
+}
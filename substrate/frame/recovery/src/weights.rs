@@ -81,6 +81,9 @@ pub trait WeightInfo {
 	fn close_recovery(n: u32, ) -> Weight;
 	fn remove_recovery(n: u32, ) -> Weight;
 	fn cancel_recovered() -> Weight;
+	fn rotate_friends(n: u32, ) -> Weight;
+	fn cancel_recovery(n: u32, ) -> Weight;
+	fn set_recovery_fallback() -> Weight;
 }
 
 /// Weights for `pallet_recovery` using the Substrate node and recommended hardware.
@@ -159,16 +162,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Fallbacks` (r:1 w:0)
+	/// Proof: `Recovery::Fallbacks` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `201 + n * (64 ±0)`
+		//  Measured:  `293 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 20_990_000 picoseconds.
-		Weight::from_parts(22_056_997, 3854)
+		// Minimum execution time: 26_734_000 picoseconds.
+		Weight::from_parts(27_801_997, 3854)
 			// Standard Error: 5_490
-			.saturating_add(Weight::from_parts(551, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(Weight::from_parts(105_466, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
@@ -212,6 +217,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn rotate_friends(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + n * (32 ±0)`
+		//  Estimated: `3816`
+		// Minimum execution time: 22_424_000 picoseconds.
+		Weight::from_parts(22_431_118, 3816)
+			// Standard Error: 13_587
+			.saturating_add(Weight::from_parts(95_112, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + n * (64 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 36_010_000 picoseconds.
+		Weight::from_parts(36_017_534, 3854)
+			// Standard Error: 18_771
+			.saturating_add(Weight::from_parts(131_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Fallbacks` (r:1 w:1)
+	/// Proof: `Recovery::Fallbacks` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn set_recovery_fallback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3816`
+		// Minimum execution time: 19_839_000 picoseconds.
+		Weight::from_parts(19_846_000, 3816)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -289,16 +339,18 @@ impl WeightInfo for () {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Fallbacks` (r:1 w:0)
+	/// Proof: `Recovery::Fallbacks` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `201 + n * (64 ±0)`
+		//  Measured:  `293 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 20_990_000 picoseconds.
-		Weight::from_parts(22_056_997, 3854)
+		// Minimum execution time: 26_734_000 picoseconds.
+		Weight::from_parts(27_801_997, 3854)
 			// Standard Error: 5_490
-			.saturating_add(Weight::from_parts(551, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(Weight::from_parts(105_466, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
@@ -342,4 +394,49 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn rotate_friends(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + n * (32 ±0)`
+		//  Estimated: `3816`
+		// Minimum execution time: 22_424_000 picoseconds.
+		Weight::from_parts(22_431_118, 3816)
+			// Standard Error: 13_587
+			.saturating_add(Weight::from_parts(95_112, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + n * (64 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 36_010_000 picoseconds.
+		Weight::from_parts(36_017_534, 3854)
+			// Standard Error: 18_771
+			.saturating_add(Weight::from_parts(131_402, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Fallbacks` (r:1 w:1)
+	/// Proof: `Recovery::Fallbacks` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn set_recovery_fallback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3816`
+		// Minimum execution time: 19_839_000 picoseconds.
+		Weight::from_parts(19_846_000, 3816)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
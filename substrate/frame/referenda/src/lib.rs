@@ -90,6 +90,7 @@ use sp_runtime::{
 
 mod branch;
 pub mod migration;
+pub mod runtime_api;
 mod types;
 pub mod weights;
 
@@ -98,9 +99,10 @@ pub use self::{
 	pallet::*,
 	types::{
 		BalanceOf, BlockNumberFor, BoundedCallOf, CallOf, Curve, DecidingStatus, DecidingStatusOf,
-		Deposit, InsertSorted, NegativeImbalanceOf, PalletsOriginOf, ReferendumIndex,
-		ReferendumInfo, ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf,
-		TallyOf, Track, TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
+		Deposit, InsertSorted, NegativeImbalanceOf, PalletsOriginOf, PassingRequirement,
+		ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf,
+		ReferendumTimeline, ScheduleAddressOf, TallyOf, Track, TrackIdOf, TrackInfo, TrackInfoOf,
+		TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -873,6 +875,121 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Returns the projected timeline of ongoing referendum `index`, assuming its tally does not
+	/// change.
+	pub fn referendum_timeline(
+		index: ReferendumIndex,
+	) -> Result<ReferendumTimeline<BlockNumberFor<T, I>>, DispatchError> {
+		let status = Self::ensure_ongoing(index)?;
+		let track = T::Tracks::info(status.track).ok_or(Error::<T, I>::NoTrack)?;
+		let decision_start = Self::projected_decision_start(&status, &track);
+		let (confirmation_start, confirmation_end) = match status
+			.deciding
+			.as_ref()
+			.and_then(|d| d.confirming)
+		{
+			Some(end) => (Some(end.saturating_sub(track.confirm_period)), Some(end)),
+			None => {
+				let start = decision_start.and_then(|since| {
+					Self::projected_confirmation_start(since, &status.tally, status.track, &track)
+				});
+				(start, start.map(|s| s.saturating_add(track.confirm_period)))
+			},
+		};
+		Ok(ReferendumTimeline {
+			decision_start,
+			decision_end: decision_start.map(|since| since.saturating_add(track.decision_period)),
+			confirmation_start,
+			confirmation_end,
+			timeout: status
+				.deciding
+				.is_none()
+				.then(|| status.submitted.saturating_add(T::UndecidingTimeout::get())),
+			next_alarm: status.alarm.map(|(when, _)| when),
+		})
+	}
+
+	/// Returns the approval and support ongoing referendum `index` needs in order to be approved
+	/// by block `at`, or `None` if it cannot be approved by then anymore.
+	///
+	/// Since the thresholds of the track only decrease, these are the thresholds at the latest
+	/// block at which confirmation can begin, or at the current block if already confirming.
+	pub fn passing_requirement(
+		index: ReferendumIndex,
+		at: BlockNumberFor<T, I>,
+	) -> Result<Option<PassingRequirement>, DispatchError> {
+		let status = Self::ensure_ongoing(index)?;
+		let track = T::Tracks::info(status.track).ok_or(Error::<T, I>::NoTrack)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let Some(since) = Self::projected_decision_start(&status, &track) else { return Ok(None) };
+		let confirm_by = match status.deciding.as_ref().and_then(|d| d.confirming) {
+			Some(end) if end <= at => now,
+			Some(_) => return Ok(None),
+			None => {
+				let confirm_by = at.saturating_sub(track.confirm_period);
+				if at < track.confirm_period ||
+					confirm_by < now.max(since) ||
+					confirm_by > since.saturating_add(track.decision_period)
+				{
+					return Ok(None)
+				}
+				confirm_by
+			},
+		};
+		let elapsed = confirm_by.saturating_sub(since).min(track.decision_period);
+		let x = Perbill::from_rational(elapsed, track.decision_period);
+		let approval = track.min_approval.threshold(x);
+		let support = track.min_support.threshold(x);
+		Ok(Some(PassingRequirement {
+			approval,
+			support,
+			additional_approval: approval.saturating_sub(status.tally.approval(status.track)),
+			additional_support: support.saturating_sub(status.tally.support(status.track)),
+		}))
+	}
+
+	/// The block at which the referendum of `status` began or will begin being decided, if known.
+	fn projected_decision_start(
+		status: &ReferendumStatusOf<T, I>,
+		track: &TrackInfoOf<T, I>,
+	) -> Option<BlockNumberFor<T, I>> {
+		if let Some(deciding) = status.deciding.as_ref() {
+			return Some(deciding.since)
+		}
+		if status.decision_deposit.is_none() ||
+			status.in_queue ||
+			DecidingCount::<T, I>::get(status.track) >= track.max_deciding
+		{
+			return None
+		}
+		let now = T::BlockNumberProvider::current_block_number();
+		let start = status.submitted.saturating_add(track.prepare_period).max(now);
+		(start < status.submitted.saturating_add(T::UndecidingTimeout::get())).then_some(start)
+	}
+
+	/// The block at which a referendum being decided since `since` will begin confirming with the
+	/// given `tally`, or `None` if it does not pass before the end of the decision period.
+	fn projected_confirmation_start(
+		since: BlockNumberFor<T, I>,
+		tally: &T::Tally,
+		track_id: TrackIdOf<T, I>,
+		track: &TrackInfoOf<T, I>,
+	) -> Option<BlockNumberFor<T, I>> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let deciding = DecidingStatus { since, confirming: None };
+		let start = Self::decision_time(&deciding, tally, track_id, track).max(now).max(since);
+		let elapsed = start.saturating_sub(since);
+		let passing = Self::is_passing(
+			tally,
+			elapsed,
+			track.decision_period,
+			&track.min_support,
+			&track.min_approval,
+			track_id,
+		);
+		(passing && elapsed <= track.decision_period).then_some(start)
+	}
+
 	// Enqueue a proposal from a referendum which has presumably passed.
 	fn schedule_enactment(
 		index: ReferendumIndex,
@@ -0,0 +1,41 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME Referenda pallet.
+
+use crate::{PassingRequirement, ReferendumIndex, ReferendumTimeline};
+use codec::Codec;
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+	pub trait ReferendaApi<BlockNumber>
+	where
+		BlockNumber: Codec
+	{
+		/// The projected timeline of an ongoing referendum, assuming its tally does not change.
+		fn referendum_timeline(
+			index: ReferendumIndex,
+		) -> Result<ReferendumTimeline<BlockNumber>, DispatchError>;
+
+		/// The approval and support an ongoing referendum needs in order to be approved by block
+		/// `at`, or `None` if it cannot be approved by then anymore.
+		fn passing_requirement(
+			index: ReferendumIndex,
+			at: BlockNumber,
+		) -> Result<Option<PassingRequirement>, DispatchError>;
+	}
+}
@@ -707,3 +707,84 @@ fn zero_enactment_delay_executes_proposal_at_next_block() {
 		assert_eq!(Balances::free_balance(42), 20);
 	});
 }
+
+#[test]
+fn referendum_timeline_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		// Without a decision deposit, only the timeout is known.
+		assert_eq!(
+			Referenda::referendum_timeline(0),
+			Ok(ReferendumTimeline {
+				decision_start: None,
+				decision_end: None,
+				confirmation_start: None,
+				confirmation_end: None,
+				timeout: Some(21),
+				next_alarm: Some(21),
+			})
+		);
+		assert_eq!(Referenda::passing_requirement(0, 9), Ok(None));
+
+		// Deciding starts once preparation is over, but nobody voted yet.
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		let timeline = Referenda::referendum_timeline(0).unwrap();
+		assert_eq!(timeline.decision_start, Some(5));
+		assert_eq!(timeline.decision_end, Some(9));
+		assert_eq!(timeline.confirmation_start, None);
+		assert_eq!(timeline.next_alarm, Some(5));
+
+		// Half way through the decision period the track wants 75% approval and 50% support.
+		set_tally(0, 20, 20);
+		assert_eq!(
+			Referenda::passing_requirement(0, 9),
+			Ok(Some(PassingRequirement {
+				approval: Perbill::from_percent(75),
+				support: Perbill::from_percent(50),
+				additional_approval: Perbill::from_percent(25),
+				additional_support: Perbill::from_percent(30),
+			}))
+		);
+		// Too early to confirm in time, or after the decision period.
+		assert_eq!(Referenda::passing_requirement(0, 6), Ok(None));
+		assert_eq!(Referenda::passing_requirement(0, 12), Ok(None));
+
+		// With this tally it just passes at the very end of the decision period.
+		run_to(5);
+		assert_eq!(
+			Referenda::referendum_timeline(0),
+			Ok(ReferendumTimeline {
+				decision_start: Some(5),
+				decision_end: Some(9),
+				confirmation_start: Some(9),
+				confirmation_end: Some(11),
+				timeout: None,
+				next_alarm: Some(9),
+			})
+		);
+
+		// Once confirming, the actual confirmation is reported.
+		set_tally(0, 100, 0);
+		let timeline = Referenda::referendum_timeline(0).unwrap();
+		assert_eq!(timeline.confirmation_start, Some(5));
+		assert_eq!(timeline.confirmation_end, Some(7));
+		assert_eq!(
+			Referenda::passing_requirement(0, 8),
+			Ok(Some(PassingRequirement {
+				approval: Perbill::one(),
+				support: Perbill::one(),
+				additional_approval: Perbill::zero(),
+				additional_support: Perbill::zero(),
+			}))
+		);
+		assert_eq!(Referenda::passing_requirement(0, 6), Ok(None));
+
+		run_to(7);
+		assert_eq!(Referenda::referendum_timeline(0), Err(Error::<Test>::NotOngoing.into()));
+	});
+}
@@ -326,6 +326,40 @@ impl<
 	}
 }
 
+/// The projected course of an ongoing referendum, assuming its tally does not change.
+///
+/// Blocks which cannot be projected, e.g. the start of the deciding phase of a referendum which
+/// has no decision deposit yet or is waiting in the track queue, are `None`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumTimeline<Moment> {
+	/// When the referendum began or will begin being decided.
+	pub decision_start: Option<Moment>,
+	/// When the decision period ends. The referendum is rejected then unless confirming.
+	pub decision_end: Option<Moment>,
+	/// When the referendum began or will begin confirming. `None` if the current tally does not
+	/// pass before the end of the decision period.
+	pub confirmation_start: Option<Moment>,
+	/// When the referendum will be approved, if it keeps passing while confirming.
+	pub confirmation_end: Option<Moment>,
+	/// When the referendum times out if it has not begun being decided by then.
+	pub timeout: Option<Moment>,
+	/// The next block at which the referendum is scheduled to be serviced.
+	pub next_alarm: Option<Moment>,
+}
+
+/// The approval and support an ongoing referendum needs in order to be approved by some block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PassingRequirement {
+	/// The approval needed at the latest block at which confirmation can begin.
+	pub approval: Perbill,
+	/// The support needed at the latest block at which confirmation can begin.
+	pub support: Perbill,
+	/// The approval needed on top of the current one.
+	pub additional_approval: Perbill,
+	/// The support needed on top of the current one.
+	pub additional_support: Perbill,
+}
+
 /// Type for describing a curve over the 2-dimensional space of axes between 0-1, as represented
 /// by `(Perbill, Perbill)`.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
@@ -19,9 +19,14 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::Codec;
+use sp_runtime::Perbill;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(3)]
 	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
@@ -35,5 +40,36 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Projects the rewards of a hypothetical nominator bonding `bond` and nominating
+		/// `targets`, for each era in history that has a recorded payout.
+		#[api_version(2)]
+		fn simulate_rewards(
+			bond: Balance,
+			targets: Vec<AccountId>,
+		) -> Vec<sp_staking::EraRewardProjection<AccountId, Balance>>;
+
+		/// Computes the slash that an offence of `validator` in `era`, slashing `fraction` of its
+		/// exposure, would result in if it were processed now.
+		///
+		/// Returns `None` if the offence would not be slashed at all.
+		#[api_version(3)]
+		fn simulate_slash(
+			validator: AccountId,
+			era: sp_staking::EraIndex,
+			fraction: Perbill,
+		) -> Option<sp_staking::SlashSimulation<AccountId, Balance>>;
+
+		/// Returns the largest slash fraction recorded against each validator, for every era
+		/// still in history.
+		///
+		/// Returns at most `limit` records in storage order, starting after the record of the era
+		/// and validator in `start_after`. Pass those of the last returned record to fetch the
+		/// next page.
+		#[api_version(3)]
+		fn recorded_offences(
+			start_after: Option<(sp_staking::EraIndex, AccountId)>,
+			limit: u32,
+		) -> Vec<(sp_staking::EraIndex, AccountId, Perbill)>;
 	}
 }
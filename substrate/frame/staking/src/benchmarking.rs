@@ -884,6 +884,61 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn payout_secondary_stakers(
+		n: Linear<0, { T::MaxExposurePageSize::get() as u32 }>,
+	) -> Result<(), BenchmarkError> {
+		if !T::SecondaryReward::is_enabled() {
+			return Err(BenchmarkError::Weightless)
+		}
+
+		// reset genesis era 0 so that triggering the new genesis era works as expected.
+		CurrentEra::<T>::set(Some(0));
+		let current_era = CurrentEra::<T>::get().unwrap();
+		Staking::<T>::clear_era_information(current_era);
+
+		let (validator, _) = create_validator_with_nominators::<T>(
+			n,
+			T::MaxExposurePageSize::get() as u32,
+			false,
+			true,
+			RewardDestination::Staked,
+			current_era,
+		)?;
+
+		// set the commission for this particular era as well.
+		<ErasValidatorPrefs<T>>::insert(
+			current_era,
+			validator.clone(),
+			Validators::<T>::get(&validator),
+		);
+
+		let total: SecondaryBalanceOf<T> = 1_000_000_000u32.into();
+		T::SecondaryReward::fund(total);
+		ErasSecondaryReward::<T>::insert(
+			current_era,
+			SecondaryEraReward { total, claimed: Zero::zero() },
+		);
+		SecondaryRewardCommitted::<T>::put(total);
+
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		payout_secondary_stakers_by_page(
+			RawOrigin::Signed(caller),
+			validator.clone(),
+			current_era,
+			0,
+		);
+
+		ensure!(
+			EraInfo::<T>::is_secondary_rewards_claimed(current_era, &validator, 0),
+			"Secondary reward of the page should have been claimed.",
+		);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn rebond(l: Linear<1, { T::MaxUnlockingChunks::get() as u32 }>) -> Result<(), BenchmarkError> {
 		// clean up any existing state.
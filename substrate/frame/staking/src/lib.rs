@@ -279,6 +279,18 @@
 //! - Stash account, also increasing the staked value.
 //! - Any other account, sent as free balance.
 //!
+//! ### Secondary Rewards
+//!
+//! On top of the native payout, a runtime can pay stakers a secondary reward in another asset
+//! through [`Config::SecondaryReward`], for example a [`FungiblesRewardPot`] topped up by a
+//! treasury. Whatever the pot holds at the end of an era, beyond what is still owed to previous
+//! eras, is set aside for that era in [`ErasSecondaryReward`]. It is split exactly like the native
+//! reward and paid to the stash accounts along with each page in
+//! [`payout_stakers`](Call::payout_stakers). Claimed pages are tracked separately in
+//! [`ClaimedSecondaryRewards`], so a page whose secondary payout failed can still be claimed with
+//! [`payout_secondary_stakers_by_page`](Call::payout_secondary_stakers_by_page). Whatever is not
+//! claimed within [`Config::HistoryDepth`] eras is released back to the pot.
+//!
 //! ### Additional Fund Management Operations
 //!
 //! Any funds already placed into stash can be the target of the following operations:
@@ -349,7 +361,11 @@ use frame_election_provider_support::ElectionProvider;
 use frame_support::{
 	defensive, defensive_assert,
 	traits::{
-		tokens::fungible::{Credit, Debt},
+		fungibles,
+		tokens::{
+			fungible::{Credit, Debt},
+			Fortitude, Preservation,
+		},
 		ConstU32, Contains, Defensive, DefensiveMax, DefensiveSaturating, Get, LockIdentifier,
 	},
 	weights::Weight,
@@ -358,8 +374,8 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_runtime::{
 	curve::PiecewiseLinear,
-	traits::{AtLeast32BitUnsigned, Convert, StaticLookup, Zero},
-	Perbill, Perquintill, Rounding, RuntimeDebug, Saturating,
+	traits::{AtLeast32BitUnsigned, Convert, MaybeSerializeDeserialize, StaticLookup, Zero},
+	DispatchError, DispatchResult, Perbill, Perquintill, Rounding, RuntimeDebug, Saturating,
 };
 use sp_staking::{
 	offence::{Offence, OffenceError, OffenceSeverity, ReportOffence},
@@ -412,6 +428,11 @@ pub type RewardPoint = u32;
 /// The balance type of this pallet.
 pub type BalanceOf<T> = <T as Config>::CurrencyBalance;
 
+/// The balance type of the secondary reward asset.
+pub type SecondaryBalanceOf<T> = <<T as Config>::SecondaryReward as SecondaryRewardPot<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
 type PositiveImbalanceOf<T> = Debt<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 pub type NegativeImbalanceOf<T> =
 	Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
@@ -1021,6 +1042,119 @@ where
 	}
 }
 
+/// Source of an optional secondary staking reward, paid in an asset other than
+/// [`Config::Currency`] on top of the regular era payout.
+///
+/// Whatever is available in the pot when an era ends is set aside for that era and distributed
+/// to its stakers when their pages are paid out, following the same split as the native reward.
+pub trait SecondaryRewardPot<AccountId> {
+	/// The balance type of the secondary reward asset.
+	type Balance: AtLeast32BitUnsigned
+		+ codec::FullCodec
+		+ DecodeWithMemTracking
+		+ Copy
+		+ MaybeSerializeDeserialize
+		+ core::fmt::Debug
+		+ Default
+		+ TypeInfo
+		+ MaxEncodedLen;
+
+	/// Whether secondary rewards are enabled at all.
+	fn is_enabled() -> bool;
+
+	/// The amount currently held by the pot that can be paid out.
+	fn available() -> Self::Balance;
+
+	/// Pay `amount` out of the pot to `who`.
+	fn pay(who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Make sure the pot holds at least `amount`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(_amount: Self::Balance) {}
+}
+
+impl<AccountId> SecondaryRewardPot<AccountId> for () {
+	type Balance = u128;
+
+	fn is_enabled() -> bool {
+		false
+	}
+
+	fn available() -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn pay(_: &AccountId, _: Self::Balance) -> DispatchResult {
+		Err(DispatchError::Other("secondary rewards are disabled"))
+	}
+}
+
+/// A [`SecondaryRewardPot`] backed by the `AssetId` asset of `Assets`, held by the `Pot` account.
+///
+/// The pot is kept alive, so at least the minimum balance of the asset is never paid out.
+pub struct FungiblesRewardPot<Assets, AssetId, Pot>(
+	core::marker::PhantomData<(Assets, AssetId, Pot)>,
+);
+impl<AccountId, Assets, AssetId, Pot> SecondaryRewardPot<AccountId>
+	for FungiblesRewardPot<Assets, AssetId, Pot>
+where
+	Assets: fungibles::Mutate<AccountId>,
+	Assets::Balance: DecodeWithMemTracking + MaybeSerializeDeserialize,
+	AssetId: Get<Assets::AssetId>,
+	Pot: Get<AccountId>,
+{
+	type Balance = Assets::Balance;
+
+	fn is_enabled() -> bool {
+		true
+	}
+
+	fn available() -> Self::Balance {
+		Assets::reducible_balance(
+			AssetId::get(),
+			&Pot::get(),
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+	}
+
+	fn pay(who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		Assets::transfer(AssetId::get(), &Pot::get(), who, amount, Preservation::Preserve)
+			.map(|_| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(amount: Self::Balance) {
+		let pot = Pot::get();
+		let held = Assets::balance(AssetId::get(), &pot);
+		let target = amount.saturating_add(Assets::minimum_balance(AssetId::get()));
+		if held < target {
+			let _ = Assets::mint_into(AssetId::get(), &pot, target - held);
+		}
+	}
+}
+
+/// The amount of secondary reward set aside for an era.
+#[derive(
+	PartialEq,
+	Eq,
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	Default,
+)]
+pub struct SecondaryEraReward<Balance> {
+	/// The total amount set aside for the era.
+	pub total: Balance,
+	/// The part of `total` that belongs to pages which have already been paid out.
+	pub claimed: Balance,
+}
+
 /// Mode of era-forcing.
 #[derive(
 	Copy,
@@ -1143,6 +1277,15 @@ impl<T: Config> EraInfo<T> {
 		ClaimedRewards::<T>::get(era, validator).contains(&page)
 	}
 
+	/// Check if the secondary rewards for the given era and page index have been claimed.
+	pub(crate) fn is_secondary_rewards_claimed(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page: Page,
+	) -> bool {
+		ClaimedSecondaryRewards::<T>::get(era, validator).contains(&page)
+	}
+
 	/// Get exposure for a validator at a given era and page.
 	///
 	/// This builds a paged exposure from `PagedExposureMetadata` and `ExposurePage` of the
@@ -1240,6 +1383,22 @@ impl<T: Config> EraInfo<T> {
 		ClaimedRewards::<T>::insert(era, validator, claimed_pages);
 	}
 
+	/// Creates an entry to track validator secondary reward has been claimed for a given era and
+	/// page. Noop if already claimed.
+	pub(crate) fn set_secondary_rewards_as_claimed(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page: Page,
+	) {
+		ClaimedSecondaryRewards::<T>::mutate(era, validator, |claimed_pages| {
+			if claimed_pages.contains(&page) {
+				defensive!("Trying to set an already claimed secondary reward");
+				return
+			}
+			claimed_pages.push(page);
+		});
+	}
+
 	/// Store exposure for elected validators at start of an era.
 	///
 	/// If the exposure does not exist yet for the tuple (era, validator), it sets it. Otherwise,
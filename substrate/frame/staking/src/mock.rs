@@ -24,7 +24,7 @@ use frame_election_provider_support::{
 	Support, VoteWeight,
 };
 use frame_support::{
-	assert_ok, derive_impl, ord_parameter_types, parameter_types,
+	assert_ok, derive_impl, ensure, ord_parameter_types, parameter_types,
 	traits::{
 		ConstU64, EitherOfDiverse, FindAuthor, Get, Imbalance, OnUnbalanced, OneSessionHandler,
 	},
@@ -33,7 +33,10 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::ConstBool;
 use sp_io;
-use sp_runtime::{curve::PiecewiseLinear, testing::UintAuthorityId, traits::Zero, BuildStorage};
+use sp_runtime::{
+	curve::PiecewiseLinear, testing::UintAuthorityId, traits::Zero, BuildStorage, DispatchResult,
+	TokenError,
+};
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
 	OnStakingUpdate, StakingInterface,
@@ -314,6 +317,44 @@ impl OnUnbalanced<PositiveImbalanceOf<Test>> for MockReward {
 	}
 }
 
+parameter_types! {
+	// secondary rewards are only paid out in the tests that enable them.
+	pub static SecondaryRewardEnabled: bool = false;
+	pub static SecondaryRewardPotBalance: u128 = 0;
+	pub static SecondaryRewardMinimum: u128 = 0;
+	pub static SecondaryRewardBalances: BTreeMap<AccountId, u128> = BTreeMap::new();
+}
+
+/// A secondary reward pot that keeps track of the balances of the asset in memory. Payouts that
+/// would leave an account below `SecondaryRewardMinimum` fail.
+pub struct SecondaryRewardMock;
+impl crate::SecondaryRewardPot<AccountId> for SecondaryRewardMock {
+	type Balance = u128;
+
+	fn is_enabled() -> bool {
+		SecondaryRewardEnabled::get()
+	}
+
+	fn available() -> u128 {
+		SecondaryRewardPotBalance::get()
+	}
+
+	fn pay(who: &AccountId, amount: u128) -> DispatchResult {
+		let pot = SecondaryRewardPotBalance::get();
+		ensure!(pot >= amount, TokenError::FundsUnavailable);
+		let balance = SecondaryRewardBalances::get().get(who).copied().unwrap_or_default();
+		ensure!(balance + amount >= SecondaryRewardMinimum::get(), TokenError::BelowMinimum);
+		SecondaryRewardPotBalance::set(pot - amount);
+		SecondaryRewardBalances::mutate(|balances| balances.insert(*who, balance + amount));
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(amount: u128) {
+		SecondaryRewardPotBalance::mutate(|pot| *pot = (*pot).max(amount));
+	}
+}
+
 parameter_types! {
 	pub static LedgerSlashPerEra:
 		(BalanceOf<Test>, BTreeMap<EraIndex, BalanceOf<Test>>) =
@@ -355,6 +396,7 @@ impl crate::pallet::pallet::Config for Test {
 	type UnixTime = Timestamp;
 	type RewardRemainder = RewardRemainderMock;
 	type Reward = MockReward;
+	type SecondaryReward = SecondaryRewardMock;
 	type SessionsPerEra = SessionsPerEra;
 	type SlashDeferDuration = SlashDeferDuration;
 	type AdminOrigin = EnsureOneOrRoot;
@@ -1068,6 +1110,10 @@ pub(crate) fn balances(who: &AccountId) -> (Balance, Balance) {
 	(asset::stakeable_balance::<Test>(who), Balances::reserved_balance(who))
 }
 
+pub(crate) fn secondary_reward_balance(who: AccountId) -> u128 {
+	SecondaryRewardBalances::get().get(&who).copied().unwrap_or_default()
+}
+
 pub(crate) fn to_bounded_supports(
 	supports: Vec<(AccountId, Support<AccountId>)>,
 ) -> BoundedSupports<
@@ -25,6 +25,7 @@ use frame_support::{
 	defensive,
 	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		Defensive, DefensiveSaturating, EstimateNextNewSession, Get, Imbalance,
 		InspectLockableCurrency, Len, LockableCurrency, OnUnbalanced, TryCollect, UnixTime,
@@ -34,23 +35,25 @@ use frame_support::{
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_session::historical;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	traits::{Bounded, CheckedAdd, Convert, SaturatedConversion, Saturating, StaticLookup, Zero},
-	ArithmeticError, DispatchResult, Perbill, Percent,
+	ArithmeticError, DispatchResult, Perbill, Percent, Rounding,
 };
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{OffenceDetails, OffenceSeverity, OnOffenceHandler},
-	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
+	EraIndex, EraRewardProjection, OnStakingUpdate, Page, SessionIndex, SlashSimulation, Stake,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, ValidatorRewardProjection,
 };
 
 use crate::{
 	asset, election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
 	BalanceOf, BoundedExposuresOf, EraInfo, EraPayout, Exposure, Forcing, IndividualExposure,
 	LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, MaxWinnersPerPageOf, Nominations,
-	NominationsQuota, PositiveImbalanceOf, RewardDestination, SessionInterface, SnapshotStatus,
-	StakingLedger, ValidatorPrefs, STAKING_ID,
+	NominationsQuota, PositiveImbalanceOf, RewardDestination, SecondaryEraReward,
+	SecondaryRewardPot, SessionInterface, SnapshotStatus, StakingLedger, ValidatorPrefs,
+	STAKING_ID,
 };
 use alloc::{boxed::Box, vec, vec::Vec};
 
@@ -364,7 +367,11 @@ impl<T: Config> Pallet<T> {
 		if let Some((imbalance, dest)) =
 			Self::make_payout(&stash, validator_staking_payout + validator_commission_payout)
 		{
-			Self::deposit_event(Event::<T>::Rewarded { stash, dest, amount: imbalance.peek() });
+			Self::deposit_event(Event::<T>::Rewarded {
+				stash: stash.clone(),
+				dest,
+				amount: imbalance.peek(),
+			});
 			total_imbalance.subsume(imbalance);
 		}
 
@@ -397,7 +404,185 @@ impl<T: Config> Pallet<T> {
 		T::Reward::on_unbalanced(total_imbalance);
 		debug_assert!(nominator_payout_count <= T::MaxExposurePageSize::get());
 
-		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
+		let mut weight = T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count);
+
+		// Pay out the secondary reward of the same page along with it. A failure here must not
+		// revert the native payout, the page can still be claimed later on its own.
+		if ErasSecondaryReward::<T>::contains_key(era) &&
+			!EraInfo::<T>::is_secondary_rewards_claimed(era, &stash, page)
+		{
+			let result = with_storage_layer(|| {
+				Self::do_payout_secondary_stakers_by_page(stash.clone(), era, page)
+			});
+			let post_info = match result {
+				Ok(post_info) => post_info,
+				Err(e) => {
+					Self::deposit_event(Event::<T>::SecondaryPayoutFailed {
+						validator_stash: stash,
+						era,
+						page,
+						error: e.error,
+					});
+					e.post_info
+				},
+			};
+			weight.saturating_accrue(
+				post_info.actual_weight.unwrap_or_else(|| {
+					Self::secondary_payout_weight(T::MaxExposurePageSize::get())
+				}),
+			);
+		}
+
+		Ok(Some(weight).into())
+	}
+
+	/// Pay out the secondary reward of a single exposure page of `validator_stash` for `era`.
+	///
+	/// The reward is split the same way as the native one: the validator's share of the era's
+	/// secondary reward is proportional to its reward points, commission goes to the validator and
+	/// the rest is shared pro-rata by exposure. Everything is paid to the stash accounts.
+	pub(super) fn do_payout_secondary_stakers_by_page(
+		validator_stash: T::AccountId,
+		era: EraIndex,
+		page: Page,
+	) -> DispatchResultWithPostInfo {
+		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
+			Error::<T>::InvalidEraToReward.with_weight(T::WeightInfo::payout_secondary_stakers(0))
+		})?;
+
+		ensure!(
+			era <= current_era && era >= current_era.saturating_sub(T::HistoryDepth::get()),
+			Error::<T>::InvalidEraToReward.with_weight(T::WeightInfo::payout_secondary_stakers(0))
+		);
+
+		ensure!(
+			page < EraInfo::<T>::get_page_count(era, &validator_stash),
+			Error::<T>::InvalidPage.with_weight(T::WeightInfo::payout_secondary_stakers(0))
+		);
+
+		let mut era_reward = ErasSecondaryReward::<T>::get(era).ok_or_else(|| {
+			Error::<T>::NoSecondaryReward.with_weight(T::WeightInfo::payout_secondary_stakers(0))
+		})?;
+
+		ensure!(
+			!EraInfo::<T>::is_secondary_rewards_claimed(era, &validator_stash, page),
+			Error::<T>::AlreadyClaimed.with_weight(T::WeightInfo::payout_secondary_stakers(0))
+		);
+
+		let exposure =
+			EraInfo::<T>::get_paged_exposure(era, &validator_stash, page).ok_or_else(|| {
+				Error::<T>::InvalidEraToReward
+					.with_weight(T::WeightInfo::payout_secondary_stakers(0))
+			})?;
+
+		let era_reward_points = <ErasRewardPoints<T>>::get(&era);
+		let validator_reward_points = era_reward_points
+			.individual
+			.get(&validator_stash)
+			.copied()
+			.unwrap_or_else(Zero::zero);
+
+		// Nothing to pay if they have no reward points, but the page is done with.
+		if validator_reward_points.is_zero() {
+			EraInfo::<T>::set_secondary_rewards_as_claimed(era, &validator_stash, page);
+			return Ok(Some(T::WeightInfo::payout_secondary_stakers(0)).into())
+		}
+
+		let validator_total_payout =
+			Perbill::from_rational(validator_reward_points, era_reward_points.total) *
+				era_reward.total;
+		let validator_commission = EraInfo::<T>::get_validator_commission(era, &validator_stash);
+		let validator_total_commission_payout = validator_commission * validator_total_payout;
+		let validator_leftover_payout =
+			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
+		let validator_staking_payout =
+			Perbill::from_rational(exposure.own(), exposure.total()) * validator_leftover_payout;
+		let validator_commission_payout =
+			Perbill::from_rational(exposure.page_total(), exposure.total()) *
+				validator_total_commission_payout;
+		let validator_payout = validator_staking_payout.saturating_add(validator_commission_payout);
+
+		// The validator is paid first, if the pot cannot pay them nothing of this page is paid
+		// and it stays claimable.
+		if !validator_payout.is_zero() {
+			T::SecondaryReward::pay(&validator_stash, validator_payout)
+				.map_err(|e| e.with_weight(T::WeightInfo::payout_secondary_stakers(0)))?;
+			Self::deposit_event(Event::<T>::SecondaryRewarded {
+				stash: validator_stash.clone(),
+				amount: validator_payout,
+			});
+		}
+
+		EraInfo::<T>::set_secondary_rewards_as_claimed(era, &validator_stash, page);
+
+		let mut page_payout = validator_payout;
+		// Every attempt is charged, a failed payout costs as much as a successful one.
+		let mut nominator_payout_count: u32 = 0;
+		for nominator in exposure.others().iter() {
+			let nominator_reward = Perbill::from_rational(nominator.value, exposure.total()) *
+				validator_leftover_payout;
+			if nominator_reward.is_zero() {
+				continue
+			}
+			// A share that cannot be paid, e.g. because it is below the minimum balance of the
+			// asset, is left in the pot and released for future eras.
+			page_payout.saturating_accrue(nominator_reward);
+			nominator_payout_count += 1;
+			match T::SecondaryReward::pay(&nominator.who, nominator_reward) {
+				Ok(()) => {
+					Self::deposit_event(Event::<T>::SecondaryRewarded {
+						stash: nominator.who.clone(),
+						amount: nominator_reward,
+					});
+				},
+				Err(e) => log!(
+					debug,
+					"secondary reward of {:?} for {:?} could not be paid: {:?}",
+					nominator_reward,
+					nominator.who,
+					e,
+				),
+			}
+		}
+
+		// rounding must never let an era claim more than was set aside for it.
+		let page_payout = page_payout.min(era_reward.total.saturating_sub(era_reward.claimed));
+		era_reward.claimed.saturating_accrue(page_payout);
+		ErasSecondaryReward::<T>::insert(era, era_reward);
+		SecondaryRewardCommitted::<T>::mutate(|committed| committed.saturating_reduce(page_payout));
+
+		Ok(Some(T::WeightInfo::payout_secondary_stakers(nominator_payout_count)).into())
+	}
+
+	/// The weight of paying out the secondary reward of a page of `n` nominators, or zero if
+	/// secondary rewards are disabled.
+	pub(crate) fn secondary_payout_weight(n: u32) -> Weight {
+		if T::SecondaryReward::is_enabled() {
+			T::WeightInfo::payout_secondary_stakers(n)
+		} else {
+			Weight::zero()
+		}
+	}
+
+	/// Set aside whatever the secondary reward pot holds on top of what is still owed to earlier
+	/// eras as the secondary reward of `era`.
+	fn set_secondary_era_reward(era: EraIndex) {
+		if !T::SecondaryReward::is_enabled() {
+			return
+		}
+
+		let committed = SecondaryRewardCommitted::<T>::get();
+		let amount = T::SecondaryReward::available().saturating_sub(committed);
+		if amount.is_zero() {
+			return
+		}
+
+		ErasSecondaryReward::<T>::insert(
+			era,
+			SecondaryEraReward { total: amount, claimed: Zero::zero() },
+		);
+		SecondaryRewardCommitted::<T>::put(committed.saturating_add(amount));
+		Self::deposit_event(Event::<T>::SecondaryRewardSet { era_index: era, amount });
 	}
 
 	/// Chill a stash account.
@@ -610,6 +795,8 @@ impl<T: Config> Pallet<T> {
 			// Set ending era reward.
 			<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
 			T::RewardRemainder::on_unbalanced(asset::issue::<T>(remainder));
+
+			Self::set_secondary_era_reward(active_era.index);
 		}
 	}
 
@@ -966,12 +1153,20 @@ impl<T: Config> Pallet<T> {
 		debug_assert!(cursor.maybe_cursor.is_none());
 		cursor = <ClaimedRewards<T>>::clear_prefix(era_index, u32::MAX, None);
 		debug_assert!(cursor.maybe_cursor.is_none());
+		cursor = <ClaimedSecondaryRewards<T>>::clear_prefix(era_index, u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
 		cursor = <ErasStakersPaged<T>>::clear_prefix((era_index,), u32::MAX, None);
 		debug_assert!(cursor.maybe_cursor.is_none());
 		cursor = <ErasStakersOverview<T>>::clear_prefix(era_index, u32::MAX, None);
 		debug_assert!(cursor.maybe_cursor.is_none());
 
 		<ErasValidatorReward<T>>::remove(era_index);
+		// whatever was not claimed of the secondary reward is released back to the pot.
+		if let Some(reward) = <ErasSecondaryReward<T>>::take(era_index) {
+			SecondaryRewardCommitted::<T>::mutate(|committed| {
+				committed.saturating_reduce(reward.total.saturating_sub(reward.claimed))
+			});
+		}
 		<ErasRewardPoints<T>>::remove(era_index);
 		<ErasTotalStake<T>>::remove(era_index);
 		ErasStartSessionIndex::<T>::remove(era_index);
@@ -1428,6 +1623,219 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	/// Projects the rewards of a hypothetical nominator bonding `bond` behind `targets`.
+	///
+	/// Every era in history with a recorded payout is replayed as if the nominator had been
+	/// exposed to each of the targets elected in that era, with the bond split evenly between
+	/// them. The era payout is first adjusted along [`Config::EraPayout`] for the additional
+	/// stake and then split according to the reward points, commissions and exposures recorded
+	/// for the era.
+	///
+	/// Used by the runtime API.
+	pub fn api_simulate_rewards(
+		bond: BalanceOf<T>,
+		mut targets: Vec<T::AccountId>,
+	) -> Vec<EraRewardProjection<T::AccountId, BalanceOf<T>>> {
+		targets.sort();
+		targets.dedup();
+
+		let active_era = ActiveEra::<T>::get().map(|e| e.index).unwrap_or_default();
+		(active_era.saturating_sub(T::HistoryDepth::get())..active_era)
+			.filter_map(|era| {
+				let era_payout = ErasValidatorReward::<T>::get(era)?;
+				Some(Self::project_era_rewards(era, era_payout, bond, &targets))
+			})
+			.collect()
+	}
+
+	/// Projects the rewards of `era` for a nominator bonding `bond` behind `targets`.
+	fn project_era_rewards(
+		era: EraIndex,
+		era_payout: BalanceOf<T>,
+		bond: BalanceOf<T>,
+		targets: &[T::AccountId],
+	) -> EraRewardProjection<T::AccountId, BalanceOf<T>> {
+		let elected = targets
+			.iter()
+			.filter_map(|target| {
+				ErasStakersOverview::<T>::get(era, target).map(|overview| (target, overview))
+			})
+			.collect::<Vec<_>>();
+
+		let mut projection =
+			EraRewardProjection { era, total: Zero::zero(), validators: Vec::new() };
+		if elected.is_empty() {
+			return projection
+		}
+
+		let stake = bond / BalanceOf::<T>::from(elected.len() as u32);
+		let era_payout = Self::project_era_payout(era, era_payout, bond);
+		let reward_points = ErasRewardPoints::<T>::get(era);
+		let max_backers = <T::ElectionProvider as ElectionProvider>::MaxBackersPerWinner::get();
+		let page_size = T::MaxExposurePageSize::get().max(1);
+
+		for (validator, overview) in elected {
+			let mut exposed = overview.total.saturating_add(stake);
+			let mut page =
+				Some(overview.nominator_count.min(max_backers.saturating_sub(1)) / page_size);
+
+			// a validator backed by the maximum number of nominators only keeps the ones with the
+			// most stake, so the nominator either replaces the smallest backer or is left out.
+			if overview.nominator_count >= max_backers {
+				let smallest = Self::smallest_backer_stake(era, validator, overview.page_count);
+				if stake > smallest {
+					exposed = exposed.saturating_sub(smallest);
+				} else {
+					page = None;
+				}
+			}
+
+			let validator_points =
+				reward_points.individual.get(validator).copied().unwrap_or_else(Zero::zero);
+			let reward = if page.is_none() || validator_points.is_zero() {
+				Zero::zero()
+			} else {
+				let validator_payout =
+					Perbill::from_rational(validator_points, reward_points.total) * era_payout;
+				let commission = EraInfo::<T>::get_validator_commission(era, validator);
+				let leftover = validator_payout.saturating_sub(commission * validator_payout);
+				Perbill::from_rational(stake, exposed) * leftover
+			};
+
+			projection.total = projection.total.saturating_add(reward);
+			projection.validators.push(ValidatorRewardProjection {
+				validator: validator.clone(),
+				stake,
+				reward,
+				page,
+			});
+		}
+
+		projection
+	}
+
+	/// Scales the recorded payout of `era` by how much [`Config::EraPayout`] would have changed
+	/// it had there been `bond` more stake, respecting [`MaxStakedRewards`].
+	fn project_era_payout(
+		era: EraIndex,
+		era_payout: BalanceOf<T>,
+		bond: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		// the payout is linear in the era duration, so any duration yields the same ratio.
+		const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
+		let issuance = asset::total_issuance::<T>();
+		let staked = ErasTotalStake::<T>::get(era);
+		let max_staked_rewards = MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
+		let staked_payout = |staked: BalanceOf<T>| {
+			let (validator_payout, remainder) =
+				T::EraPayout::era_payout(staked, issuance, MILLISECONDS_PER_YEAR);
+			validator_payout.min(max_staked_rewards * validator_payout.saturating_add(remainder))
+		};
+
+		let before = staked_payout(staked);
+		if before.is_zero() {
+			return era_payout
+		}
+		let after = staked_payout(staked.saturating_add(bond));
+
+		multiply_by_rational_with_rounding(
+			era_payout.saturated_into(),
+			after.saturated_into(),
+			before.saturated_into(),
+			Rounding::Down,
+		)
+		.map(|payout| payout.saturated_into())
+		.unwrap_or(era_payout)
+	}
+
+	/// The smallest stake among the nominators exposed to `validator` in `era`.
+	fn smallest_backer_stake(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page_count: Page,
+	) -> BalanceOf<T> {
+		(0..page_count)
+			.filter_map(|page| ErasStakersPaged::<T>::get((era, validator, page)))
+			.flat_map(|page| page.others.into_iter().map(|backer| backer.value))
+			.min()
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Computes the slash that an offence of `validator` in `offence_era`, slashing `fraction` of
+	/// its exposure, would result in if it were reported and processed now.
+	///
+	/// An offence still queued for the same validator and era is taken into account the same way
+	/// [`OnOffenceHandler::on_offence`] would: the larger of both fractions is slashed, relative
+	/// to the slash the validator already had before the queued offence. Returns `None` if the
+	/// offence would be ignored, e.g. because the validator is invulnerable, was not exposed in
+	/// `offence_era`, or was already slashed at least as much in that era.
+	///
+	/// Used by the runtime API.
+	pub fn api_simulate_slash(
+		validator: T::AccountId,
+		offence_era: EraIndex,
+		fraction: Perbill,
+	) -> Option<SlashSimulation<T::AccountId, BalanceOf<T>>> {
+		let active_era = ActiveEra::<T>::get()?.index;
+		if offence_era > active_era || Invulnerables::<T>::get().contains(&validator) {
+			return None
+		}
+		let overview = ErasStakersOverview::<T>::get(offence_era, &validator)?;
+
+		let (fraction, prior_fraction, reported_era) =
+			match OffenceQueue::<T>::get(offence_era, &validator) {
+				Some(queued) => (
+					fraction.max(queued.slash_fraction),
+					queued.prior_slash_fraction,
+					queued.reported_era,
+				),
+				None => (
+					fraction,
+					ValidatorSlashInEra::<T>::get(offence_era, &validator)
+						.map_or(Zero::zero(), |(f, _)| f),
+					active_era,
+				),
+			};
+		if fraction <= prior_fraction {
+			return None
+		}
+
+		let mut simulation = slashing::simulate_slash::<T>(
+			&validator,
+			fraction,
+			prior_fraction,
+			offence_era,
+			reported_era.saturating_sub(T::BondingDuration::get()),
+			reported_era,
+			overview.page_count,
+		)
+		.ok()?;
+		let slash_defer_duration = T::SlashDeferDuration::get();
+		simulation.apply_era =
+			(slash_defer_duration > 0).then(|| offence_era.saturating_add(slash_defer_duration));
+		Some(simulation)
+	}
+
+	/// The largest slash fraction recorded against each validator, for every era in history.
+	///
+	/// Returns at most `limit` records, starting after the one of `start_after`. Used by the
+	/// runtime API.
+	pub fn api_recorded_offences(
+		start_after: Option<(EraIndex, T::AccountId)>,
+		limit: u32,
+	) -> Vec<(EraIndex, T::AccountId, Perbill)> {
+		let iter = match start_after {
+			Some((era, validator)) => ValidatorSlashInEra::<T>::iter_from(
+				ValidatorSlashInEra::<T>::hashed_key_for(era, validator),
+			),
+			None => ValidatorSlashInEra::<T>::iter(),
+		};
+		iter.take(limit as usize)
+			.map(|(era, validator, (fraction, _))| (era, validator, fraction))
+			.collect()
+	}
 }
 
 // TODO: this is a very bad design. A hack for now so we can do benchmarks. Once
@@ -2414,9 +2822,26 @@ impl<T: Config> Pallet<T> {
 		Self::check_payees()?;
 		Self::check_nominators()?;
 		Self::check_paged_exposures()?;
+		Self::check_secondary_rewards()?;
 		Self::check_count()
 	}
 
+	/// Invariants:
+	/// * `SecondaryRewardCommitted` is the sum of what is left unclaimed of every era's secondary
+	///   reward.
+	fn check_secondary_rewards() -> Result<(), TryRuntimeError> {
+		let mut owed = crate::SecondaryBalanceOf::<T>::zero();
+		for (_, reward) in ErasSecondaryReward::<T>::iter() {
+			ensure!(reward.claimed <= reward.total, "secondary reward over-claimed");
+			owed.saturating_accrue(reward.total.saturating_sub(reward.claimed));
+		}
+		ensure!(
+			owed == SecondaryRewardCommitted::<T>::get(),
+			"committed secondary reward does not match what is owed to eras"
+		);
+		Ok(())
+	}
+
 	/// Test invariants of:
 	///
 	/// - `NextElectionPage`: should only be set if pages > 1 and if we are within `pages-election
@@ -60,7 +60,8 @@ use crate::{
 	asset, slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, EraPayout,
 	EraRewardPoints, ExposurePage, Forcing, LedgerIntegrityState, MaxNominationsOf,
 	NegativeImbalanceOf, Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination,
-	SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
+	SecondaryBalanceOf, SecondaryEraReward, SecondaryRewardPot, SessionInterface, StakingLedger,
+	UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 // The speculative number of spans are used as an input of the weight annotation of
@@ -211,6 +212,13 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type Reward: OnUnbalanced<PositiveImbalanceOf<Self>>;
 
+		/// Pot of an optional secondary reward, paid in another asset alongside the native era
+		/// payout.
+		///
+		/// Use `()` to disable secondary rewards.
+		#[pallet::no_default_bounds]
+		type SecondaryReward: SecondaryRewardPot<Self::AccountId>;
+
 		/// Number of sessions per era.
 		#[pallet::constant]
 		type SessionsPerEra: Get<SessionIndex>;
@@ -388,6 +396,7 @@ pub mod pallet {
 			type RewardRemainder = ();
 			type Slash = ();
 			type Reward = ();
+			type SecondaryReward = ();
 			type SessionsPerEra = SessionsPerEra;
 			type BondingDuration = BondingDuration;
 			type SlashDeferDuration = ();
@@ -615,6 +624,41 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ErasValidatorReward<T: Config> = StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>>;
 
+	/// The secondary reward set aside for each of the last [`Config::HistoryDepth`] eras.
+	///
+	/// Eras that haven't finished yet, had nothing in the pot or have been removed don't have a
+	/// secondary reward.
+	#[pallet::storage]
+	pub type ErasSecondaryReward<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, SecondaryEraReward<SecondaryBalanceOf<T>>>;
+
+	/// The part of the secondary reward pot that is owed to eras which have not been fully paid
+	/// out yet.
+	///
+	/// Only the balance of the pot above this amount is set aside for a newly ended era.
+	#[pallet::storage]
+	pub type SecondaryRewardCommitted<T: Config> =
+		StorageValue<_, SecondaryBalanceOf<T>, ValueQuery>;
+
+	/// History of claimed paged secondary rewards by era and validator.
+	///
+	/// This is keyed by era and validator stash which maps to the set of page indexes whose
+	/// secondary reward has been claimed. Tracked separately from [`ClaimedRewards`] so that a
+	/// page whose secondary payout failed can still be claimed on its own.
+	///
+	/// It is removed after [`Config::HistoryDepth`] eras.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ClaimedSecondaryRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		Vec<Page>,
+		ValueQuery,
+	>;
+
 	/// Rewards for the last [`Config::HistoryDepth`] eras.
 	/// If reward hasn't been set or has been removed then 0 reward is returned.
 	#[pallet::storage]
@@ -1081,6 +1125,24 @@ pub mod pallet {
 			slash_key: (T::AccountId, Perbill, u32),
 			payout: BalanceOf<T>,
 		},
+		/// The given amount of the secondary reward pot has been set aside for an era.
+		SecondaryRewardSet {
+			era_index: EraIndex,
+			amount: SecondaryBalanceOf<T>,
+		},
+		/// A staker has been paid this amount of secondary reward.
+		SecondaryRewarded {
+			stash: T::AccountId,
+			amount: SecondaryBalanceOf<T>,
+		},
+		/// The secondary reward of a page could not be paid out along with its native reward.
+		/// It can be claimed later with `payout_secondary_stakers_by_page`.
+		SecondaryPayoutFailed {
+			validator_stash: T::AccountId,
+			era: EraIndex,
+			page: Page,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -1159,6 +1221,8 @@ pub mod pallet {
 		/// Account is restricted from participation in staking. This may happen if the account is
 		/// staking in another way already, such as via pool.
 		Restricted,
+		/// No secondary reward has been set aside for the era.
+		NoSecondaryReward,
 	}
 
 	#[pallet::hooks]
@@ -2023,7 +2087,8 @@ pub mod pallet {
 		///
 		/// If all pages are claimed, it returns an error `InvalidPage`.
 		#[pallet::call_index(18)]
-		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
+		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+			.saturating_add(Pallet::<T>::secondary_payout_weight(T::MaxExposurePageSize::get())))]
 		pub fn payout_stakers(
 			origin: OriginFor<T>,
 			validator_stash: T::AccountId,
@@ -2366,7 +2431,8 @@ pub mod pallet {
 		/// and so it should not be assumed the highest staker would be on the topmost page and vice
 		/// versa. If rewards are not claimed in [`Config::HistoryDepth`] eras, they are lost.
 		#[pallet::call_index(26)]
-		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
+		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+			.saturating_add(Pallet::<T>::secondary_payout_weight(T::MaxExposurePageSize::get())))]
 		pub fn payout_stakers_by_page(
 			origin: OriginFor<T>,
 			validator_stash: T::AccountId,
@@ -2639,5 +2705,32 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pay out the secondary reward of a page of the stakers behind a validator for the given
+		/// era and page.
+		///
+		/// The secondary reward is normally paid out together with the native reward by
+		/// `payout_stakers` and `payout_stakers_by_page`. This call allows claiming it on its own,
+		/// for example when its payout failed while the native reward of the page went through.
+		///
+		/// - `validator_stash` is the stash account of the validator.
+		/// - `era` may be any era between `[current_era - history_depth; current_era]` which has a
+		///   secondary reward.
+		/// - `page` is the page index of nominators to pay out.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function, even if
+		/// it is not one of the stakers. The secondary reward is always paid to the stash of each
+		/// staker, regardless of their reward destination.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::payout_secondary_stakers(T::MaxExposurePageSize::get()))]
+		pub fn payout_secondary_stakers_by_page(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+			era: EraIndex,
+			page: Page,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_payout_secondary_stakers_by_page(validator_stash, era, page)
+		}
 	}
 }
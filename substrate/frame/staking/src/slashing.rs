@@ -58,14 +58,15 @@ use alloc::vec::Vec;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	ensure,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{Defensive, DefensiveSaturating, Get, Imbalance, OnUnbalanced},
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Saturating, Zero},
-	DispatchResult, RuntimeDebug, WeakBoundedVec, Weight,
+	DispatchError, DispatchResult, RuntimeDebug, WeakBoundedVec, Weight,
 };
-use sp_staking::{EraIndex, StakingInterface};
+use sp_staking::{EraIndex, Page, SlashSimulation, StakerSlash, StakingInterface};
 
 /// The proportion of the slashing reward to be paid out on the first slashing detection.
 /// This is f_1 in the paper.
@@ -472,6 +473,93 @@ pub(crate) fn compute_slash<T: Config>(params: SlashParams<T>) -> Option<Unappli
 	})
 }
 
+/// Computes the slash an offence would result in, without altering any state.
+///
+/// Each page of the offender's exposure is slashed in the same order as [`process_offence`] would
+/// do, within a storage transaction that is rolled back afterwards.
+pub(crate) fn simulate_slash<T: Config>(
+	stash: &T::AccountId,
+	slash: Perbill,
+	prior_slash: Perbill,
+	slash_era: EraIndex,
+	window_start: EraIndex,
+	now: EraIndex,
+	page_count: Page,
+) -> Result<SlashSimulation<T::AccountId, BalanceOf<T>>, DispatchError> {
+	let reward_proportion = SlashRewardFraction::<T>::get();
+	// the span in which `slash_era` falls for `who`, as seen before slashing it.
+	let span_of = |who: &T::AccountId| {
+		crate::SlashingSpans::<T>::get(who)
+			.unwrap_or_else(|| SlashingSpans::new(window_start))
+			.iter()
+			.find(|span| span.contains_era(slash_era))
+			.map(|span| span.index)
+	};
+
+	with_transaction(|| {
+		let mut simulation = SlashSimulation {
+			offence_era: slash_era,
+			fraction: slash,
+			prior_fraction: prior_slash,
+			apply_era: None,
+			validator: StakerSlash {
+				who: stash.clone(),
+				amount: Zero::zero(),
+				span: span_of(stash),
+			},
+			nominators: Vec::new(),
+			total: Zero::zero(),
+			reporters_reward: Zero::zero(),
+		};
+
+		for page in (0..page_count.max(1)).rev() {
+			let Some(exposure) = EraInfo::<T>::get_paged_exposure(slash_era, stash, page) else {
+				continue
+			};
+			let spans = exposure
+				.exposure_page
+				.others
+				.iter()
+				.map(|nominator| span_of(&nominator.who))
+				.collect::<Vec<_>>();
+
+			let (own, others, payout) = match compute_slash::<T>(SlashParams {
+				stash,
+				slash,
+				prior_slash,
+				exposure: &exposure,
+				slash_era,
+				window_start,
+				now,
+				reward_proportion,
+			}) {
+				Some(unapplied) => (unapplied.own, unapplied.others.into_inner(), unapplied.payout),
+				// no one in this page ends up slashed.
+				None => (
+					Zero::zero(),
+					exposure
+						.exposure_page
+						.others
+						.iter()
+						.map(|nominator| (nominator.who.clone(), Zero::zero()))
+						.collect(),
+					Zero::zero(),
+				),
+			};
+
+			simulation.validator.amount.saturating_accrue(own);
+			simulation.total.saturating_accrue(own);
+			simulation.reporters_reward.saturating_accrue(payout);
+			for ((who, amount), span) in others.into_iter().zip(spans) {
+				simulation.total.saturating_accrue(amount);
+				simulation.nominators.push((page, StakerSlash { who, amount, span }));
+			}
+		}
+
+		TransactionOutcome::Rollback(Ok(simulation))
+	})
+}
+
 // doesn't apply any slash, but kicks out the validator if the misbehavior is from
 // the most recent slashing span.
 fn kick_out_if_recent<T: Config>(params: SlashParams<T>) {
@@ -49,7 +49,8 @@ use sp_runtime::{
 };
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
-	SessionIndex, Stake, StakingInterface,
+	EraRewardProjection, SessionIndex, SlashSimulation, Stake, StakerSlash, StakingInterface,
+	ValidatorRewardProjection,
 };
 use substrate_test_utils::assert_eq_uvec;
 
@@ -4812,6 +4813,195 @@ fn payout_to_any_account_works() {
 	})
 }
 
+#[test]
+fn secondary_rewards_are_paid_out_with_payout_stakers() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator(11, 1000);
+		bond_nominator(1234, 100, vec![11]);
+
+		// nothing is set aside while secondary rewards are disabled.
+		SecondaryRewardPotBalance::set(1100);
+		mock::start_active_era(1);
+		assert_eq!(ErasSecondaryReward::<Test>::get(0), None);
+
+		SecondaryRewardEnabled::set(true);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+
+		// the whole pot is set aside for era 1.
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(1),
+			Some(SecondaryEraReward { total: 1100, claimed: 0 })
+		);
+		assert_eq!(SecondaryRewardCommitted::<Test>::get(), 1100);
+		assert!(staking_events_since_last_call()
+			.contains(&Event::SecondaryRewardSet { era_index: 1, amount: 1100 }));
+
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+
+		// split pro-rata by exposure, like the native reward.
+		assert_eq!(secondary_reward_balance(11), 1000);
+		assert_eq!(secondary_reward_balance(1234), 100);
+		assert_eq!(SecondaryRewardPotBalance::get(), 0);
+		let events = staking_events_since_last_call();
+		assert!(events.contains(&Event::SecondaryRewarded { stash: 11, amount: 1000 }));
+		assert!(events.contains(&Event::SecondaryRewarded { stash: 1234, amount: 100 }));
+
+		assert_eq!(ClaimedSecondaryRewards::<Test>::get(1, 11), vec![0]);
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(1),
+			Some(SecondaryEraReward { total: 1100, claimed: 1100 })
+		);
+		assert_eq!(SecondaryRewardCommitted::<Test>::get(), 0);
+
+		// the page cannot be claimed twice.
+		assert_noop!(
+			Staking::payout_secondary_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_secondary_stakers(0))
+		);
+		// and nothing was set aside for era 0.
+		assert_noop!(
+			Staking::payout_secondary_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 0),
+			Error::<Test>::NoSecondaryReward
+				.with_weight(<Test as Config>::WeightInfo::payout_secondary_stakers(0))
+		);
+	})
+}
+
+#[test]
+fn failed_secondary_payout_can_be_claimed_later() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator(11, 1000);
+		bond_nominator(1234, 100, vec![11]);
+		SecondaryRewardEnabled::set(true);
+
+		mock::start_active_era(1);
+		SecondaryRewardPotBalance::set(1100);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		// no one can receive the asset for now.
+		SecondaryRewardMinimum::set(2000);
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+
+		// the native reward went through, the secondary one is still claimable.
+		assert_eq!(ClaimedRewards::<Test>::get(1, 11), vec![0]);
+		assert!(ClaimedSecondaryRewards::<Test>::get(1, 11).is_empty());
+		assert!(staking_events_since_last_call().contains(&Event::SecondaryPayoutFailed {
+			validator_stash: 11,
+			era: 1,
+			page: 0,
+			error: TokenError::BelowMinimum.into(),
+		}));
+		assert_eq!(SecondaryRewardPotBalance::get(), 1100);
+		assert_eq!(SecondaryRewardCommitted::<Test>::get(), 1100);
+
+		// the validator can now be paid, but the share of the nominator is still too small.
+		SecondaryRewardMinimum::set(500);
+		let post_info =
+			Staking::payout_secondary_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0)
+				.unwrap();
+		// the failed attempt is charged all the same.
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as Config>::WeightInfo::payout_secondary_stakers(1))
+		);
+		assert_eq!(secondary_reward_balance(11), 1000);
+		assert_eq!(secondary_reward_balance(1234), 0);
+		assert_eq!(ClaimedSecondaryRewards::<Test>::get(1, 11), vec![0]);
+
+		// the unpaid share stays in the pot and is set aside again for the next era.
+		assert_eq!(SecondaryRewardPotBalance::get(), 100);
+		assert_eq!(SecondaryRewardCommitted::<Test>::get(), 0);
+		mock::start_active_era(3);
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(2),
+			Some(SecondaryEraReward { total: 100, claimed: 0 })
+		);
+	})
+}
+
+#[test]
+fn secondary_rewards_of_validators_without_points_are_claimed() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		bond_validator(11, 1000);
+		bond_validator(21, 1000);
+		SecondaryRewardEnabled::set(true);
+
+		mock::start_active_era(1);
+		SecondaryRewardPotBalance::set(1000);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+
+		// 21 earned no points, so there is nothing to pay, but the page is done with.
+		assert_ok!(Staking::payout_secondary_stakers_by_page(
+			RuntimeOrigin::signed(1337),
+			21,
+			1,
+			0
+		));
+		assert_eq!(secondary_reward_balance(21), 0);
+		assert_eq!(ClaimedSecondaryRewards::<Test>::get(1, 21), vec![0]);
+		assert_noop!(
+			Staking::payout_secondary_stakers_by_page(RuntimeOrigin::signed(1337), 21, 1, 0),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_secondary_stakers(0))
+		);
+
+		// the whole reward of the era is left to 11.
+		assert_ok!(Staking::payout_secondary_stakers_by_page(
+			RuntimeOrigin::signed(1337),
+			11,
+			1,
+			0
+		));
+		assert_eq!(secondary_reward_balance(11), 1000);
+	})
+}
+
+#[test]
+fn unclaimed_secondary_rewards_are_released_when_eras_expire() {
+	ExtBuilder::default().build_and_execute(|| {
+		SecondaryRewardEnabled::set(true);
+		SecondaryRewardPotBalance::set(1000);
+		mock::start_active_era(1);
+
+		// top up the pot during era 1, only the new funds are set aside for it.
+		SecondaryRewardPotBalance::set(1500);
+		mock::start_active_era(2);
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(0),
+			Some(SecondaryEraReward { total: 1000, claimed: 0 })
+		);
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(1),
+			Some(SecondaryEraReward { total: 500, claimed: 0 })
+		);
+		assert_eq!(SecondaryRewardCommitted::<Test>::get(), 1500);
+
+		// nothing new in the pot, nothing set aside.
+		mock::start_active_era(3);
+		assert_eq!(ErasSecondaryReward::<Test>::get(2), None);
+
+		// once era 0 and 1 expire, what they did not pay out is set aside again. Old eras are
+		// cleared when a new era is planned, before the active one ends.
+		mock::start_active_era(HistoryDepth::get() + 2);
+		assert_eq!(ErasSecondaryReward::<Test>::get(0), None);
+		assert_eq!(ErasSecondaryReward::<Test>::get(1), None);
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(HistoryDepth::get()),
+			Some(SecondaryEraReward { total: 1000, claimed: 0 })
+		);
+		assert_eq!(
+			ErasSecondaryReward::<Test>::get(HistoryDepth::get() + 1),
+			Some(SecondaryEraReward { total: 500, claimed: 0 })
+		);
+		assert_eq!(SecondaryRewardCommitted::<Test>::get(), 1500);
+	})
+}
+
 #[test]
 fn session_buffering_with_offset() {
 	// similar to live-chains, have some offset for the first session
@@ -6889,6 +7079,215 @@ fn test_runtime_api_pending_rewards() {
 	});
 }
 
+#[test]
+fn test_runtime_api_simulate_rewards() {
+	ExtBuilder::default().exposures_page_size(1).build_and_execute(|| {
+		start_active_era(1);
+
+		// GIVEN two validators which earned the same points in era 0, one of them charging a
+		// commission, and a third one which was not elected.
+		let (validator_one, validator_two, not_elected) = (301, 302, 303);
+		ErasRewardPoints::<Test>::insert(
+			0,
+			EraRewardPoints::<AccountId> {
+				total: 2,
+				individual: vec![(validator_one, 1), (validator_two, 1)].into_iter().collect(),
+			},
+		);
+		ErasValidatorPrefs::<Test>::insert(
+			0,
+			validator_two,
+			ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false },
+		);
+		ErasValidatorReward::<Test>::insert(0, 1000);
+		for v in [validator_one, validator_two] {
+			EraInfo::<Test>::upsert_exposure(
+				0,
+				&v,
+				Exposure {
+					total: 300,
+					own: 100,
+					others: vec![IndividualExposure { who: 1, value: 200 }],
+				},
+			);
+		}
+
+		// the era payout grows along the curve with the additional stake.
+		let staked_payout = |staked: Balance| {
+			<Test as Config>::EraPayout::era_payout(
+				staked,
+				pallet_balances::TotalIssuance::<Test>::get(),
+				1000 * 3600 * 24 * 36525 / 100,
+			)
+			.0
+		};
+		let staked = ErasTotalStake::<Test>::get(0);
+		let projected_payout =
+			|bond: Balance| 1000 * staked_payout(staked + bond) / staked_payout(staked);
+		let rewards = |bond: Balance, stake: Balance, exposed: Balance| {
+			let validator_payout = Perbill::from_percent(50) * projected_payout(bond);
+			let commission = Perbill::from_percent(10) * validator_payout;
+			(
+				Perbill::from_rational(stake, exposed) * validator_payout,
+				Perbill::from_rational(stake, exposed) * (validator_payout - commission),
+			)
+		};
+
+		// WHEN simulating a bond split across the elected targets.
+		let (reward_one, reward_two) = rewards(400, 200, 500);
+
+		// THEN the nominator lands in the second page of each validator, since the first page is
+		// full.
+		assert_eq!(
+			Staking::api_simulate_rewards(
+				400,
+				vec![validator_two, not_elected, validator_one, validator_one]
+			),
+			vec![EraRewardProjection {
+				era: 0,
+				total: reward_one + reward_two,
+				validators: vec![
+					ValidatorRewardProjection {
+						validator: validator_one,
+						stake: 200,
+						reward: reward_one,
+						page: Some(1),
+					},
+					ValidatorRewardProjection {
+						validator: validator_two,
+						stake: 200,
+						reward: reward_two,
+						page: Some(1),
+					},
+				],
+			}]
+		);
+
+		// no elected targets, no rewards.
+		assert_eq!(
+			Staking::api_simulate_rewards(400, vec![not_elected]),
+			vec![EraRewardProjection { era: 0, total: 0, validators: vec![] }]
+		);
+
+		// WHEN the validators are already backed by the maximum number of nominators.
+		MaxBackersPerWinner::set(1);
+
+		// THEN a nominator with less stake than the existing backer is left out.
+		let projection = Staking::api_simulate_rewards(300, vec![validator_one, validator_two]);
+		assert_eq!(projection[0].total, 0);
+		assert!(projection[0].validators.iter().all(|v| v.page.is_none() && v.reward == 0));
+
+		// while one with more stake replaces the existing backer.
+		let (reward_one, reward_two) = rewards(600, 300, 400);
+		let projection = Staking::api_simulate_rewards(600, vec![validator_one, validator_two]);
+		assert_eq!(projection[0].total, reward_one + reward_two);
+		assert!(projection[0].validators.iter().all(|v| v.page == Some(0)));
+	});
+}
+
+#[test]
+fn test_runtime_api_simulate_slash() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		start_active_era(1);
+		let nominated_value = Staking::eras_stakers(1, &11)
+			.others
+			.iter()
+			.find(|o| o.who == 101)
+			.unwrap()
+			.value;
+		let nominator_slash = Perbill::from_percent(10) * nominated_value;
+		// half of the reward fraction of each slash is paid out to the first reporter.
+		let reporters_reward =
+			|slash: Balance| Perbill::from_percent(50) * (Perbill::from_percent(10) * slash);
+
+		// WHEN simulating an offence of 11 in the active era.
+		let simulation = Staking::api_simulate_slash(11, 1, Perbill::from_percent(10));
+
+		// THEN the slash is computed against the exposure and deferred.
+		assert_eq!(
+			simulation,
+			Some(SlashSimulation {
+				offence_era: 1,
+				fraction: Perbill::from_percent(10),
+				prior_fraction: Perbill::from_percent(0),
+				apply_era: Some(3),
+				validator: StakerSlash { who: 11, amount: 100, span: Some(0) },
+				nominators: vec![(
+					0,
+					StakerSlash { who: 101, amount: nominator_slash, span: Some(0) }
+				)],
+				total: 100 + nominator_slash,
+				reporters_reward: reporters_reward(100) + reporters_reward(nominator_slash),
+			})
+		);
+		// and no slashing metadata is left behind.
+		assert!(SlashingSpans::<Test>::get(&11).is_none());
+		assert!(SlashingSpans::<Test>::get(&101).is_none());
+		assert_eq!(ValidatorSlashInEra::<Test>::get(1, &11), None);
+		assert_eq!(NominatorSlashInEra::<Test>::get(1, &101), None);
+
+		// invulnerables, unexposed validators and future eras are not slashed.
+		assert_eq!(Staking::api_simulate_slash(11, 2, Perbill::from_percent(10)), None);
+		assert_eq!(Staking::api_simulate_slash(1337, 1, Perbill::from_percent(10)), None);
+		Invulnerables::<Test>::put(BoundedVec::truncate_from(vec![11]));
+		assert_eq!(Staking::api_simulate_slash(11, 1, Perbill::from_percent(10)), None);
+		Invulnerables::<Test>::kill();
+
+		// WHEN the offence is reported while still queued.
+		on_offence_now(&[offence_from(11, None)], &[Perbill::from_percent(10)], false);
+
+		// THEN the queued offence is accounted for, as if it were reported again.
+		assert_eq!(Staking::api_simulate_slash(11, 1, Perbill::from_percent(5)), simulation);
+
+		// WHEN the offence is computed.
+		advance_blocks(1);
+
+		// THEN the same offence has no further effect.
+		assert_eq!(Staking::api_simulate_slash(11, 1, Perbill::from_percent(10)), None);
+
+		// while a larger one only slashes the difference.
+		let larger = Staking::api_simulate_slash(11, 1, Perbill::from_percent(20)).unwrap();
+		assert_eq!(larger.prior_fraction, Perbill::from_percent(10));
+		assert_eq!(larger.validator, StakerSlash { who: 11, amount: 100, span: Some(0) });
+
+		// and the simulated amounts are what ends up slashed.
+		start_active_era(3);
+		advance_blocks(1);
+		assert_eq!(asset::stakeable_balance::<Test>(&11), 1000 - 100);
+		assert_eq!(asset::stakeable_balance::<Test>(&101), 2000 - nominator_slash);
+	});
+}
+
+#[test]
+fn test_runtime_api_recorded_offences() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_active_era(1);
+		assert!(Staking::api_recorded_offences(None, 10).is_empty());
+
+		on_offence_now(&[offence_from(11, None)], &[Perbill::from_percent(10)], true);
+		on_offence_now(&[offence_from(11, None)], &[Perbill::from_percent(20)], true);
+		on_offence_now(&[offence_from(21, None)], &[Perbill::from_percent(5)], true);
+
+		let mut offences = Staking::api_recorded_offences(None, 10);
+		offences.sort();
+		assert_eq!(
+			offences,
+			vec![(1, 11, Perbill::from_percent(20)), (1, 21, Perbill::from_percent(5))]
+		);
+
+		// The same offences can be fetched page by page.
+		let first = Staking::api_recorded_offences(None, 1);
+		assert_eq!(first.len(), 1);
+		let (era, validator, _) = first[0];
+		let second = Staking::api_recorded_offences(Some((era, validator)), 1);
+		assert_eq!(second.len(), 1);
+		assert!(Staking::api_recorded_offences(Some((second[0].0, second[0].1)), 1).is_empty());
+		let mut pages = [first, second].concat();
+		pages.sort();
+		assert_eq!(pages, offences);
+	});
+}
+
 mod staking_interface {
 	use frame_support::storage::with_storage_layer;
 	use sp_staking::StakingInterface;
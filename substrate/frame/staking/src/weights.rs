@@ -107,6 +107,7 @@ pub trait WeightInfo {
 	fn restore_ledger() -> Weight;
 	fn migrate_currency() -> Weight;
 	fn apply_slash() -> Weight;
+	fn payout_secondary_stakers(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_staking` using the Substrate node and recommended hardware.
@@ -897,6 +898,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(233_u64))
 			.saturating_add(T::DbWeight::get().writes(133_u64))
 	}
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Proof: `Staking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ErasStakersOverview` (r:1 w:0)
+	/// Proof: `Staking::ErasStakersOverview` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ErasSecondaryReward` (r:1 w:1)
+	/// Proof: `Staking::ErasSecondaryReward` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ClaimedSecondaryRewards` (r:1 w:1)
+	/// Proof: `Staking::ClaimedSecondaryRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasStakersPaged` (r:1 w:0)
+	/// Proof: `Staking::ErasStakersPaged` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasRewardPoints` (r:1 w:0)
+	/// Proof: `Staking::ErasRewardPoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasValidatorPrefs` (r:1 w:0)
+	/// Proof: `Staking::ErasValidatorPrefs` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:34 w:34)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::SecondaryRewardCommitted` (r:1 w:1)
+	/// Proof: `Staking::SecondaryRewardCommitted` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 32]`.
+	fn payout_secondary_stakers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1877 + n * (218 ±0)`
+		//  Estimated: `6254 + n * (2609 ±0)`
+		// Minimum execution time: 78_209_000 picoseconds.
+		Weight::from_parts(78_216_930, 6254)
+			// Standard Error: 19_584
+			.saturating_add(Weight::from_parts(41_382_117, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1686,4 +1722,39 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(233_u64))
 			.saturating_add(RocksDbWeight::get().writes(133_u64))
 	}
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Proof: `Staking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ErasStakersOverview` (r:1 w:0)
+	/// Proof: `Staking::ErasStakersOverview` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ErasSecondaryReward` (r:1 w:1)
+	/// Proof: `Staking::ErasSecondaryReward` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ClaimedSecondaryRewards` (r:1 w:1)
+	/// Proof: `Staking::ClaimedSecondaryRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasStakersPaged` (r:1 w:0)
+	/// Proof: `Staking::ErasStakersPaged` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasRewardPoints` (r:1 w:0)
+	/// Proof: `Staking::ErasRewardPoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasValidatorPrefs` (r:1 w:0)
+	/// Proof: `Staking::ErasValidatorPrefs` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:34 w:34)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::SecondaryRewardCommitted` (r:1 w:1)
+	/// Proof: `Staking::SecondaryRewardCommitted` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 32]`.
+	fn payout_secondary_stakers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1877 + n * (218 ±0)`
+		//  Estimated: `6254 + n * (2609 ±0)`
+		// Minimum execution time: 78_209_000 picoseconds.
+		Weight::from_parts(78_216_930, 6254)
+			// Standard Error: 19_584
+			.saturating_add(Weight::from_parts(41_382_117, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
 }
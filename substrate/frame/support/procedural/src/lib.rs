@@ -1046,6 +1046,16 @@ pub fn disable_try_decode_storage(_: TokenStream, _: TokenStream) -> TokenStream
 	pallet_macro_stub()
 }
 
+///
+/// ---
+///
+/// Documentation for this macro can be found at
+/// `frame_support::pallet_macros::scheduled_removal`.
+#[proc_macro_attribute]
+pub fn scheduled_removal(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 ///
 /// ---
 ///
@@ -67,6 +67,27 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	};
 
+	// Storage scheduled for removal is cleared by a multi-block migration, only check that it is
+	// gone once no migration is ongoing anymore.
+	let ensure_scheduled_removals_cleared =
+		if def.storages.iter().any(|storage| storage.scheduled_removal.is_some()) {
+			quote::quote! {
+				.and_then(|_| {
+					if <
+						<T as #frame_system::Config>::MultiBlockMigrator
+						as #frame_support::migrations::MultiStepMigrator
+					>::ongoing() {
+						return Ok(())
+					}
+					<
+						Self as #frame_support::migrations::ScheduledStorageRemovals
+					>::ensure_scheduled_removals_cleared()
+				})
+			}
+		} else {
+			quote::quote! {}
+		};
+
 	let log_runtime_upgrade = if has_runtime_upgrade {
 		// a migration is defined here.
 		quote::quote! {
@@ -191,6 +212,29 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		impl<#type_impl_gen>
+			#frame_support::traits::PalletsHooksAccess<#frame_system::pallet_prelude::BlockNumberFor::<T>>
+			for #pallet_ident<#type_use_gen> #where_clause
+		{
+			fn pallets_hooks() -> #frame_support::__private::Vec<
+				#frame_support::traits::PalletHooks<#frame_system::pallet_prelude::BlockNumberFor::<T>>
+			> {
+				#frame_support::__private::vec![#frame_support::traits::PalletHooks {
+					name: #pallet_name,
+					on_idle: <
+						Self as #frame_support::traits::OnIdle<
+							#frame_system::pallet_prelude::BlockNumberFor::<T>
+						>
+					>::on_idle,
+					on_poll: <
+						Self as #frame_support::traits::OnPoll<
+							#frame_system::pallet_prelude::BlockNumberFor::<T>
+						>
+					>::on_poll,
+				}]
+			}
+		}
+
 		impl<#type_impl_gen>
 			#frame_support::traits::OnInitialize<#frame_system::pallet_prelude::BlockNumberFor::<T>>
 			for #pallet_ident<#type_use_gen> #where_clause
@@ -324,7 +368,9 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 						Self as #frame_support::traits::Hooks<
 							#frame_system::pallet_prelude::BlockNumberFor::<T>
 						>
-					>::try_state(n).inspect_err(|err| {
+					>::try_state(n)
+					#ensure_scheduled_removals_cleared
+					.inspect_err(|err| {
 						#frame_support::__private::log::error!(
 							target: #frame_support::LOG_TARGET,
 							"❌ {:?} try_state checks failed: {:?}",
@@ -20,7 +20,7 @@ use crate::{
 	pallet::{
 		parse::{
 			helper::two128_str,
-			storage::{Metadata, QueryKind, StorageDef, StorageGenerics},
+			storage::{Metadata, QueryKind, ScheduledRemoval, StorageDef, StorageGenerics},
 		},
 		Def,
 	},
@@ -418,12 +418,27 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		let full_ident = quote::quote_spanned!(storage.attr_span => #ident<#gen> );
 
 		let cfg_attrs = &storage.cfg_attrs;
-		let deprecation = match crate::deprecation::get_deprecation(
-			&quote::quote! { #frame_support },
-			&storage.attrs,
-		) {
-			Ok(deprecation) => deprecation,
-			Err(e) => return e.into_compile_error(),
+		let is_deprecated = storage.attrs.iter().any(|attr| attr.path().is_ident("deprecated"));
+		let deprecation = match &storage.scheduled_removal {
+			// An explicit `#[deprecated]` takes precedence over the note of the scheduled removal.
+			Some(ScheduledRemoval { note, since }) if !is_deprecated => {
+				let since = since
+					.as_ref()
+					.map_or_else(|| quote::quote!(None), |since| quote::quote!(Some(#since)));
+				quote::quote! {
+					#frame_support::__private::metadata_ir::DeprecationStatusIR::Deprecated {
+						note: #note,
+						since: #since,
+					}
+				}
+			},
+			_ => match crate::deprecation::get_deprecation(
+				&quote::quote! { #frame_support },
+				&storage.attrs,
+			) {
+				Ok(deprecation) => deprecation,
+				Err(e) => return e.into_compile_error(),
+			},
 		};
 		entries_builder.push(quote::quote_spanned!(storage.attr_span =>
 			#(#cfg_attrs)*
@@ -898,6 +913,29 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		)
 	};
 
+	let scheduled_removals = def
+		.storages
+		.iter()
+		.filter(|storage| storage.scheduled_removal.is_some())
+		.map(|storage| {
+			let cfg_attrs = &storage.cfg_attrs;
+			let prefix = storage.prefix();
+			let counter = match storage.metadata {
+				Metadata::CountedMap { .. } | Metadata::CountedNMap { .. } => {
+					let counter_prefix = counter_prefix(&prefix);
+					quote::quote!(removals.push(#counter_prefix);)
+				},
+				_ => Default::default(),
+			};
+			quote::quote!(
+				#(#cfg_attrs)*
+				{
+					removals.push(#prefix);
+					#counter
+				}
+			)
+		});
+
 	quote::quote!(
 		impl<#type_impl_gen> #pallet_ident<#type_use_gen>
 			#completed_where_clause
@@ -924,6 +962,18 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		#( #prefix_structs )*
 		#( #on_empty_structs )*
 
+		impl<#type_impl_gen> #frame_support::migrations::ScheduledStorageRemovals
+			for #pallet_ident<#type_use_gen>
+			#completed_where_clause
+		{
+			fn scheduled_removals() -> #frame_support::__private::Vec<&'static str> {
+				#[allow(unused_mut)]
+				let mut removals = #frame_support::__private::Vec::new();
+				#( #scheduled_removals )*
+				removals
+			}
+		}
+
 		#try_decode_entire_state
 	)
 }
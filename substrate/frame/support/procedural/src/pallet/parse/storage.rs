@@ -30,6 +30,9 @@ mod keyword {
 	syn::custom_keyword!(unbounded);
 	syn::custom_keyword!(whitelist_storage);
 	syn::custom_keyword!(disable_try_decode_storage);
+	syn::custom_keyword!(scheduled_removal);
+	syn::custom_keyword!(note);
+	syn::custom_keyword!(since);
 	syn::custom_keyword!(OptionQuery);
 	syn::custom_keyword!(ResultQuery);
 	syn::custom_keyword!(ValueQuery);
@@ -41,12 +44,14 @@ mod keyword {
 /// * `#[pallet::unbounded]`
 /// * `#[pallet::whitelist_storage]
 /// * `#[pallet::disable_try_decode_storage]`
+/// * `#[pallet::scheduled_removal(note = "reason", since = "version")]`
 pub enum PalletStorageAttr {
 	Getter(syn::Ident, proc_macro2::Span),
 	StorageName(syn::LitStr, proc_macro2::Span),
 	Unbounded(proc_macro2::Span),
 	WhitelistStorage(proc_macro2::Span),
 	DisableTryDecodeStorage(proc_macro2::Span),
+	ScheduledRemoval(ScheduledRemoval, proc_macro2::Span),
 }
 
 impl PalletStorageAttr {
@@ -57,6 +62,7 @@ impl PalletStorageAttr {
 			Self::Unbounded(span) |
 			Self::WhitelistStorage(span) => *span,
 			Self::DisableTryDecodeStorage(span) => *span,
+			Self::ScheduledRemoval(_, span) => *span,
 		}
 	}
 }
@@ -100,18 +106,68 @@ impl syn::parse::Parse for PalletStorageAttr {
 		} else if lookahead.peek(keyword::disable_try_decode_storage) {
 			content.parse::<keyword::disable_try_decode_storage>()?;
 			Ok(Self::DisableTryDecodeStorage(attr_span))
+		} else if lookahead.peek(keyword::scheduled_removal) {
+			content.parse::<keyword::scheduled_removal>()?;
+
+			let args;
+			syn::parenthesized!(args in content);
+			Ok(Self::ScheduledRemoval(args.parse()?, attr_span))
 		} else {
 			Err(lookahead.error())
 		}
 	}
 }
 
+/// The arguments of `#[pallet::scheduled_removal(note = "reason", since = "version")]`.
+///
+/// `note` is mandatory, `since` is optional.
+pub struct ScheduledRemoval {
+	/// Why the storage item is removed, shown as deprecation note in the metadata.
+	pub note: syn::LitStr,
+	/// Since when the storage item is deprecated.
+	pub since: Option<syn::LitStr>,
+}
+
+impl syn::parse::Parse for ScheduledRemoval {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let span = input.span();
+		let mut note = None;
+		let mut since = None;
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			let target = if lookahead.peek(keyword::note) {
+				input.parse::<keyword::note>()?;
+				&mut note
+			} else if lookahead.peek(keyword::since) {
+				input.parse::<keyword::since>()?;
+				&mut since
+			} else {
+				return Err(lookahead.error())
+			};
+			input.parse::<syn::Token![=]>()?;
+			let value = input.parse::<syn::LitStr>()?;
+			if target.replace(value).is_some() {
+				return Err(syn::Error::new(span, "Invalid attribute: Duplicate argument"))
+			}
+			if !input.is_empty() {
+				input.parse::<syn::Token![,]>()?;
+			}
+		}
+
+		let note = note.ok_or_else(|| {
+			syn::Error::new(span, "Invalid pallet::scheduled_removal, expected `note = \"..\"`")
+		})?;
+		Ok(Self { note, since })
+	}
+}
+
 struct PalletStorageAttrInfo {
 	getter: Option<syn::Ident>,
 	rename_as: Option<syn::LitStr>,
 	unbounded: bool,
 	whitelisted: bool,
 	try_decode: bool,
+	scheduled_removal: Option<ScheduledRemoval>,
 }
 
 impl PalletStorageAttrInfo {
@@ -121,6 +177,7 @@ impl PalletStorageAttrInfo {
 		let mut unbounded = false;
 		let mut whitelisted = false;
 		let mut disable_try_decode_storage = false;
+		let mut scheduled_removal = None;
 		for attr in attrs {
 			match attr {
 				PalletStorageAttr::Getter(ident, ..) if getter.is_none() => getter = Some(ident),
@@ -130,6 +187,8 @@ impl PalletStorageAttrInfo {
 				PalletStorageAttr::WhitelistStorage(..) if !whitelisted => whitelisted = true,
 				PalletStorageAttr::DisableTryDecodeStorage(..) if !disable_try_decode_storage =>
 					disable_try_decode_storage = true,
+				PalletStorageAttr::ScheduledRemoval(removal, ..) if scheduled_removal.is_none() =>
+					scheduled_removal = Some(removal),
 				attr =>
 					return Err(syn::Error::new(
 						attr.attr_span(),
@@ -144,6 +203,7 @@ impl PalletStorageAttrInfo {
 			unbounded,
 			whitelisted,
 			try_decode: !disable_try_decode_storage,
+			scheduled_removal,
 		})
 	}
 }
@@ -207,6 +267,8 @@ pub struct StorageDef {
 	pub try_decode: bool,
 	/// Whether or not a default hasher is allowed to replace `_`
 	pub use_default_hasher: bool,
+	/// If the storage is deprecated and scheduled for removal.
+	pub scheduled_removal: Option<ScheduledRemoval>,
 	/// Attributes
 	pub attrs: Vec<syn::Attribute>,
 }
@@ -796,8 +858,14 @@ impl StorageDef {
 		};
 
 		let attrs: Vec<PalletStorageAttr> = helper::take_item_pallet_attrs(&mut item.attrs)?;
-		let PalletStorageAttrInfo { getter, rename_as, mut unbounded, whitelisted, try_decode } =
-			PalletStorageAttrInfo::from_attrs(attrs)?;
+		let PalletStorageAttrInfo {
+			getter,
+			rename_as,
+			mut unbounded,
+			whitelisted,
+			try_decode,
+			scheduled_removal,
+		} = PalletStorageAttrInfo::from_attrs(attrs)?;
 
 		// set all storages to be unbounded if dev_mode is enabled
 		unbounded |= dev_mode;
@@ -944,6 +1012,7 @@ impl StorageDef {
 			whitelisted,
 			try_decode,
 			use_default_hasher,
+			scheduled_removal,
 			attrs: item.attrs.clone(),
 		})
 	}
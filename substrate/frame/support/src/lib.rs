@@ -1183,6 +1183,41 @@ pub mod pallet_macros {
 	/// ```
 	pub use frame_support_procedural::disable_try_decode_storage;
 
+	/// Deprecates a storage item and schedules it for removal.
+	///
+	/// The attribute `#[pallet::scheduled_removal(note = "reason", since = "version")]`, where
+	/// `since` is optional, marks the storage item as deprecated in the metadata with the
+	/// given note, unless it already carries a `#[deprecated]` attribute.
+	///
+	/// The pallet implements
+	/// [`ScheduledStorageRemovals`](frame_support::migrations::ScheduledStorageRemovals) for
+	/// all storage items that are scheduled for removal. Adding
+	/// [`RemoveScheduledStorage`](frame_support::migrations::RemoveScheduledStorage) for the
+	/// pallet to the migrations of `pallet-migrations` clears them in bounded steps. Once no
+	/// multi-block migration is ongoing anymore, the try-state checks of the pallet fail if
+	/// any of them still has keys.
+	///
+	/// The item can be deleted from the code after the migration ran on all chains.
+	///
+	/// ### Example
+	/// ```
+	/// #[frame_support::pallet]
+	/// mod pallet {
+	/// # 	use frame_support::pallet_prelude::*;
+	/// #
+	/// 	#[pallet::pallet]
+	/// 	pub struct Pallet<T>(_);
+	///
+	/// 	#[pallet::storage]
+	/// 	#[pallet::scheduled_removal(note = "Replaced by `NewStorage`", since = "2.0.0")]
+	/// 	pub type OldStorage<T> = StorageMap<_, Twox64Concat, u32, u32>;
+	/// #
+	/// # 	#[pallet::config]
+	/// # 	pub trait Config: frame_system::Config {}
+	/// }
+	/// ```
+	pub use frame_support_procedural::scheduled_removal;
+
 	/// Declares a storage as unbounded in potential size.
 	///
 	/// When implementing the storage info (when `#[pallet::generate_storage_info]` is
@@ -1896,7 +1931,10 @@ pub mod pallet_macros {
 	/// [`OnRuntimeUpgrade`](frame_support::traits::OnRuntimeUpgrade),
 	/// [`OffchainWorker`](frame_support::traits::OffchainWorker), and
 	/// [`IntegrityTest`](frame_support::traits::IntegrityTest) using
-	/// the provided [`Hooks`](frame_support::traits::Hooks) implementation.
+	/// the provided [`Hooks`](frame_support::traits::Hooks) implementation. It also implements
+	/// [`PalletsHooksAccess`](frame_support::traits::PalletsHooksAccess), which gives a
+	/// [`HookScheduler`](frame_support::traits::HookScheduler) access to the `on_idle` and
+	/// `on_poll` hooks of the pallet.
 	///
 	/// NOTE: `OnRuntimeUpgrade` is implemented with `Hooks::on_runtime_upgrade` and some
 	/// additional logic. E.g. logic to write the pallet version into storage.
@@ -2634,6 +2672,8 @@ pub mod pallet_macros {
 	/// * [`macro@unbounded`]: Declares the storage item as unbounded.
 	/// * [`macro@disable_try_decode_storage`]: Declares that try-runtime checks should not
 	///   attempt to decode the storage item.
+	/// * [`macro@scheduled_removal`]: Deprecates the storage item and schedules it for
+	///   removal.
 	///
 	/// #### Example
 	/// ```
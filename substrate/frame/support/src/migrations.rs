@@ -31,7 +31,7 @@ use impl_trait_for_tuples::impl_for_tuples;
 use sp_arithmetic::traits::Bounded;
 use sp_core::Get;
 use sp_io::{hashing::twox_128, storage::clear_prefix, KillStorageResult};
-use sp_runtime::traits::Zero;
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
 
 /// Handles storage migration pallet versioning.
 ///
@@ -482,6 +482,122 @@ impl<P: Get<&'static str>, S: Get<&'static str>, DbWeight: Get<RuntimeDbWeight>>
 	}
 }
 
+/// The storage items of a pallet that are scheduled for removal.
+///
+/// Implemented by the pallet macro for every pallet. The items are the ones marked with
+/// `#[pallet::scheduled_removal]`; they are removed by [`RemoveScheduledStorage`].
+pub trait ScheduledStorageRemovals: PalletInfoAccess {
+	/// The storage prefixes, as in [`storage_prefix`], of the items scheduled for removal.
+	///
+	/// This includes the counter of counted maps.
+	fn scheduled_removals() -> Vec<&'static str>;
+
+	/// Check that no keys are left for any storage item scheduled for removal.
+	#[cfg(feature = "try-runtime")]
+	fn ensure_scheduled_removals_cleared() -> Result<(), sp_runtime::TryRuntimeError> {
+		use crate::storage::unhashed::contains_prefixed_key;
+
+		for item in Self::scheduled_removals() {
+			if contains_prefixed_key(&storage_prefix(Self::name().as_bytes(), item.as_bytes())) {
+				log::error!(
+					"`{}` `{}` is scheduled for removal but still has keys ❗",
+					Self::name(),
+					item
+				);
+				return Err("Storage scheduled for removal was not removed".into())
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Removes the storage items of a pallet that are scheduled for removal.
+///
+/// This is a [`SteppedMigration`] for `pallet-migrations`, which removes the items listed by
+/// [`ScheduledStorageRemovals`] one after another, in batches that fit into the given weight.
+///
+/// The identifier of the migration depends on the pallet and the items that it removes. Marking
+/// more items for removal therefore results in a new migration that has to run again.
+///
+/// # Parameters
+///
+/// - `P`: The pallet whose storage items are removed.
+/// - `DbWeight`: The runtime's [`RuntimeDbWeight`].
+///
+/// ### Examples
+/// ```ignore
+/// impl pallet_migrations::Config for Runtime {
+/// 	type Migrations = (
+/// 		RemoveScheduledStorage<SomePallet, RocksDbWeight>,
+/// 		AnyOtherMigrations...
+/// 	);
+/// 	...
+/// }
+/// ```
+pub struct RemoveScheduledStorage<P, DbWeight>(PhantomData<(P, DbWeight)>);
+
+impl<P: ScheduledStorageRemovals, DbWeight: Get<RuntimeDbWeight>> SteppedMigration
+	for RemoveScheduledStorage<P, DbWeight>
+{
+	/// The index of the item that is being removed.
+	type Cursor = u32;
+	type Identifier = [u8; 16];
+
+	fn id() -> Self::Identifier {
+		("RemoveScheduledStorage::", P::name(), P::scheduled_removals()).using_encoded(twox_128)
+	}
+
+	fn step(
+		cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let items = P::scheduled_removals();
+		let start = cursor.unwrap_or(0);
+		let mut index = start;
+		let base_weight = DbWeight::get().reads(1);
+		let weight_per_key = DbWeight::get().reads_writes(1, 1);
+
+		while let Some(item) = items.get(index as usize) {
+			let key_budget: u32 = meter
+				.remaining()
+				.saturating_sub(base_weight)
+				.checked_div_per_component(&weight_per_key)
+				.unwrap_or(u64::MAX)
+				.saturated_into();
+			if key_budget == 0 {
+				let required = base_weight.saturating_add(weight_per_key);
+				if index == start {
+					return Err(SteppedMigrationError::InsufficientWeight { required })
+				}
+				// Some items were removed in this step, continue with the next one in a later step.
+				return Ok(Some(index))
+			}
+
+			let hashed_prefix = storage_prefix(P::name().as_bytes(), item.as_bytes());
+			let (keys_removed, is_done) = match clear_prefix(&hashed_prefix, Some(key_budget)) {
+				KillStorageResult::AllRemoved(value) => (value, true),
+				KillStorageResult::SomeRemaining(value) => (value, false),
+			};
+			meter.consume(
+				base_weight.saturating_add(weight_per_key.saturating_mul(keys_removed.into())),
+			);
+			log::info!("Removed `{}` `{}` `{}` keys 🧹", keys_removed, P::name(), item);
+
+			if !is_done {
+				return Ok(Some(index))
+			}
+			index.saturating_inc();
+		}
+
+		Ok(None)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		P::ensure_scheduled_removals_cleared()
+	}
+}
+
 /// A migration that can proceed in multiple steps.
 pub trait SteppedMigration {
 	/// The cursor type that stores the progress (aka. state) of this migration.
@@ -504,6 +620,15 @@ pub trait SteppedMigration {
 		None
 	}
 
+	/// The encoded identifiers of the migrations that must complete before this one can start.
+	///
+	/// Each entry is the SCALE encoded [`Self::id`] of another migration. A dependency must either
+	/// appear before this migration in the same [`SteppedMigrations`] or have completed in a past
+	/// runtime upgrade. Migrations without dependencies may be interleaved with any other one.
+	fn dependencies() -> Vec<Vec<u8>> {
+		Vec::new()
+	}
+
 	/// Try to migrate as much as possible with the given weight.
 	///
 	/// **ANY STORAGE CHANGES MUST BE ROLLED-BACK BY THE CALLER UPON ERROR.** This is necessary
@@ -701,6 +826,13 @@ pub trait SteppedMigrations {
 	/// Is guaranteed to return `Some` if `n < Self::len()`.
 	fn nth_max_steps(n: u32) -> Option<Option<u32>>;
 
+	/// The [`SteppedMigration::dependencies`] of the `n`th migration.
+	///
+	/// Is guaranteed to return `Some` if `n < Self::len()`.
+	fn nth_dependencies(n: u32) -> Option<Vec<Vec<u8>>> {
+		(n < Self::len()).then(Vec::new)
+	}
+
 	/// Do a [`SteppedMigration::step`] on the `n`th migration.
 	///
 	/// Is guaranteed to return `Some` if `n < Self::len()`.
@@ -751,6 +883,15 @@ pub trait SteppedMigrations {
 			ensure!(Self::nth_id(n).is_some(), "id is None");
 			ensure!(Self::nth_max_steps(n).is_some(), "steps is None");
 
+			// Dependencies that are part of this batch must be executed before the dependant.
+			let Some(dependencies) = Self::nth_dependencies(n) else {
+				return Err("dependencies is None")
+			};
+			for dependency in dependencies {
+				let position = (0..l).find(|m| Self::nth_id(*m).as_ref() == Some(&dependency));
+				ensure!(position.map_or(true, |m| m < n), "dependency must come first");
+			}
+
 			// The cursor that we use does not matter. Hence use empty.
 			ensure!(
 				Self::nth_step(n, Some(vec![]), &mut WeightMeter::new()).is_some(),
@@ -779,6 +920,10 @@ impl SteppedMigrations for () {
 		None
 	}
 
+	fn nth_dependencies(_n: u32) -> Option<Vec<Vec<u8>>> {
+		None
+	}
+
 	fn nth_step(
 		_n: u32,
 		_cursor: Option<Vec<u8>>,
@@ -836,6 +981,12 @@ impl<T: SteppedMigration> SteppedMigrations for T {
 			.defensive_proof("nth_max_steps should only be called with n==0")
 	}
 
+	fn nth_dependencies(n: u32) -> Option<Vec<Vec<u8>>> {
+		n.is_zero()
+			.then(|| T::dependencies())
+			.defensive_proof("nth_dependencies should only be called with n==0")
+	}
+
 	fn nth_step(
 		n: u32,
 		cursor: Option<Vec<u8>>,
@@ -1006,6 +1157,20 @@ impl SteppedMigrations for Tuple {
 		None
 	}
 
+	fn nth_dependencies(n: u32) -> Option<Vec<Vec<u8>>> {
+		let mut i = 0;
+
+		for_tuples!( #(
+			if (i + Tuple::len()) > n {
+				return Tuple::nth_dependencies(n - i)
+			}
+
+			i += Tuple::len();
+		)* );
+
+		None
+	}
+
 	fn cursor_max_encoded_len() -> usize {
 		let mut max_len = 0;
 
@@ -1122,6 +1287,29 @@ mod tests {
 		}
 	}
 
+	pub struct D0;
+	impl SteppedMigration for D0 {
+		type Cursor = ();
+		type Identifier = u8;
+
+		fn id() -> Self::Identifier {
+			4
+		}
+
+		fn dependencies() -> Vec<Vec<u8>> {
+			vec![M0::id().encode()]
+		}
+
+		fn step(
+			_cursor: Option<Self::Cursor>,
+			_meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			log::info!("D0");
+			unhashed::put(&[4], &());
+			Ok(None)
+		}
+	}
+
 	// Three migrations combined to execute in order:
 	type Triple = (M0, (M1, M2));
 	// Six migrations, just concatenating the ones from before:
@@ -1176,6 +1364,95 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn dependencies_work() {
+		assert_eq!(<(M0, D0)>::nth_dependencies(0), Some(vec![]));
+		assert_eq!(<(M0, D0)>::nth_dependencies(1), Some(vec![0u8.encode()]));
+		assert_eq!(<(M0, D0)>::nth_dependencies(2), None);
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_ok!(<(M0, D0) as SteppedMigrations>::integrity_test());
+			// Dependencies outside of the batch are assumed to have completed in the past.
+			assert_ok!(<D0 as SteppedMigrations>::integrity_test());
+			assert_eq!(
+				<(D0, M0) as SteppedMigrations>::integrity_test(),
+				Err("dependency must come first")
+			);
+		});
+	}
+
+	pub struct Scheduled;
+	impl PalletInfoAccess for Scheduled {
+		fn index() -> usize {
+			0
+		}
+		fn name() -> &'static str {
+			"Scheduled"
+		}
+		fn name_hash() -> [u8; 16] {
+			twox_128(b"Scheduled")
+		}
+		fn module_name() -> &'static str {
+			"scheduled"
+		}
+		fn crate_version() -> crate::traits::CrateVersion {
+			crate::traits::CrateVersion::new(1, 0, 0)
+		}
+	}
+	impl ScheduledStorageRemovals for Scheduled {
+		fn scheduled_removals() -> Vec<&'static str> {
+			vec!["Foo", "Bar"]
+		}
+	}
+
+	pub struct UnitDbWeight;
+	impl Get<RuntimeDbWeight> for UnitDbWeight {
+		fn get() -> RuntimeDbWeight {
+			RuntimeDbWeight { read: 1, write: 1 }
+		}
+	}
+
+	#[test]
+	fn remove_scheduled_storage_works() {
+		type Migration = RemoveScheduledStorage<Scheduled, UnitDbWeight>;
+		let key = |item: &str, n: u8| {
+			let mut key = storage_prefix(b"Scheduled", item.as_bytes()).to_vec();
+			key.push(n);
+			key
+		};
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			(0..3).for_each(|n| unhashed::put(&key("Foo", n), &n));
+			(0..2).for_each(|n| unhashed::put(&key("Bar", n), &n));
+			unhashed::put(&key("Baz", 0), &0u8);
+		});
+		ext.commit_all().unwrap();
+
+		// Every step runs in its own block and can remove two keys.
+		let mut step = |cursor| {
+			let result = ext.execute_with(|| {
+				Migration::step(cursor, &mut WeightMeter::with_limit(Weight::from_parts(5, 0)))
+			});
+			ext.commit_all().unwrap();
+			result
+		};
+		assert_eq!(step(None).unwrap(), Some(0));
+		assert_eq!(step(Some(0)).unwrap(), Some(1));
+		assert_eq!(step(Some(1)).unwrap(), None);
+
+		ext.execute_with(|| {
+			assert!(!unhashed::contains_prefixed_key(&storage_prefix(b"Scheduled", b"Foo")));
+			assert!(!unhashed::contains_prefixed_key(&storage_prefix(b"Scheduled", b"Bar")));
+			assert!(unhashed::exists(&key("Baz", 0)));
+
+			let required = Weight::from_parts(3, 0);
+			assert!(matches!(
+				Migration::step(None, &mut WeightMeter::with_limit(Weight::from_parts(2, 0))),
+				Err(SteppedMigrationError::InsufficientWeight { required: r }) if r == required
+			));
+		});
+	}
+
 	#[test]
 	fn transactional_rollback_works() {
 		sp_io::TestExternalities::default().execute_with(|| {
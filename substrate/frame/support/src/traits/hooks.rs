@@ -21,13 +21,12 @@
 
 #![deny(missing_docs)]
 
-use crate::weights::Weight;
+use crate::{traits::Get, weights::Weight};
+use alloc::{vec, vec::Vec};
 use impl_trait_for_tuples::impl_for_tuples;
-use sp_runtime::traits::AtLeast32BitUnsigned;
+use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill};
 use sp_weights::WeightMeter;
 
-#[cfg(feature = "try-runtime")]
-use alloc::vec::Vec;
 #[cfg(feature = "try-runtime")]
 use sp_runtime::TryRuntimeError;
 
@@ -151,6 +150,243 @@ impl<BlockNumber: Copy + AtLeast32BitUnsigned> OnIdle<BlockNumber> for Tuple {
 	}
 }
 
+/// The [`OnIdle`] and [`OnPoll`] hooks of a single pallet, see [`PalletsHooksAccess`].
+pub struct PalletHooks<BlockNumber> {
+	/// The name of the pallet in the runtime.
+	pub name: &'static str,
+	/// See [`OnIdle::on_idle`].
+	pub on_idle: fn(BlockNumber, Weight) -> Weight,
+	/// See [`OnPoll::on_poll`].
+	pub on_poll: fn(BlockNumber, &mut WeightMeter),
+}
+
+/// Provides the [`OnIdle`] and [`OnPoll`] hooks of each pallet individually.
+///
+/// Implemented by the `pallet` macro and for tuples of pallets, so that a [`HookScheduler`] can
+/// decide how the remaining weight of a block is distributed between them.
+pub trait PalletsHooksAccess<BlockNumber> {
+	/// The hooks of all pallets that this type represents.
+	fn pallets_hooks() -> Vec<PalletHooks<BlockNumber>>;
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+impl<BlockNumber> PalletsHooksAccess<BlockNumber> for Tuple {
+	fn pallets_hooks() -> Vec<PalletHooks<BlockNumber>> {
+		let mut res = vec![];
+		for_tuples!( #( res.extend(Tuple::pallets_hooks()); )* );
+		res
+	}
+}
+
+/// Distributes the weight remaining in a block between the [`OnIdle`] and [`OnPoll`] hooks of
+/// the pallets.
+///
+/// Both functions return the weight consumed by each of the `pallets`, in the same order.
+pub trait HookScheduler<BlockNumber> {
+	/// Run the [`OnIdle`] hooks of `pallets` within `remaining_weight`.
+	fn on_idle(
+		n: BlockNumber,
+		remaining_weight: Weight,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight>;
+
+	/// Run the [`OnPoll`] hooks of `pallets` within the limit of `meter`.
+	fn on_poll(
+		n: BlockNumber,
+		meter: &mut WeightMeter,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight>;
+}
+
+/// The positions of `len` pallets in the order in which they are called in block `n`.
+///
+/// Starts with the pallet at `n % len`, so that every pallet is called first once in `len` blocks.
+fn rotation<BlockNumber: Copy + AtLeast32BitUnsigned>(
+	n: BlockNumber,
+	len: usize,
+) -> impl Iterator<Item = usize> {
+	let start = if len == 0 {
+		0
+	} else {
+		let start_index = n % (len as u32).into();
+		start_index.try_into().ok().expect(
+			"`start_index % len` always fits into `usize`, because `len` can be in maximum `usize::MAX`; qed"
+		)
+	};
+	(0..len).map(move |i| (start + i) % len)
+}
+
+/// Call `hook` for the pallets at the positions yielded by `order`, each with the weight which is
+/// left of `limit`, and note the weight consumed by each of them in `consumed`.
+///
+/// Returns the weight consumed in total.
+fn call_in_order<BlockNumber>(
+	pallets: &[PalletHooks<BlockNumber>],
+	order: impl Iterator<Item = usize>,
+	limit: Weight,
+	consumed: &mut [Weight],
+	mut hook: impl FnMut(&PalletHooks<BlockNumber>, Weight) -> Weight,
+) -> Weight {
+	let mut used = Weight::zero();
+	for index in order {
+		let weight = hook(&pallets[index], limit.saturating_sub(used));
+		used.saturating_accrue(weight);
+		consumed[index] = weight;
+	}
+	used
+}
+
+/// Call the [`OnPoll`] hook of `pallet` with a meter limited to `limit`.
+fn poll<BlockNumber>(n: BlockNumber, pallet: &PalletHooks<BlockNumber>, limit: Weight) -> Weight {
+	let mut meter = WeightMeter::with_limit(limit);
+	(pallet.on_poll)(n, &mut meter);
+	meter.consumed()
+}
+
+/// Calls the [`OnIdle`] hooks starting at a different pallet in every block and the [`OnPoll`]
+/// hooks in the order of the pallets in the runtime.
+///
+/// This is how `frame-executive` always called the hooks.
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> HookScheduler<BlockNumber> for () {
+	fn on_idle(
+		n: BlockNumber,
+		remaining_weight: Weight,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight> {
+		RotatingStart::on_idle(n, remaining_weight, pallets)
+	}
+
+	fn on_poll(
+		n: BlockNumber,
+		meter: &mut WeightMeter,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight> {
+		let mut consumed = vec![Weight::zero(); pallets.len()];
+		let used = call_in_order(
+			pallets,
+			0..pallets.len(),
+			meter.remaining(),
+			&mut consumed,
+			|p, limit| poll(n, p, limit),
+		);
+		meter.consume(used);
+		consumed
+	}
+}
+
+/// A [`HookScheduler`] which starts both the [`OnIdle`] and the [`OnPoll`] hooks at a different
+/// pallet in every block.
+///
+/// Every pallet gets the first chance at the remaining weight once in as many blocks as there
+/// are pallets.
+pub struct RotatingStart;
+
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> HookScheduler<BlockNumber> for RotatingStart {
+	fn on_idle(
+		n: BlockNumber,
+		remaining_weight: Weight,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight> {
+		let mut consumed = vec![Weight::zero(); pallets.len()];
+		call_in_order(
+			pallets,
+			rotation(n, pallets.len()),
+			remaining_weight,
+			&mut consumed,
+			|p, limit| (p.on_idle)(n, limit),
+		);
+		consumed
+	}
+
+	fn on_poll(
+		n: BlockNumber,
+		meter: &mut WeightMeter,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight> {
+		let mut consumed = vec![Weight::zero(); pallets.len()];
+		let used = call_in_order(
+			pallets,
+			rotation(n, pallets.len()),
+			meter.remaining(),
+			&mut consumed,
+			|p, limit| poll(n, p, limit),
+		);
+		meter.consume(used);
+		consumed
+	}
+}
+
+/// A [`HookScheduler`] which splits the remaining weight between the pallets listed in `Shares`.
+///
+/// `Shares` maps pallet names to their share of the remaining weight, relative to the sum of all
+/// shares. Listed pallets are called first and can at most consume their share. The weight left
+/// afterwards goes to the pallets which are not listed. Within both groups, the first pallet
+/// changes in every block as with [`RotatingStart`].
+///
+/// The same split applies to the [`OnIdle`] and the [`OnPoll`] hooks.
+pub struct WeightShares<Shares>(core::marker::PhantomData<Shares>);
+
+impl<Shares: Get<Vec<(&'static str, u32)>>> WeightShares<Shares> {
+	/// Call `hook` for all `pallets`, splitting `limit` according to `Shares`.
+	fn distribute<BlockNumber: Copy + AtLeast32BitUnsigned>(
+		n: BlockNumber,
+		pallets: &[PalletHooks<BlockNumber>],
+		limit: Weight,
+		mut hook: impl FnMut(&PalletHooks<BlockNumber>, Weight) -> Weight,
+	) -> Vec<Weight> {
+		let shares = Shares::get();
+		let total = shares.iter().fold(0u32, |total, (_, share)| total.saturating_add(*share));
+		let share_of = |pallet: &PalletHooks<BlockNumber>| {
+			shares
+				.iter()
+				.find(|(name, _)| *name == pallet.name)
+				.map(|(_, share)| Perbill::from_rational(*share, total.max(1)) * limit)
+		};
+
+		let mut consumed = vec![Weight::zero(); pallets.len()];
+		let used = call_in_order(
+			pallets,
+			rotation(n, pallets.len()).filter(|i| share_of(&pallets[*i]).is_some()),
+			limit,
+			&mut consumed,
+			|p, left| hook(p, left.min(share_of(p).unwrap_or_default())),
+		);
+		call_in_order(
+			pallets,
+			rotation(n, pallets.len()).filter(|i| share_of(&pallets[*i]).is_none()),
+			limit.saturating_sub(used),
+			&mut consumed,
+			hook,
+		);
+		consumed
+	}
+}
+
+impl<BlockNumber: Copy + AtLeast32BitUnsigned, Shares: Get<Vec<(&'static str, u32)>>>
+	HookScheduler<BlockNumber> for WeightShares<Shares>
+{
+	fn on_idle(
+		n: BlockNumber,
+		remaining_weight: Weight,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight> {
+		Self::distribute(n, pallets, remaining_weight, |p, limit| (p.on_idle)(n, limit))
+	}
+
+	fn on_poll(
+		n: BlockNumber,
+		meter: &mut WeightMeter,
+		pallets: &[PalletHooks<BlockNumber>],
+	) -> Vec<Weight> {
+		let consumed =
+			Self::distribute(n, pallets, meter.remaining(), |p, limit| poll(n, p, limit));
+		meter.consume(consumed.iter().fold(Weight::zero(), |used, w| used.saturating_add(*w)));
+		consumed
+	}
+}
+
 /// A trait that will be called at genesis.
 ///
 /// Implementing this trait for a pallet let's you express operations that should
@@ -761,4 +997,61 @@ mod tests {
 		assert_eq!(OnIdleInvocationOrder::get(), ["Test2", "Test3", "Test1"].to_vec());
 		OnIdleInvocationOrder::mutate(|o| o.clear());
 	}
+
+	#[test]
+	fn hook_schedulers_work() {
+		parameter_types! {
+			static Offered: Vec<(&'static str, u64)> = Vec::new();
+			pub Shares: Vec<(&'static str, u32)> = vec![("A", 1), ("B", 3)];
+		}
+
+		// A pallet which consumes as much of the offered weight as it can, up to `$max`.
+		macro_rules! pallet_hooks {
+			($name:literal, $max:literal) => {
+				PalletHooks::<u32> {
+					name: $name,
+					on_idle: |_, limit| {
+						Offered::mutate(|o| o.push(($name, limit.ref_time())));
+						limit.min(Weight::from_parts($max, 0))
+					},
+					on_poll: |_, meter| {
+						Offered::mutate(|o| o.push(($name, meter.remaining().ref_time())));
+						meter.consume(meter.remaining().min(Weight::from_parts($max, 0)))
+					},
+				}
+			};
+		}
+
+		let pallets = [pallet_hooks!("A", 10), pallet_hooks!("B", 100), pallet_hooks!("C", 100)];
+		let limit = Weight::from_parts(100, 0);
+		let ref_times =
+			|weights: Vec<Weight>| weights.iter().map(|w| w.ref_time()).collect::<Vec<_>>();
+
+		// by default, `on_idle` starts at a different pallet in every block, `on_poll` does not.
+		assert_eq!(ref_times(<() as HookScheduler<u32>>::on_idle(1, limit, &pallets)), [0, 100, 0]);
+		assert_eq!(Offered::take(), [("B", 100), ("C", 0), ("A", 0)]);
+		let mut meter = WeightMeter::with_limit(limit);
+		assert_eq!(
+			ref_times(<() as HookScheduler<u32>>::on_poll(1, &mut meter, &pallets)),
+			[10, 90, 0]
+		);
+		assert_eq!(Offered::take(), [("A", 100), ("B", 90), ("C", 0)]);
+		assert_eq!(meter.consumed(), limit);
+
+		let mut meter = WeightMeter::with_limit(limit);
+		assert_eq!(ref_times(RotatingStart::on_poll(2, &mut meter, &pallets)), [0, 0, 100]);
+		assert_eq!(Offered::take(), [("C", 100), ("A", 0), ("B", 0)]);
+		assert_eq!(meter.consumed(), limit);
+
+		// listed pallets are limited to their share, the rest goes to the others.
+		type Scheduler = WeightShares<Shares>;
+		assert_eq!(ref_times(Scheduler::on_idle(0, limit, &pallets)), [10, 75, 15]);
+		assert_eq!(Offered::take(), [("A", 25), ("B", 75), ("C", 15)]);
+		assert_eq!(ref_times(Scheduler::on_idle(1, limit, &pallets)), [10, 75, 15]);
+		assert_eq!(Offered::take(), [("B", 75), ("A", 25), ("C", 15)]);
+		let mut meter = WeightMeter::with_limit(limit);
+		assert_eq!(ref_times(Scheduler::on_poll(0, &mut meter, &pallets)), [10, 75, 15]);
+		assert_eq!(Offered::take(), [("A", 25), ("B", 75), ("C", 15)]);
+		assert_eq!(meter.consumed(), limit);
+	}
 }
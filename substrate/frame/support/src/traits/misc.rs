@@ -17,7 +17,10 @@
 
 //! Smaller traits used in FRAME which don't need their own file.
 
-use crate::dispatch::{DispatchResult, Parameter};
+use crate::{
+	dispatch::{DispatchResult, Parameter},
+	weights::Weight,
+};
 use alloc::{vec, vec::Vec};
 use codec::{CompactLen, Decode, DecodeLimit, Encode, EncodeLike, Input, MaxEncodedLen};
 use impl_trait_for_tuples::impl_for_tuples;
@@ -31,7 +34,9 @@ pub use sp_runtime::traits::{
 	ConstBool, ConstI128, ConstI16, ConstI32, ConstI64, ConstI8, ConstInt, ConstU128, ConstU16,
 	ConstU32, ConstU64, ConstU8, ConstUint, Get, GetDefault, TryCollect, TypedGet,
 };
-use sp_runtime::{traits::Block as BlockT, DispatchError};
+use sp_runtime::{
+	traits::Block as BlockT, transaction_validity::TransactionValidityError, DispatchError,
+};
 
 #[doc(hidden)]
 pub const DEFENSIVE_OP_PUBLIC_ERROR: &str = "a defensive failure has been triggered; please report the block number at https://github.com/paritytech/substrate/issues";
@@ -918,6 +923,46 @@ pub trait IsInherent<Extrinsic> {
 	fn is_inherent(ext: &Extrinsic) -> bool;
 }
 
+/// Validates the composition of a block, one extrinsic at a time.
+///
+/// This lets a runtime enforce rules about the order of the extrinsics in a block or about how
+/// many extrinsics of some kind a block may contain. `frame-executive` runs the check on every
+/// extrinsic that is not an inherent, right after its signature was checked and before it is
+/// applied. A block author therefore leaves rejected extrinsics out of the block and an importer
+/// rejects any block that contains one.
+pub trait ValidateBlockComposition<Extrinsic> {
+	/// Check whether the checked `extrinsic` can be applied next in the current block.
+	///
+	/// Implementations may keep per-block state in storage. Changes are reverted if the
+	/// extrinsic is not included in the block.
+	///
+	/// Return [`InvalidTransaction::ExhaustsResources`](sp_runtime::transaction_validity::InvalidTransaction::ExhaustsResources)
+	/// for extrinsics which can be included in a later block. Block authors keep those in the
+	/// transaction pool, while other errors make them drop the extrinsic.
+	fn validate_next(extrinsic: &Extrinsic) -> Result<(), TransactionValidityError>;
+
+	/// The weight of calling [`Self::validate_next`] with `extrinsic`.
+	///
+	/// It is registered as mandatory weight of the block for every validated extrinsic.
+	fn weight(extrinsic: &Extrinsic) -> Weight;
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+impl<Extrinsic> ValidateBlockComposition<Extrinsic> for Tuple {
+	fn validate_next(extrinsic: &Extrinsic) -> Result<(), TransactionValidityError> {
+		for_tuples!( #( Tuple::validate_next(extrinsic)?; )* );
+		Ok(())
+	}
+
+	fn weight(extrinsic: &Extrinsic) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::weight(extrinsic)); )* );
+		weight
+	}
+}
+
 /// An extrinsic on which we can get access to call.
 pub trait ExtrinsicCall: sp_runtime::traits::ExtrinsicLike {
 	type Call;
@@ -0,0 +1,142 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `#[pallet::scheduled_removal]` and [`RemoveScheduledStorage`].
+
+use frame_support::{
+	construct_runtime, derive_impl,
+	migrations::{RemoveScheduledStorage, ScheduledStorageRemovals, SteppedMigration},
+	weights::{constants::RocksDbWeight, Weight, WeightMeter},
+};
+use sp_metadata_ir::DeprecationStatusIR;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::pallet]
+mod dummy_pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::storage]
+	pub type Kept<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::scheduled_removal(note = "Use `Kept` instead")]
+	pub type OldValue<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::scheduled_removal(note = "No longer used", since = "2.0.0")]
+	pub type OldMap<T: Config> = CountedStorageMap<_, Twox64Concat, u32, u32>;
+}
+
+impl dummy_pallet::Config for Test {}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system = 0,
+		DummyPallet: dummy_pallet = 1,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::from(storage);
+	ext.execute_with(|| {
+		dummy_pallet::Kept::<Test>::put(1);
+		dummy_pallet::OldValue::<Test>::put(2);
+		(0..10).for_each(|i| dummy_pallet::OldMap::<Test>::insert(i, i));
+	});
+	ext
+}
+
+#[test]
+fn scheduled_removals_are_deprecated_in_metadata() {
+	let entries = DummyPallet::storage_metadata().entries;
+	let deprecation = |name: &str| {
+		entries
+			.iter()
+			.find(|entry| entry.name == name)
+			.unwrap()
+			.deprecation_info
+			.clone()
+	};
+
+	assert_eq!(deprecation("Kept"), DeprecationStatusIR::NotDeprecated);
+	assert_eq!(
+		deprecation("OldValue"),
+		DeprecationStatusIR::Deprecated { note: "Use `Kept` instead", since: None }
+	);
+	assert_eq!(
+		deprecation("OldMap"),
+		DeprecationStatusIR::Deprecated { note: "No longer used", since: Some("2.0.0") }
+	);
+}
+
+#[test]
+fn scheduled_removals_includes_counters() {
+	assert_eq!(DummyPallet::scheduled_removals(), vec!["OldValue", "OldMap", "CounterForOldMap"]);
+}
+
+#[test]
+fn remove_scheduled_storage_works() {
+	type Migration = RemoveScheduledStorage<DummyPallet, RocksDbWeight>;
+
+	new_test_ext().execute_with(|| {
+		#[cfg(feature = "try-runtime")]
+		assert!(DummyPallet::ensure_scheduled_removals_cleared().is_err());
+
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+		assert_eq!(Migration::step(None, &mut meter).unwrap(), None);
+
+		assert!(!dummy_pallet::OldValue::<Test>::exists());
+		assert_eq!(dummy_pallet::OldMap::<Test>::iter().count(), 0);
+		assert_eq!(dummy_pallet::OldMap::<Test>::count(), 0);
+		assert_eq!(dummy_pallet::Kept::<Test>::get(), 1);
+
+		#[cfg(feature = "try-runtime")]
+		assert!(DummyPallet::ensure_scheduled_removals_cleared().is_ok());
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_checks_scheduled_removals() {
+	use frame_support::traits::{TryState, TryStateSelect};
+
+	new_test_ext().execute_with(|| {
+		assert!(DummyPallet::try_state(1, TryStateSelect::All).is_err());
+
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+		assert_eq!(
+			RemoveScheduledStorage::<DummyPallet, RocksDbWeight>::step(None, &mut meter).unwrap(),
+			None
+		);
+		assert!(DummyPallet::try_state(1, TryStateSelect::All).is_ok());
+	});
+}
@@ -58,12 +58,16 @@
 
 use alloc::{boxed::Box, collections::btree_set::BTreeSet, vec::Vec};
 use codec::Encode;
+#[cfg(feature = "experimental")]
+use frame_support::traits::Task;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	app_crypto::RuntimeAppPublic,
 	traits::{ExtrinsicLike, IdentifyAccount, One},
 	RuntimeDebug,
 };
+#[cfg(feature = "experimental")]
+use sp_weights::Weight;
 
 /// Marker struct used to flag using all supported keys to sign a payload.
 pub struct ForAll {}
@@ -90,6 +94,61 @@ where
 	}
 }
 
+/// Provides the unsigned transactions executing the tasks of the runtime.
+///
+/// Block authors include them through [`TaskExtrinsics::all`], which is meant for implementing
+/// `sp_block_builder::TaskApi`. Offchain workers can instead submit them to the transaction pool
+/// with [`TaskExtrinsics::submit`], for chains whose block authors do not include tasks.
+#[cfg(feature = "experimental")]
+pub struct TaskExtrinsics<T> {
+	_phantom: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "experimental")]
+impl<T> TaskExtrinsics<T>
+where
+	T: crate::Config + CreateInherent<crate::Call<T>>,
+{
+	/// Unsigned extrinsics executing the valid tasks among `tasks`.
+	///
+	/// Stops at the first task which would take the combined weight of the tasks above
+	/// `weight_limit`, without checking whether the remaining tasks are valid.
+	pub fn from_tasks(
+		tasks: impl IntoIterator<Item = T::RuntimeTask>,
+		weight_limit: Weight,
+	) -> Vec<T::Extrinsic> {
+		let mut remaining = weight_limit;
+		let mut extrinsics = Vec::new();
+		for task in tasks {
+			let Some(left) = remaining.checked_sub(&task.weight()) else { break };
+			if task.is_valid() {
+				remaining = left;
+				extrinsics.push(T::create_inherent(crate::Call::do_task { task }.into()));
+			}
+		}
+		extrinsics
+	}
+
+	/// Unsigned extrinsics executing all valid tasks of the runtime, up to `weight_limit`.
+	pub fn all(weight_limit: Weight) -> Vec<T::Extrinsic>
+	where
+		<T::RuntimeTask as Task>::Enumeration: Iterator<Item = T::RuntimeTask>,
+	{
+		Self::from_tasks(<T::RuntimeTask as Task>::iter(), weight_limit)
+	}
+
+	/// Submit the valid tasks among `tasks` to the transaction pool, up to `weight_limit`.
+	///
+	/// Returns the number of tasks which were submitted.
+	pub fn submit(tasks: impl IntoIterator<Item = T::RuntimeTask>, weight_limit: Weight) -> u32 {
+		Self::from_tasks(tasks, weight_limit)
+			.into_iter()
+			.map(SubmitTransaction::<T, crate::Call<T>>::submit_transaction)
+			.filter(Result::is_ok)
+			.count() as u32
+	}
+}
+
 /// Provides an implementation for signing transaction payloads.
 ///
 /// Keys used for signing are defined when instantiating the signer object.
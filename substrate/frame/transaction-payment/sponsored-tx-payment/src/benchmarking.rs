@@ -0,0 +1,150 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for Sponsored Tx Payment Pallet
+
+use super::*;
+use crate::Pallet;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::GetCallMetadata,
+};
+use frame_system::{EventRecord, RawOrigin};
+use sp_runtime::traits::{
+	AsTransactionAuthorizedOrigin, Bounded, DispatchTransaction, Dispatchable, One,
+};
+
+pub trait BenchmarkHelper<Signature, Signer> {
+	fn create_signature(entropy: &[u8], msg: &[u8]) -> (Signature, Signer);
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+	// compare to the last event record
+	let EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+fn funded_account<T: Config>(who: &T::AccountId) -> BalanceOf<T> {
+	let amount =
+		<T::OnChargeTransaction as OnChargeTransaction<T>>::minimum_balance() * 1_000_000u32.into();
+	<T::OnChargeTransaction as OnChargeTransaction<T>>::endow_account(who, amount);
+	amount
+}
+
+/// The largest call filter, which only allows `metadata` with its last entry.
+fn worst_case_filter<T: Config>(metadata: &CallMetadata) -> CallFilter<T> {
+	let max_name = || NameOf::<T>::truncate_from(vec![b'x'; T::MaxNameLen::get() as usize]);
+	let mut allowed = vec![
+		AllowedCall::<T> { pallet: max_name(), call: Some(max_name()) };
+		T::MaxAllowedCalls::get().saturating_sub(1) as usize
+	];
+	allowed.push(AllowedCall {
+		pallet: NameOf::<T>::truncate_from(metadata.pallet_name.as_bytes().to_vec()),
+		call: Some(NameOf::<T>::truncate_from(metadata.function_name.as_bytes().to_vec())),
+	});
+	CallFilter::Only(BoundedVec::truncate_from(allowed))
+}
+
+#[benchmarks(where
+	T::RuntimeOrigin: AsTransactionAuthorizedOrigin,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetCallMetadata,
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_sponsorship() {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let budget = funded_account::<T>(&sponsor);
+		let call: T::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let allowed_calls = worst_case_filter::<T>(&call.get_call_metadata());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()), budget, allowed_calls);
+
+		assert_last_event::<T>(Event::SponsorshipSet { sponsor, budget }.into());
+	}
+
+	#[benchmark]
+	fn remove_sponsorship() {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let budget = funded_account::<T>(&sponsor);
+		let call: T::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let allowed_calls = worst_case_filter::<T>(&call.get_call_metadata());
+		assert!(Pallet::<T>::set_sponsorship(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			budget,
+			allowed_calls
+		)
+		.is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()));
+
+		assert_last_event::<T>(Event::SponsorshipRemoved { sponsor }.into());
+	}
+
+	#[benchmark]
+	fn charge_sponsored_transaction_payment() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let call: T::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let tip = <T::OnChargeTransaction as OnChargeTransaction<T>>::minimum_balance();
+		let max_fee = tip * 1000u32.into();
+		let nonce = T::Nonce::zero();
+		let valid_until = BlockNumberFor::<T>::max_value();
+		let msg = Pallet::<T>::sponsorship_payload(&caller, &call, max_fee, nonce, valid_until);
+		let (signature, sponsor) = T::BenchmarkHelper::create_signature(&[42u8; 256], &msg[..]);
+		let budget = funded_account::<T>(&sponsor);
+		assert!(Pallet::<T>::set_sponsorship(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			budget,
+			worst_case_filter::<T>(&call.get_call_metadata())
+		)
+		.is_ok());
+
+		let sponsorship =
+			Sponsorship { sponsor: sponsor.clone(), max_fee, nonce, valid_until, signature };
+		let ext = ChargeSponsoredTransactionPayment::<T>::from(tip, Some(sponsorship));
+		let extension_weight = ext.weight(&call);
+		let info = DispatchInfo {
+			call_weight: Weight::from_parts(100, 0),
+			extension_weight,
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes,
+		};
+		let post_info = PostDispatchInfo {
+			actual_weight: Some(Weight::from_parts(10, 0)),
+			pays_fee: Pays::Yes,
+		};
+
+		#[block]
+		{
+			assert!(ext
+				.test_run(RawOrigin::Signed(caller.clone()).into(), &call, &info, 10, 0, |_| Ok(
+					post_info
+				))
+				.unwrap()
+				.is_ok());
+		}
+
+		assert_eq!(Nonces::<T>::get(&sponsor, &caller), One::one());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
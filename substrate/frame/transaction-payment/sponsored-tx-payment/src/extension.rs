@@ -0,0 +1,294 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The [`ChargeSponsoredTransactionPayment`] transaction extension.
+
+use crate::*;
+use alloc::vec;
+use frame_support::{
+	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
+	traits::GetCallMetadata,
+	weights::Weight,
+};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, One, PostDispatchInfoOf, Saturating, TransactionExtension,
+		ValidateResult,
+	},
+	transaction_validity::TransactionSource,
+	SaturatedConversion,
+};
+
+/// A sponsor's permission to pay the fee of a single transaction.
+///
+/// The signature is over [`Pallet::sponsorship_payload`].
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct Sponsorship<T: Config> {
+	/// The account that pays the fee.
+	pub sponsor: T::AccountId,
+	/// The maximal fee, including the tip, the sponsor agreed to pay.
+	#[codec(compact)]
+	pub max_fee: BalanceOf<T>,
+	/// The sponsorship nonce of the sponsored account, see [`Nonces`].
+	pub nonce: T::Nonce,
+	/// The last block in which the sponsorship can be used.
+	pub valid_until: BlockNumberFor<T>,
+	/// The signature of the sponsor.
+	pub signature: T::Signature,
+}
+
+/// Require the transactor to pay for themselves and possibly include a tip to gain additional
+/// priority in the queue, or let a sponsor pay for the transaction.
+///
+/// Without a sponsorship this behaves exactly like [`ChargeTransactionPayment`].
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeSponsoredTransactionPayment<T: Config> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	sponsorship: Option<Sponsorship<T>>,
+}
+
+impl<T: Config> ChargeSponsoredTransactionPayment<T> {
+	/// Utility constructor. Used only in client/factory code.
+	pub fn from(tip: BalanceOf<T>, sponsorship: Option<Sponsorship<T>>) -> Self {
+		Self { tip, sponsorship }
+	}
+}
+
+impl<T: Config> core::fmt::Debug for ChargeSponsoredTransactionPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"ChargeSponsoredTransactionPayment<{:?}, {:?}>",
+			self.tip,
+			self.sponsorship.as_ref().map(|s| &s.sponsor)
+		)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+/// The info passed between the validate and prepare steps for the
+/// `ChargeSponsoredTransactionPayment` extension.
+#[derive(RuntimeDebugNoBound)]
+pub enum Val<T: Config> {
+	/// The signer pays for the transaction.
+	Unsponsored(pallet_transaction_payment::Val<T>),
+	/// The sponsor pays the fee for the transaction of `who`.
+	Sponsored {
+		sponsor: T::AccountId,
+		who: T::AccountId,
+		nonce: T::Nonce,
+		// validated charge of the sponsor
+		inner: pallet_transaction_payment::Val<T>,
+	},
+}
+
+/// The info passed between the prepare and post-dispatch steps for the
+/// `ChargeSponsoredTransactionPayment` extension.
+#[derive(RuntimeDebugNoBound)]
+pub enum Pre<T: Config> {
+	/// The signer paid for the transaction.
+	Unsponsored(pallet_transaction_payment::Pre<T>),
+	/// The sponsor paid the fee for the transaction of `who`.
+	Sponsored {
+		sponsor: T::AccountId,
+		who: T::AccountId,
+		// fee deducted from the sponsor's budget
+		reserved: BalanceOf<T>,
+		// withdrawn charge of the sponsor
+		inner: pallet_transaction_payment::Pre<T>,
+	},
+}
+
+impl<T: Config> TransactionExtension<T::RuntimeCall> for ChargeSponsoredTransactionPayment<T>
+where
+	T::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "ChargeSponsoredTransactionPayment";
+	type Implicit = ();
+	type Val = Val<T>;
+	type Pre = Pre<T>;
+
+	fn weight(&self, call: &T::RuntimeCall) -> Weight {
+		if self.sponsorship.is_some() {
+			<T as Config>::WeightInfo::charge_sponsored_transaction_payment()
+		} else {
+			ChargeTransactionPayment::<T>::from(self.tip).weight(call)
+		}
+	}
+
+	fn validate(
+		&self,
+		origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		_self_implicit: Self::Implicit,
+		inherited_implication: &impl Encode,
+		source: TransactionSource,
+	) -> ValidateResult<Self::Val, T::RuntimeCall> {
+		let inner = ChargeTransactionPayment::<T>::from(self.tip);
+		let Some(sponsorship) = &self.sponsorship else {
+			let (validity, val, origin) =
+				inner.validate(origin, call, info, len, (), inherited_implication, source)?;
+			return Ok((validity, Val::Unsponsored(val), origin))
+		};
+		let Ok(who) = frame_system::ensure_signed(origin.clone()) else {
+			return Err(InvalidTransaction::BadSigner.into())
+		};
+		let Sponsorship { sponsor, max_fee, nonce, valid_until, signature } = sponsorship;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if now > *valid_until {
+			return Err(SponsorshipError::Expired.into())
+		}
+		let sponsor_info = Sponsors::<T>::get(sponsor).ok_or(SponsorshipError::UnknownSponsor)?;
+		if !sponsor_info.allowed_calls.allows(&call.get_call_metadata()) {
+			return Err(SponsorshipError::CallNotAllowed.into())
+		}
+		let next_nonce = Nonces::<T>::get(sponsor, &who);
+		if *nonce < next_nonce {
+			return Err(InvalidTransaction::Stale.into())
+		}
+		let payload = Pallet::<T>::sponsorship_payload(&who, call, *max_fee, *nonce, *valid_until);
+		if !signature.verify(&payload[..], sponsor) {
+			return Err(InvalidTransaction::BadProof.into())
+		}
+
+		// The fee is withdrawn from the sponsor, so the inner extension validates with the
+		// sponsor as the signer. The origin of the call itself is left untouched.
+		let sponsor_origin = frame_system::RawOrigin::Signed(sponsor.clone()).into();
+		let (mut validity, inner_val, _) =
+			inner.validate(sponsor_origin, call, info, len, (), inherited_implication, source)?;
+		if let pallet_transaction_payment::Val::Charge { fee, .. } = &inner_val {
+			if fee > max_fee {
+				return Err(SponsorshipError::FeeAboveLimit.into())
+			}
+			if *fee > sponsor_info.budget {
+				return Err(SponsorshipError::BudgetExhausted.into())
+			}
+		}
+
+		// The transaction must not stay in the pool after the sponsorship expired.
+		let remaining = valid_until.saturating_sub(now).saturated_into::<u64>().saturating_add(1);
+		validity.longevity = validity.longevity.min(remaining);
+		validity.provides = vec![(SPONSORSHIP_CONTEXT, sponsor, &who, nonce).encode()];
+		if *nonce > next_nonce {
+			let previous = (*nonce).saturating_sub(One::one());
+			validity.requires = vec![(SPONSORSHIP_CONTEXT, sponsor, &who, previous).encode()];
+		}
+		let val = Val::Sponsored { sponsor: sponsor.clone(), who, nonce: *nonce, inner: inner_val };
+		Ok((validity, val, origin))
+	}
+
+	fn prepare(
+		self,
+		val: Self::Val,
+		origin: &<T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let inner = ChargeTransactionPayment::<T>::from(self.tip);
+		let (sponsor, who, nonce, inner_val) = match val {
+			Val::Unsponsored(val) =>
+				return Ok(Pre::Unsponsored(inner.prepare(val, origin, call, info, len)?)),
+			Val::Sponsored { sponsor, who, nonce, inner } => (sponsor, who, nonce, inner),
+		};
+
+		Nonces::<T>::try_mutate(&sponsor, &who, |next_nonce| {
+			if nonce != *next_nonce {
+				return Err(if nonce < *next_nonce {
+					InvalidTransaction::Stale
+				} else {
+					InvalidTransaction::Future
+				})
+			}
+			next_nonce.saturating_inc();
+			Ok(())
+		})?;
+
+		let reserved = match &inner_val {
+			pallet_transaction_payment::Val::Charge { fee, .. } => *fee,
+			pallet_transaction_payment::Val::NoCharge => Zero::zero(),
+		};
+		Sponsors::<T>::try_mutate(&sponsor, |maybe_info| {
+			let info = maybe_info.as_mut().ok_or(SponsorshipError::UnknownSponsor)?;
+			if reserved > info.budget {
+				return Err(SponsorshipError::BudgetExhausted)
+			}
+			info.budget.saturating_reduce(reserved);
+			Ok(())
+		})?;
+
+		let inner_pre = inner.prepare(inner_val, origin, call, info, len)?;
+		Ok(Pre::Sponsored { sponsor, who, reserved, inner: inner_pre })
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		let (sponsor, who, reserved, inner_pre) = match pre {
+			Pre::Unsponsored(pre) =>
+				return ChargeTransactionPayment::<T>::post_dispatch_details(
+					pre, info, post_info, len, result,
+				),
+			Pre::Sponsored { sponsor, who, reserved, inner } => (sponsor, who, reserved, inner),
+		};
+
+		// Without a fee, the inner extension refunds the weight of the skipped charge.
+		let tip = match &inner_pre {
+			pallet_transaction_payment::Pre::Charge { tip, .. } => *tip,
+			pallet_transaction_payment::Pre::NoCharge { refund } => return Ok(*refund),
+		};
+		let unspent_weight = ChargeTransactionPayment::<T>::post_dispatch_details(
+			inner_pre, info, post_info, len, result,
+		)?;
+
+		// The fee follows the actual weight of the call, like in the inner extension. Return the
+		// part of the fee that was refunded to the sponsor to their budget as well.
+		let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+			len as u32, info, post_info, tip,
+		);
+		Sponsors::<T>::mutate(&sponsor, |maybe_info| {
+			if let Some(info) = maybe_info {
+				info.budget.saturating_accrue(reserved.saturating_sub(actual_fee));
+			}
+		});
+		Pallet::<T>::deposit_event(Event::<T>::SponsoredFeePaid { sponsor, who, actual_fee, tip });
+		Ok(unspent_weight)
+	}
+}
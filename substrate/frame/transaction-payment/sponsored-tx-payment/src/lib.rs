@@ -0,0 +1,337 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Sponsored Transaction Payment Pallet
+//!
+//! This pallet allows runtimes that include it to let a second account, the sponsor, pay the
+//! transaction fees of a transaction that was signed by someone else.
+//!
+//! ## Overview
+//!
+//! Sponsors register on-chain with [`Pallet::set_sponsorship`]. The registration contains a
+//! budget, which caps how much the sponsor is willing to spend on fees in total, and a
+//! [`CallFilter`] that restricts the calls the sponsor pays for.
+//!
+//! A user who wants to have a transaction sponsored obtains a [`Sponsorship`] off-chain. It
+//! contains the sponsor's signature over the call, the user, the maximal fee, a nonce and the last
+//! block in which it can be used (see [`Pallet::sponsorship_payload`]). The user then includes it
+//! in the [`ChargeSponsoredTransactionPayment`] extension of their transaction. The extension
+//! checks the signature, the call filter, the expiry and the limits, and charges the fee to the
+//! sponsor instead of the signer. The nonce is tracked per sponsor and user, so every sponsorship
+//! can be used only once.
+//!
+//! The fee itself is computed, withdrawn and corrected by [`pallet_transaction_payment`].
+//! Sponsored transactions therefore get the same priority, refunds of unused weight and weight
+//! reclaim as any other transaction. The part of the fee that is refunded after dispatch is also
+//! returned to the sponsor's budget.
+//!
+//! ## Integration
+//!
+//! This pallet wraps FRAME's transaction payment pallet and functions as a replacement. This means
+//! you should include both pallets in your `construct_runtime` macro, but only include this
+//! pallet's [`TransactionExtension`](sp_runtime::traits::TransactionExtension)
+//! ([`ChargeSponsoredTransactionPayment`]). A sponsorship of `None` falls back to the signer
+//! paying for the transaction.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{CallMetadata, Consideration, Footprint},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::*;
+use pallet_transaction_payment::OnChargeTransaction;
+use scale_info::TypeInfo;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{
+	traits::{IdentifyAccount, Verify, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod extension;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::BenchmarkHelper;
+pub use extension::*;
+pub use weights::WeightInfo;
+
+/// Type aliases used for interaction with `OnChargeTransaction`.
+pub(crate) type OnChargeTransactionOf<T> =
+	<T as pallet_transaction_payment::Config>::OnChargeTransaction;
+/// Balance type alias.
+pub(crate) type BalanceOf<T> = <OnChargeTransactionOf<T> as OnChargeTransaction<T>>::Balance;
+
+/// The stringy name of a pallet or call from
+/// [`GetCallMetadata`](frame_support::traits::GetCallMetadata).
+pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// The context that is mixed into [`Pallet::sponsorship_payload`] and the transaction tags.
+const SPONSORSHIP_CONTEXT: &[u8] = b"sponsorship";
+
+/// A call, or all calls of a pallet, that a sponsor pays for.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct AllowedCall<T: Config> {
+	/// The name of the pallet.
+	pub pallet: NameOf<T>,
+	/// The name of the call, or `None` for every call of the pallet.
+	pub call: Option<NameOf<T>>,
+}
+
+/// The calls that a sponsor pays for.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum CallFilter<T: Config> {
+	/// Every call is sponsored.
+	Any,
+	/// Only the listed calls are sponsored.
+	Only(BoundedVec<AllowedCall<T>, T::MaxAllowedCalls>),
+}
+
+impl<T: Config> CallFilter<T> {
+	/// Whether a call with the given metadata passes the filter.
+	pub fn allows(&self, metadata: &CallMetadata) -> bool {
+		match self {
+			Self::Any => true,
+			Self::Only(allowed) => allowed.iter().any(|allowed| {
+				allowed.pallet[..] == *metadata.pallet_name.as_bytes() &&
+					allowed
+						.call
+						.as_ref()
+						.map_or(true, |call| call[..] == *metadata.function_name.as_bytes())
+			}),
+		}
+	}
+}
+
+/// The on-chain registration of a sponsor.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct SponsorInfo<T: Config> {
+	/// The amount the sponsor is still willing to spend on transaction fees.
+	pub budget: BalanceOf<T>,
+	/// The calls the sponsor pays for.
+	pub allowed_calls: CallFilter<T>,
+	/// The consideration for storing this registration.
+	pub ticket: T::Consideration,
+}
+
+/// Custom [`InvalidTransaction`] codes returned by [`ChargeSponsoredTransactionPayment`].
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[repr(u8)]
+pub enum SponsorshipError {
+	/// The sponsor is not registered.
+	UnknownSponsor = 0,
+	/// The sponsor does not pay for this call.
+	CallNotAllowed = 1,
+	/// The fee is higher than the maximum the sponsor signed.
+	FeeAboveLimit = 2,
+	/// The remaining budget of the sponsor does not cover the fee.
+	BudgetExhausted = 3,
+	/// The sponsorship is past its last valid block.
+	Expired = 4,
+}
+
+impl From<SponsorshipError> for TransactionValidityError {
+	fn from(error: SponsorshipError) -> Self {
+		InvalidTransaction::Custom(error as u8).into()
+	}
+}
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Signature type that sponsors use to sign a [`Sponsorship`].
+		type Signature: Verify<Signer = Self::AccountIdentifier> + Parameter + Send + Sync;
+
+		/// The account identifier used by the signature type.
+		type AccountIdentifier: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// A means of providing some cost while a sponsor is registered.
+		type Consideration: Consideration<Self::AccountId, Footprint>;
+
+		/// Maximum length of a pallet or call name in a [`CallFilter`].
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Maximum number of entries in a [`CallFilter`].
+		#[pallet::constant]
+		type MaxAllowedCalls: Get<u32>;
+
+		/// The weight information of this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to create a signed sponsorship in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Signature, Self::AccountId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The registered sponsors.
+	#[pallet::storage]
+	pub type Sponsors<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SponsorInfo<T>, OptionQuery>;
+
+	/// The next sponsorship nonce per sponsor and sponsored account.
+	///
+	/// This is kept when a sponsor unregisters, so old sponsorships cannot be replayed later.
+	#[pallet::storage]
+	pub type Nonces<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Nonce,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A sponsor was registered or updated.
+		SponsorshipSet { sponsor: T::AccountId, budget: BalanceOf<T> },
+		/// A sponsor was removed.
+		SponsorshipRemoved { sponsor: T::AccountId },
+		/// A transaction fee `actual_fee`, of which `tip` was added to the minimum inclusion fee,
+		/// has been paid by `sponsor` for a transaction of `who`.
+		SponsoredFeePaid {
+			sponsor: T::AccountId,
+			who: T::AccountId,
+			actual_fee: BalanceOf<T>,
+			tip: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not a registered sponsor.
+		NotSponsor,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register as a sponsor or update an existing registration.
+		///
+		/// The origin pays the fees of sponsored transactions that pass `allowed_calls`, until
+		/// their `budget` is spent.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship())]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			budget: BalanceOf<T>,
+			allowed_calls: CallFilter<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			let footprint = Footprint::from_mel::<SponsorInfo<T>>();
+			let ticket = match Sponsors::<T>::get(&sponsor) {
+				Some(info) => info.ticket.update(&sponsor, footprint)?,
+				None => T::Consideration::new(&sponsor, footprint)?,
+			};
+			Sponsors::<T>::insert(&sponsor, SponsorInfo { budget, allowed_calls, ticket });
+
+			Self::deposit_event(Event::SponsorshipSet { sponsor, budget });
+			Ok(())
+		}
+
+		/// Stop sponsoring transactions.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsorship())]
+		pub fn remove_sponsorship(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			let info = Sponsors::<T>::take(&sponsor).ok_or(Error::<T>::NotSponsor)?;
+			info.ticket.drop(&sponsor)?;
+
+			Self::deposit_event(Event::SponsorshipRemoved { sponsor });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The payload that a sponsor signs to pay for `call` of `who`.
+	///
+	/// The sponsor agrees to pay at most `max_fee` (including the tip). The `nonce` must match
+	/// [`Nonces`] when the transaction is included, which must happen at the latest in block
+	/// `valid_until`.
+	pub fn sponsorship_payload(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		max_fee: BalanceOf<T>,
+		nonce: T::Nonce,
+		valid_until: BlockNumberFor<T>,
+	) -> [u8; 32] {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(SPONSORSHIP_CONTEXT, genesis_hash, who, nonce, max_fee, valid_until, call)
+			.using_encoded(blake2_256)
+	}
+}
@@ -0,0 +1,360 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::{GetCallMetadata, OriginTrait},
+};
+use mock::*;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{DispatchTransaction, TransactionExtension},
+};
+
+const CALL: &RuntimeCall = &RuntimeCall::System(frame_system::Call::remark { remark: Vec::new() });
+
+fn name(name: &str) -> NameOf<Runtime> {
+	NameOf::<Runtime>::truncate_from(name.as_bytes().to_vec())
+}
+
+fn sponsor_everything(budget: u64) {
+	assert_ok!(SponsoredTxPayment::set_sponsorship(
+		RuntimeOrigin::signed(SPONSOR),
+		budget,
+		CallFilter::Any
+	));
+}
+
+fn sponsorship(who: AccountId, max_fee: u64, nonce: u64) -> Sponsorship<Runtime> {
+	sponsorship_until(who, max_fee, nonce, u64::MAX)
+}
+
+fn sponsorship_until(
+	who: AccountId,
+	max_fee: u64,
+	nonce: u64,
+	valid_until: u64,
+) -> Sponsorship<Runtime> {
+	let payload = SponsoredTxPayment::sponsorship_payload(&who, CALL, max_fee, nonce, valid_until);
+	Sponsorship {
+		sponsor: SPONSOR,
+		max_fee,
+		nonce,
+		valid_until,
+		signature: TestSignature(SPONSOR, payload.to_vec()),
+	}
+}
+
+fn sponsored_ext(
+	tip: u64,
+	sponsorship: Sponsorship<Runtime>,
+) -> ChargeSponsoredTransactionPayment<Runtime> {
+	ChargeSponsoredTransactionPayment::<Runtime>::from(tip, Some(sponsorship))
+}
+
+/// create a transaction info struct from weight. Handy to avoid building the whole struct.
+fn info_for(ext: &ChargeSponsoredTransactionPayment<Runtime>, w: u64) -> DispatchInfo {
+	DispatchInfo {
+		call_weight: Weight::from_parts(w, 0),
+		extension_weight: ext.weight(CALL),
+		..Default::default()
+	}
+}
+
+fn post_info_from_weight(w: u64) -> PostDispatchInfo {
+	PostDispatchInfo { actual_weight: Some(Weight::from_parts(w, 0)), pays_fee: Default::default() }
+}
+
+#[test]
+fn set_and_remove_sponsorship_works() {
+	new_test_ext().execute_with(|| {
+		let filter = CallFilter::Only(
+			vec![AllowedCall { pallet: name("System"), call: None }].try_into().unwrap(),
+		);
+		assert_ok!(SponsoredTxPayment::set_sponsorship(
+			RuntimeOrigin::signed(SPONSOR),
+			100,
+			filter.clone()
+		));
+		System::assert_last_event(Event::SponsorshipSet { sponsor: SPONSOR, budget: 100 }.into());
+		let info = Sponsors::<Runtime>::get(SPONSOR).unwrap();
+		assert_eq!((info.budget, info.allowed_calls), (100, filter));
+
+		// updating keeps a single registration.
+		sponsor_everything(50);
+		assert_eq!(Sponsors::<Runtime>::get(SPONSOR).unwrap().budget, 50);
+
+		assert_ok!(SponsoredTxPayment::remove_sponsorship(RuntimeOrigin::signed(SPONSOR)));
+		System::assert_last_event(Event::SponsorshipRemoved { sponsor: SPONSOR }.into());
+		assert!(Sponsors::<Runtime>::get(SPONSOR).is_none());
+		assert_noop!(
+			SponsoredTxPayment::remove_sponsorship(RuntimeOrigin::signed(SPONSOR)),
+			Error::<Runtime>::NotSponsor
+		);
+	});
+}
+
+#[test]
+fn call_filter_works() {
+	new_test_ext().execute_with(|| {
+		let remark = CALL.get_call_metadata();
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: 2,
+			value: 1,
+		})
+		.get_call_metadata();
+		let only = |pallet: &str, call: Option<&str>| {
+			CallFilter::<Runtime>::Only(
+				vec![AllowedCall { pallet: name(pallet), call: call.map(name) }]
+					.try_into()
+					.unwrap(),
+			)
+		};
+
+		assert!(CallFilter::<Runtime>::Any.allows(&remark));
+		assert!(only("System", None).allows(&remark));
+		assert!(!only("System", None).allows(&transfer));
+		assert!(only("System", Some("remark")).allows(&remark));
+		assert!(!only("System", Some("remark_with_event")).allows(&remark));
+		assert!(!only("Balances", Some("remark")).allows(&remark));
+	});
+}
+
+#[test]
+fn sponsored_transaction_is_paid_by_sponsor() {
+	new_test_ext().execute_with(|| {
+		sponsor_everything(1_000);
+		let len = 10;
+		let ext = sponsored_ext(5, sponsorship(ALICE, 100, 0));
+		let info = info_for(&ext, 5);
+
+		let (pre, origin) = ext
+			.validate_and_prepare(RuntimeOrigin::signed(ALICE), CALL, &info, len, 0)
+			.unwrap();
+		// the call is still dispatched by the sponsored account.
+		assert_eq!(origin.as_signer(), Some(&ALICE));
+		// len + call weight + extension weight + tip
+		assert_eq!(Balances::free_balance(SPONSOR), 10_000 - 10 - 5 - 20 - 5);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_eq!(Sponsors::<Runtime>::get(SPONSOR).unwrap().budget, 1_000 - 40);
+		assert_eq!(Nonces::<Runtime>::get(SPONSOR, ALICE), 1);
+
+		// the call used less weight than declared, the difference is refunded to the sponsor and
+		// their budget.
+		assert_ok!(ChargeSponsoredTransactionPayment::<Runtime>::post_dispatch_details(
+			pre,
+			&info,
+			&post_info_from_weight(22),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Balances::free_balance(SPONSOR), 10_000 - 10 - 22 - 5);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_eq!(Sponsors::<Runtime>::get(SPONSOR).unwrap().budget, 1_000 - 37);
+		System::assert_last_event(
+			Event::SponsoredFeePaid { sponsor: SPONSOR, who: ALICE, actual_fee: 37, tip: 5 }.into(),
+		);
+	});
+}
+
+#[test]
+fn unsponsored_transaction_is_paid_by_signer() {
+	new_test_ext().execute_with(|| {
+		sponsor_everything(1_000);
+		let len = 10;
+		let ext = ChargeSponsoredTransactionPayment::<Runtime>::from(0, None);
+		let info = info_for(&ext, 5);
+
+		let (pre, _) = ext
+			.validate_and_prepare(RuntimeOrigin::signed(ALICE), CALL, &info, len, 0)
+			.unwrap();
+		// len + call weight + inner extension weight
+		assert_eq!(Balances::free_balance(ALICE), 1_000 - 10 - 5 - 10);
+		assert_eq!(Balances::free_balance(SPONSOR), 10_000);
+
+		assert_ok!(ChargeSponsoredTransactionPayment::<Runtime>::post_dispatch_details(
+			pre,
+			&info,
+			&post_info_from_weight(12),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Balances::free_balance(ALICE), 1_000 - 10 - 12);
+		assert_eq!(Sponsors::<Runtime>::get(SPONSOR).unwrap().budget, 1_000);
+	});
+}
+
+#[test]
+fn invalid_sponsorships_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let ext = sponsored_ext(0, sponsorship(ALICE, 100, 0));
+		let info = info_for(&ext, 5);
+		let validate = |ext: ChargeSponsoredTransactionPayment<Runtime>| {
+			ext.validate_only(
+				RuntimeOrigin::signed(ALICE),
+				CALL,
+				&info,
+				10,
+				TransactionSource::External,
+				0,
+			)
+			.map(|_| ())
+		};
+
+		// not a sponsor.
+		assert_eq!(validate(ext.clone()), Err(SponsorshipError::UnknownSponsor.into()));
+
+		// the call is not sponsored.
+		assert_ok!(SponsoredTxPayment::set_sponsorship(
+			RuntimeOrigin::signed(SPONSOR),
+			1_000,
+			CallFilter::Only(
+				vec![AllowedCall { pallet: name("Balances"), call: None }].try_into().unwrap()
+			)
+		));
+		assert_eq!(validate(ext.clone()), Err(SponsorshipError::CallNotAllowed.into()));
+		sponsor_everything(1_000);
+		assert_ok!(validate(ext.clone()));
+
+		// signed for someone else.
+		let mut forged = sponsorship(ALICE, 100, 0);
+		forged.signature = sponsorship(SPONSOR, 100, 0).signature;
+		assert_eq!(validate(sponsored_ext(0, forged)), Err(InvalidTransaction::BadProof.into()));
+
+		// the limits are part of the signed payload.
+		let mut raised = sponsorship(ALICE, 10, 0);
+		raised.max_fee = 100;
+		assert_eq!(validate(sponsored_ext(0, raised)), Err(InvalidTransaction::BadProof.into()));
+
+		// the fee is above the signed limit.
+		assert_eq!(
+			validate(sponsored_ext(0, sponsorship(ALICE, 34, 0))),
+			Err(SponsorshipError::FeeAboveLimit.into())
+		);
+
+		// the budget is used up.
+		sponsor_everything(34);
+		assert_eq!(validate(ext.clone()), Err(SponsorshipError::BudgetExhausted.into()));
+
+		// unsigned transactions cannot be sponsored.
+		assert_eq!(
+			ext.validate_only(
+				RuntimeOrigin::none(),
+				CALL,
+				&info,
+				10,
+				TransactionSource::External,
+				0
+			)
+			.map(|_| ()),
+			Err(InvalidTransaction::BadSigner.into())
+		);
+	});
+}
+
+#[test]
+fn sponsorship_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		sponsor_everything(1_000);
+		let ext = sponsored_ext(0, sponsorship(ALICE, 100, 0));
+		let info = info_for(&ext, 5);
+
+		// a future nonce is valid, but has to wait for the previous sponsorship.
+		let next = sponsored_ext(0, sponsorship(ALICE, 100, 1));
+		let (validity, _, _) = next
+			.validate_only(
+				RuntimeOrigin::signed(ALICE),
+				CALL,
+				&info,
+				10,
+				TransactionSource::External,
+				0,
+			)
+			.unwrap();
+		assert_eq!(validity.requires, vec![(SPONSORSHIP_CONTEXT, SPONSOR, ALICE, 0u64).encode()]);
+		assert_eq!(validity.provides, vec![(SPONSORSHIP_CONTEXT, SPONSOR, ALICE, 1u64).encode()]);
+		assert_eq!(
+			next.clone()
+				.validate_and_prepare(RuntimeOrigin::signed(ALICE), CALL, &info, 10, 0)
+				.map(|_| ()),
+			Err(InvalidTransaction::Future.into())
+		);
+
+		assert_ok!(ext.clone().validate_and_prepare(
+			RuntimeOrigin::signed(ALICE),
+			CALL,
+			&info,
+			10,
+			0
+		));
+		assert_eq!(
+			ext.validate_and_prepare(RuntimeOrigin::signed(ALICE), CALL, &info, 10, 0)
+				.map(|_| ()),
+			Err(InvalidTransaction::Stale.into())
+		);
+		assert_ok!(next.validate_and_prepare(RuntimeOrigin::signed(ALICE), CALL, &info, 10, 0));
+
+		// removing and registering again does not reset the nonces.
+		assert_ok!(SponsoredTxPayment::remove_sponsorship(RuntimeOrigin::signed(SPONSOR)));
+		sponsor_everything(1_000);
+		assert_eq!(Nonces::<Runtime>::get(SPONSOR, ALICE), 2);
+	});
+}
+
+#[test]
+fn expired_sponsorship_is_rejected() {
+	new_test_ext().execute_with(|| {
+		sponsor_everything(1_000);
+		System::set_block_number(10);
+		let ext = sponsored_ext(0, sponsorship_until(ALICE, 100, 0, 12));
+		let info = info_for(&ext, 5);
+		let validate = |ext: &ChargeSponsoredTransactionPayment<Runtime>| {
+			ext.validate_only(
+				RuntimeOrigin::signed(ALICE),
+				CALL,
+				&info,
+				10,
+				TransactionSource::External,
+				0,
+			)
+			.map(|(validity, _, _)| validity)
+		};
+
+		// the transaction is only kept in the pool while the sponsorship is valid.
+		assert_eq!(validate(&ext).unwrap().longevity, 3);
+
+		// the expiry is part of the signed payload.
+		let mut extended = sponsorship_until(ALICE, 100, 0, 12);
+		extended.valid_until = 20;
+		assert_eq!(
+			validate(&sponsored_ext(0, extended)).map(|_| ()),
+			Err(InvalidTransaction::BadProof.into())
+		);
+
+		System::set_block_number(12);
+		assert_eq!(validate(&ext).unwrap().longevity, 1);
+
+		System::set_block_number(13);
+		assert_eq!(validate(&ext).map(|_| ()), Err(SponsorshipError::Expired.into()));
+		assert_eq!(
+			ext.validate_and_prepare(RuntimeOrigin::signed(ALICE), CALL, &info, 10, 0)
+				.map(|_| ()),
+			Err(SponsorshipError::Expired.into())
+		);
+	});
+}
@@ -0,0 +1,144 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_sponsored_tx_payment`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-12, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `runner-bn-ce5rx-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/production/substrate-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_sponsored_tx_payment
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./substrate/frame/transaction-payment/sponsored-tx-payment/src/weights.rs
+// --header=./substrate/HEADER-APACHE2
+// --template=./substrate/.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_sponsored_tx_payment`.
+pub trait WeightInfo {
+	fn set_sponsorship() -> Weight;
+	fn remove_sponsorship() -> Weight;
+	fn charge_sponsored_transaction_payment() -> Weight;
+}
+
+/// Weights for `pallet_sponsored_tx_payment` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `SponsoredTxPayment::Sponsors` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Sponsors` (`max_values`: None, `max_size`: Some(1174), added: 3649, mode: `MaxEncodedLen`)
+	fn set_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4639`
+		// Minimum execution time: 21_377_000 picoseconds.
+		Weight::from_parts(21_384_000, 4639)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SponsoredTxPayment::Sponsors` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Sponsors` (`max_values`: None, `max_size`: Some(1174), added: 3649, mode: `MaxEncodedLen`)
+	fn remove_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `4639`
+		// Minimum execution time: 19_900_000 picoseconds.
+		Weight::from_parts(19_907_000, 4639)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SponsoredTxPayment::Sponsors` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Sponsors` (`max_values`: None, `max_size`: Some(1174), added: 3649, mode: `MaxEncodedLen`)
+	/// Storage: `SponsoredTxPayment::Nonces` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Nonces` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn charge_sponsored_transaction_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `4639`
+		// Minimum execution time: 78_238_000 picoseconds.
+		Weight::from_parts(78_245_000, 4639)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `SponsoredTxPayment::Sponsors` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Sponsors` (`max_values`: None, `max_size`: Some(1174), added: 3649, mode: `MaxEncodedLen`)
+	fn set_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4639`
+		// Minimum execution time: 21_377_000 picoseconds.
+		Weight::from_parts(21_384_000, 4639)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SponsoredTxPayment::Sponsors` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Sponsors` (`max_values`: None, `max_size`: Some(1174), added: 3649, mode: `MaxEncodedLen`)
+	fn remove_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `4639`
+		// Minimum execution time: 19_900_000 picoseconds.
+		Weight::from_parts(19_907_000, 4639)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SponsoredTxPayment::Sponsors` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Sponsors` (`max_values`: None, `max_size`: Some(1174), added: 3649, mode: `MaxEncodedLen`)
+	/// Storage: `SponsoredTxPayment::Nonces` (r:1 w:1)
+	/// Proof: `SponsoredTxPayment::Nonces` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn charge_sponsored_transaction_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `4639`
+		// Minimum execution time: 78_238_000 picoseconds.
+		Weight::from_parts(78_245_000, 4639)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
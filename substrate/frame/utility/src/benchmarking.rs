@@ -115,6 +115,36 @@ mod benchmark {
 		_(RawOrigin::Signed(caller), main_call, fallback_call);
 	}
 
+	#[benchmark]
+	fn batch_with_checkpoints(c: Linear<0, 1000>) {
+		let steps = (0..c)
+			.map(|i| {
+				if i % 2 == 0 {
+					BatchStep::Call(frame_system::Call::remark { remark: vec![] }.into())
+				} else {
+					BatchStep::Checkpoint(CheckpointName::truncate_from(i.encode()))
+				}
+			})
+			.collect::<Vec<_>>();
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), steps);
+
+		assert_last_event::<T>(Event::BatchCompleted.into());
+	}
+
+	#[benchmark]
+	fn dry_run_batch(c: Linear<0, 1000>) {
+		let calls = vec![frame_system::Call::remark { remark: vec![] }.into(); c as usize];
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), calls);
+
+		assert_last_event::<T>(Event::DryRunCompleted.into());
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		tests::new_test_ext(),
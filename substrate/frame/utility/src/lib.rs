@@ -45,6 +45,10 @@
 //!
 //! #### For batch dispatch
 //! * `batch` - Dispatch multiple calls from the sender's origin.
+//! * `batch_with_checkpoints` - Dispatch multiple calls, rolling back only to the last checkpoint
+//!   on failure.
+//! * `dry_run_batch` - Dispatch multiple calls and report their outcome without keeping any of
+//!   their changes.
 //!
 //! #### For pseudonymal dispatch
 //! * `as_derivative` - Dispatch a call from a derivative signed origin.
@@ -59,22 +63,54 @@ pub mod weights;
 extern crate alloc;
 
 use alloc::{boxed::Box, vec::Vec};
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{
 	dispatch::{
 		extract_actual_weight,
 		DispatchClass::{Normal, Operational},
 		GetDispatchInfo, PostDispatchInfo,
 	},
-	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
+	storage::{with_transaction, TransactionOutcome},
+	traits::{ConstU32, IsSubType, OriginTrait, UnfilteredDispatchable},
+	weights::Weight,
+	BoundedVec,
 };
+use scale_info::TypeInfo;
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{BadOrigin, Dispatchable, TrailingZeroInput};
+use sp_runtime::{
+	traits::{BadOrigin, Dispatchable, TrailingZeroInput},
+	DispatchError, DispatchResult, RuntimeDebug,
+};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 
+/// The maximum length of a checkpoint name.
+pub const MAX_CHECKPOINT_NAME_LEN: u32 = 32;
+
+/// The name of a checkpoint in a [`Call::batch_with_checkpoints`].
+pub type CheckpointName = BoundedVec<u8, ConstU32<MAX_CHECKPOINT_NAME_LEN>>;
+
+/// A single step of a [`Call::batch_with_checkpoints`].
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BatchStep<Call> {
+	/// Dispatch the call.
+	Call(Call),
+	/// Commit all calls dispatched since the previous checkpoint.
+	Checkpoint(CheckpointName),
+}
+
+impl<Call> BatchStep<Call> {
+	/// The call of this step, if it is not a checkpoint.
+	pub fn call(&self) -> Option<&Call> {
+		match self {
+			BatchStep::Call(call) => Some(call),
+			BatchStep::Checkpoint(_) => None,
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -128,6 +164,15 @@ pub mod pallet {
 		IfElseMainSuccess,
 		/// The fallback call was dispatched.
 		IfElseFallbackCalled { main_error: DispatchError },
+		/// All calls of a checkpointed batch up to the step `index` were committed.
+		CheckpointReached { index: u32, name: CheckpointName },
+		/// A checkpointed batch was interrupted by the call at step `index`. All calls since the
+		/// last reached `checkpoint` were rolled back.
+		BatchRolledBack { index: u32, error: DispatchError, checkpoint: Option<CheckpointName> },
+		/// A call of a dry-run batch was dispatched, using `weight` with the given `result`.
+		DryRunItem { index: u32, weight: Weight, result: DispatchResult },
+		/// A dry-run batch was completed and all of its changes were discarded.
+		DryRunCompleted,
 	}
 
 	// Align the call size to 1KB. As we are currently compiling the runtime for native/wasm
@@ -582,14 +627,167 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Send a batch of dispatch calls, separated by named checkpoints.
+		///
+		/// May be called from any origin except `None`.
+		///
+		/// - `steps`: The calls to be dispatched from the same origin, interleaved with
+		///   checkpoints. The number of steps must not exceed the constant: `batched_calls_limit`
+		///   (available in constant metadata).
+		///
+		/// The calls between two checkpoints are dispatched in their own storage layer. Reaching a
+		/// checkpoint commits them and deposits `CheckpointReached`. If a call fails, only the
+		/// calls since the last checkpoint are rolled back, the remaining steps are skipped and
+		/// `BatchRolledBack` is deposited. Weight of the skipped calls is refunded.
+		///
+		/// If origin is root then the calls are dispatched without checking origin filter. (This
+		/// includes bypassing `frame_system::Config::BaseCallFilter`).
+		///
+		/// ## Complexity
+		/// - O(S) where S is the number of steps.
+		///
+		/// Like `batch`, this will return `Ok` even if a call failed.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let (dispatch_weight, dispatch_class) =
+				Pallet::<T>::weight_and_dispatch_class(steps.iter().filter_map(BatchStep::call));
+			let dispatch_weight = dispatch_weight
+				.saturating_add(T::WeightInfo::batch_with_checkpoints(steps.len() as u32));
+			(dispatch_weight, dispatch_class)
+		})]
+		pub fn batch_with_checkpoints(
+			origin: OriginFor<T>,
+			steps: Vec<BatchStep<<T as Config>::RuntimeCall>>,
+		) -> DispatchResultWithPostInfo {
+			// Do not allow the `None` origin.
+			if ensure_none(origin.clone()).is_ok() {
+				return Err(BadOrigin.into())
+			}
+
+			let is_root = ensure_root(origin.clone()).is_ok();
+			let steps_len = steps.len();
+			ensure!(steps_len <= Self::batched_calls_limit() as usize, Error::<T>::TooManyCalls);
+
+			// Track the actual weight of each of the dispatched calls.
+			let mut weight = Weight::zero();
+			// Track the number of steps processed so far and the step that failed, if any.
+			let mut processed = 0usize;
+			let mut failed_at = None;
+			let mut last_checkpoint = None;
+			let mut steps = steps.into_iter().enumerate().peekable();
+			while steps.peek().is_some() {
+				// Dispatch everything up to the next checkpoint in its own storage layer.
+				let outcome = with_transaction(|| {
+					for (index, step) in steps.by_ref() {
+						processed = index.saturating_add(1);
+						let call = match step {
+							BatchStep::Call(call) => call,
+							BatchStep::Checkpoint(name) =>
+								return TransactionOutcome::Commit(Ok(Some((index, name)))),
+						};
+						let info = call.get_dispatch_info();
+						// If origin is root, don't apply any dispatch filters; root can call
+						// anything.
+						let result = if is_root {
+							call.dispatch_bypass_filter(origin.clone())
+						} else {
+							call.dispatch(origin.clone())
+						};
+						// Add the weight of this call.
+						weight = weight.saturating_add(extract_actual_weight(&result, &info));
+						if let Err(e) = result {
+							failed_at = Some(index);
+							return TransactionOutcome::Rollback(Err(e.error))
+						}
+						Self::deposit_event(Event::ItemCompleted);
+					}
+					TransactionOutcome::Commit(Ok(None))
+				});
+
+				match outcome {
+					Ok(Some((index, name))) => {
+						Self::deposit_event(Event::CheckpointReached {
+							index: index as u32,
+							name: name.clone(),
+						});
+						last_checkpoint = Some(name);
+					},
+					Ok(None) => {},
+					Err(error) => {
+						Self::deposit_event(Event::BatchRolledBack {
+							index: failed_at.unwrap_or(processed) as u32,
+							error,
+							checkpoint: last_checkpoint,
+						});
+						// Only charge for the steps that were processed.
+						let base_weight = T::WeightInfo::batch_with_checkpoints(processed as u32);
+						return Ok(Some(base_weight.saturating_add(weight)).into())
+					},
+				}
+			}
+			Self::deposit_event(Event::BatchCompleted);
+			let base_weight = T::WeightInfo::batch_with_checkpoints(steps_len as u32);
+			Ok(Some(base_weight.saturating_add(weight)).into())
+		}
+
+		/// Dispatch a batch of calls and report their outcome, discarding all of their changes.
+		///
+		/// May be called from any origin except `None`.
+		///
+		/// - `calls`: The calls to be dispatched from the same origin. The number of call must not
+		///   exceed the constant: `batched_calls_limit` (available in constant metadata).
+		///
+		/// All calls are dispatched like in `force_batch`, each one seeing the changes of the
+		/// previous ones. Afterwards, all changes are rolled back and a `DryRunItem` event with the
+		/// actual weight and result is deposited for each call, followed by `DryRunCompleted`.
+		///
+		/// If origin is root then the calls are dispatched without checking origin filter. (This
+		/// includes bypassing `frame_system::Config::BaseCallFilter`).
+		///
+		/// ## Complexity
+		/// - O(C) where C is the number of calls to be batched.
+		#[pallet::call_index(9)]
+		#[pallet::weight({
+			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(&calls);
+			let dispatch_weight = dispatch_weight.saturating_add(T::WeightInfo::dry_run_batch(calls.len() as u32));
+			(dispatch_weight, dispatch_class)
+		})]
+		pub fn dry_run_batch(
+			origin: OriginFor<T>,
+			calls: Vec<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			// Do not allow the `None` origin.
+			if ensure_none(origin.clone()).is_ok() {
+				return Err(BadOrigin.into())
+			}
+
+			let calls_len = calls.len();
+			ensure!(calls_len <= Self::batched_calls_limit() as usize, Error::<T>::TooManyCalls);
+
+			let outcomes = Self::dry_run(origin, calls)?;
+			// The calls were really executed, so their weight is charged.
+			let mut weight = Weight::zero();
+			for (index, (call_weight, result)) in outcomes.into_iter().enumerate() {
+				weight = weight.saturating_add(call_weight);
+				Self::deposit_event(Event::DryRunItem {
+					index: index as u32,
+					weight: call_weight,
+					result,
+				});
+			}
+			Self::deposit_event(Event::DryRunCompleted);
+			let base_weight = T::WeightInfo::dry_run_batch(calls_len as u32);
+			Ok(Some(base_weight.saturating_add(weight)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		/// Get the accumulated `weight` and the dispatch class for the given `calls`.
-		fn weight_and_dispatch_class(
-			calls: &[<T as Config>::RuntimeCall],
+		fn weight_and_dispatch_class<'a>(
+			calls: impl IntoIterator<Item = &'a <T as Config>::RuntimeCall>,
 		) -> (Weight, DispatchClass) {
-			let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info());
+			let dispatch_infos = calls.into_iter().map(|call| call.get_dispatch_info());
 			let (dispatch_weight, dispatch_class) = dispatch_infos.fold(
 				(Weight::zero(), DispatchClass::Operational),
 				|(total_weight, dispatch_class): (Weight, DispatchClass), di| {
@@ -621,4 +819,30 @@ impl<T: Config> Pallet<T> {
 		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
+
+	/// Dispatch `calls` from `origin` and return the actual weight and result of each of them.
+	///
+	/// All calls are dispatched in a single storage layer, which is rolled back afterwards, so no
+	/// changes are kept. If `origin` is root, the calls bypass the origin filter.
+	pub fn dry_run(
+		origin: T::RuntimeOrigin,
+		calls: Vec<<T as Config>::RuntimeCall>,
+	) -> Result<Vec<(Weight, DispatchResult)>, DispatchError> {
+		let is_root = frame_system::ensure_root(origin.clone()).is_ok();
+		with_transaction(|| {
+			let outcomes = calls
+				.into_iter()
+				.map(|call| {
+					let info = call.get_dispatch_info();
+					let result = if is_root {
+						call.dispatch_bypass_filter(origin.clone())
+					} else {
+						call.dispatch(origin.clone())
+					};
+					(extract_actual_weight(&result, &info), result.map(|_| ()).map_err(|e| e.error))
+				})
+				.collect();
+			TransactionOutcome::Rollback(Ok(outcomes))
+		})
+	}
 }
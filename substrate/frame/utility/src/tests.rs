@@ -187,6 +187,7 @@ impl pallet_collective::Config<CouncilCollective> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -1089,3 +1090,127 @@ fn if_else_with_nested_if_else_works() {
 		System::assert_last_event(utility::Event::IfElseMainSuccess.into());
 	});
 }
+
+#[test]
+fn batch_with_checkpoints_works() {
+	new_test_ext().execute_with(|| {
+		let checkpoint: CheckpointName = b"first".to_vec().try_into().unwrap();
+		assert_ok!(Utility::batch_with_checkpoints(
+			RuntimeOrigin::signed(1),
+			vec![
+				BatchStep::Call(call_transfer(2, 2)),
+				BatchStep::Checkpoint(checkpoint.clone()),
+				BatchStep::Call(call_transfer(2, 3)),
+			]
+		));
+		assert_eq!(Balances::free_balance(1), 5);
+		assert_eq!(Balances::free_balance(2), 15);
+		System::assert_has_event(
+			utility::Event::CheckpointReached { index: 1, name: checkpoint }.into(),
+		);
+		System::assert_last_event(utility::Event::BatchCompleted.into());
+	});
+}
+
+#[test]
+fn batch_with_checkpoints_rolls_back_to_last_checkpoint() {
+	new_test_ext().execute_with(|| {
+		let checkpoint: CheckpointName = b"first".to_vec().try_into().unwrap();
+		let info = call_transfer(2, 2).get_dispatch_info();
+		let call = RuntimeCall::Utility(UtilityCall::batch_with_checkpoints {
+			steps: vec![
+				BatchStep::Call(call_transfer(2, 2)),
+				BatchStep::Checkpoint(checkpoint.clone()),
+				BatchStep::Call(call_transfer(2, 2)),
+				BatchStep::Call(call_transfer(2, 10)),
+				BatchStep::Call(call_transfer(2, 1)),
+			],
+		});
+		let result = call.dispatch(RuntimeOrigin::signed(1));
+		assert_ok!(result);
+		// Only the calls after the checkpoint were reverted, the last call was skipped.
+		assert_eq!(Balances::free_balance(1), 8);
+		assert_eq!(Balances::free_balance(2), 12);
+		System::assert_last_event(
+			utility::Event::BatchRolledBack {
+				index: 3,
+				error: TokenError::FundsUnavailable.into(),
+				checkpoint: Some(checkpoint),
+			}
+			.into(),
+		);
+		// The rolled back `ItemCompleted` event is gone.
+		assert_eq!(
+			utility_events(),
+			vec![
+				utility::Event::ItemCompleted,
+				utility::Event::CheckpointReached {
+					index: 1,
+					name: b"first".to_vec().try_into().unwrap()
+				},
+				utility::Event::BatchRolledBack {
+					index: 3,
+					error: TokenError::FundsUnavailable.into(),
+					checkpoint: Some(b"first".to_vec().try_into().unwrap()),
+				},
+			]
+		);
+		// The skipped call is refunded.
+		assert_eq!(
+			result.unwrap().actual_weight,
+			Some(<Test as Config>::WeightInfo::batch_with_checkpoints(4) + info.call_weight * 3),
+		);
+	});
+}
+
+#[test]
+fn batch_with_checkpoints_without_checkpoint_rolls_back_everything() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Utility::batch_with_checkpoints(
+			RuntimeOrigin::signed(1),
+			vec![BatchStep::Call(call_transfer(2, 5)), BatchStep::Call(call_transfer(2, 10))]
+		));
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		System::assert_last_event(
+			utility::Event::BatchRolledBack {
+				index: 1,
+				error: TokenError::FundsUnavailable.into(),
+				checkpoint: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn dry_run_batch_reports_outcomes() {
+	new_test_ext().execute_with(|| {
+		let info = call_transfer(2, 5).get_dispatch_info();
+		let call = RuntimeCall::Utility(UtilityCall::dry_run_batch {
+			calls: vec![call_transfer(2, 5), call_transfer(2, 10), call_transfer(2, 5)],
+		});
+		let result = call.dispatch(RuntimeOrigin::signed(1));
+		assert_ok!(result);
+		// No changes are kept.
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_eq!(
+			utility_events(),
+			vec![
+				utility::Event::DryRunItem { index: 0, weight: info.call_weight, result: Ok(()) },
+				utility::Event::DryRunItem {
+					index: 1,
+					weight: info.call_weight,
+					result: Err(TokenError::FundsUnavailable.into()),
+				},
+				utility::Event::DryRunItem { index: 2, weight: info.call_weight, result: Ok(()) },
+				utility::Event::DryRunCompleted,
+			]
+		);
+		assert_eq!(
+			result.unwrap().actual_weight,
+			Some(<Test as Config>::WeightInfo::dry_run_batch(3) + info.call_weight * 3),
+		);
+	});
+}
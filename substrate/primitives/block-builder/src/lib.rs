@@ -22,7 +22,7 @@
 extern crate alloc;
 
 use sp_inherents::{CheckInherentsResult, InherentData};
-use sp_runtime::{traits::Block as BlockT, ApplyExtrinsicResult};
+use sp_runtime::{traits::Block as BlockT, ApplyExtrinsicResult, Weight};
 
 sp_api::decl_runtime_apis! {
 	/// The `BlockBuilder` api trait that provides the required functionality for building a block.
@@ -51,4 +51,14 @@ sp_api::decl_runtime_apis! {
 		/// Check that the inherents are valid. The inherent data will vary from chain to chain.
 		fn check_inherents(block: Block, data: InherentData) -> CheckInherentsResult;
 	}
+
+	/// The `TaskApi` api trait that lets block authors include the tasks offered by the runtime.
+	///
+	/// This is optional for runtimes; block authors skip tasks when it is not implemented.
+	pub trait TaskApi {
+		/// Returns unsigned extrinsics executing the tasks which are currently valid.
+		///
+		/// The combined weight of the tasks must not exceed `weight_limit`.
+		fn task_extrinsics(weight_limit: Weight) -> alloc::vec::Vec<<Block as BlockT>::Extrinsic>;
+	}
 }
@@ -559,6 +559,75 @@ where
 	}
 }
 
+/// The projected reward of a hypothetical nominator backing a single validator in an era.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidatorRewardProjection<AccountId, Balance> {
+	/// The validator being backed.
+	pub validator: AccountId,
+	/// The part of the nominator's bond assumed to back `validator`.
+	pub stake: Balance,
+	/// The projected reward for backing `validator`.
+	pub reward: Balance,
+	/// The exposure page the nominator would end up in.
+	///
+	/// Pages hold up to `MaxExposurePageSize` nominators each and are paid out separately, so a
+	/// page other than the first one needs its own payout. `None` if the validator is already
+	/// backed by the maximum number of nominators, all with more stake, in which case the
+	/// nominator is not exposed and earns nothing.
+	pub page: Option<Page>,
+}
+
+/// The projected reward of a hypothetical nominator in an era.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EraRewardProjection<AccountId, Balance> {
+	/// The era the projection is based on.
+	pub era: EraIndex,
+	/// The projected reward across all validators backed in `era`.
+	pub total: Balance,
+	/// The projection for each of the targets that was elected in `era`.
+	pub validators: Vec<ValidatorRewardProjection<AccountId, Balance>>,
+}
+
+/// The slash a single staker would receive from an offence.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct StakerSlash<AccountId, Balance> {
+	/// The slashed staker.
+	pub who: AccountId,
+	/// The amount that would be slashed from `who`.
+	///
+	/// Only the largest slash within a slashing span is taken, so this is what the offence adds
+	/// on top of any earlier slash in the same span.
+	pub amount: Balance,
+	/// The index of the slashing span of `who` that the offence era falls in.
+	///
+	/// `None` if the offence era is older than any span still tracked for `who`, in which case
+	/// nothing is slashed.
+	pub span: Option<u32>,
+}
+
+/// The outcome of an offence, as it would be computed if it were processed now.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SlashSimulation<AccountId, Balance> {
+	/// The era in which the offence was committed.
+	pub offence_era: EraIndex,
+	/// The fraction of the exposure that is slashed.
+	pub fraction: Perbill,
+	/// The fraction the validator has already been slashed by for `offence_era`, which is
+	/// deducted from `fraction`.
+	pub prior_fraction: Perbill,
+	/// The era at the start of which the slash would be applied, or `None` if it would be applied
+	/// as soon as it is computed.
+	pub apply_era: Option<EraIndex>,
+	/// The slash of the validator's own stake.
+	pub validator: StakerSlash<AccountId, Balance>,
+	/// The slash of each nominator, along with the exposure page it is computed from.
+	pub nominators: Vec<(Page, StakerSlash<AccountId, Balance>)>,
+	/// The total amount slashed across the validator and all nominators.
+	pub total: Balance,
+	/// The part of the slash that would be paid out to the reporters.
+	pub reporters_reward: Balance,
+}
+
 /// A type that belongs only in the context of an `Agent`.
 ///
 /// `Agent` is someone that manages delegated funds from [`Delegator`] accounts. It can
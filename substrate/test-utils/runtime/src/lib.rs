@@ -32,7 +32,7 @@ use alloc::{vec, vec::Vec};
 use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{
 	construct_runtime, derive_impl,
-	dispatch::DispatchClass,
+	dispatch::{DispatchClass, GetDispatchInfo},
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
 	traits::{ConstU32, ConstU64},
@@ -111,6 +111,13 @@ pub fn wasm_binary_logging_disabled_unwrap() -> &'static [u8] {
 	)
 }
 
+/// Id of the `SubstrateTest::get_storage` view function.
+#[cfg(feature = "std")]
+pub fn get_storage_view_function_id() -> [u8; 32] {
+	use frame_support::view_functions::ViewFunction;
+	substrate_test_pallet::GetStorageViewFunction::<Runtime>::id().into()
+}
+
 /// Test runtime version.
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
@@ -150,6 +157,9 @@ pub type Signature = sr25519::Signature;
 pub type Pair = sp_core::sr25519::Pair;
 
 // TODO: Remove after the Checks are migrated to TxExtension.
+/// The storage key which the task offered through `sp_block_builder::TaskApi` sets.
+pub const TASK_KEY: &[u8] = b":test_task";
+
 /// The extension to the basic transaction logic.
 pub type TxExtension = (
 	(CheckNonce<Runtime>, CheckWeight<Runtime>),
@@ -498,6 +508,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			id: frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -529,6 +548,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_block_builder::TaskApi<Block> for Runtime {
+		fn task_extrinsics(weight_limit: Weight) -> Vec<<Block as BlockT>::Extrinsic> {
+			// A single task, which sets `TASK_KEY` unless it is already set.
+			if sp_io::storage::exists(TASK_KEY) {
+				return vec![]
+			}
+			let call: RuntimeCall = substrate_test_pallet::Call::storage_change {
+				key: TASK_KEY.to_vec(),
+				value: Some(vec![1]),
+			}
+			.into();
+			if call.get_dispatch_info().call_weight.any_gt(weight_limit) {
+				return vec![]
+			}
+			vec![Extrinsic::new_bare(call)]
+		}
+	}
+
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
 		fn account_nonce(account: AccountId) -> Nonce {
 			System::account_nonce(account)
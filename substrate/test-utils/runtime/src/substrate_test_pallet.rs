@@ -217,6 +217,14 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::view_functions_experimental]
+	impl<T: Config> Pallet<T> {
+		/// Read the raw value stored under `key`.
+		pub fn get_storage(key: Vec<u8>) -> Option<Vec<u8>> {
+			storage::unhashed::get_raw(&key)
+		}
+	}
+
 	#[pallet::validate_unsigned]
 	impl<T: Config> ValidateUnsigned for Pallet<T> {
 		type Call = Call<T>;
@@ -17,7 +17,8 @@
 
 //! Contains the core benchmarking logic.
 
-use codec::DecodeAll;
+use codec::{Decode, DecodeAll, Encode};
+use frame_benchmarking::{HookSimulation, PalletHookWeights};
 use frame_support::weights::constants::WEIGHT_REF_TIME_PER_NANOS;
 use frame_system::ConsumedWeight;
 use sc_block_builder::BlockBuilderApi;
@@ -25,8 +26,9 @@ use sc_cli::{Error, Result};
 use sc_client_api::{
 	Backend as ClientBackend, BlockBackend, HeaderBackend, StorageProvider, UsageProvider,
 };
-use sp_api::{ApiExt, Core, ProvideRuntimeApi};
+use sp_api::{ApiExt, CallApiAt, CallApiAtParams, Core, ProvideRuntimeApi, RuntimeApiInfo};
 use sp_blockchain::Error::RuntimeApiError;
+use sp_core::traits::CallContext;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, Header as HeaderT},
@@ -59,6 +61,15 @@ pub struct BenchmarkParams {
 	/// Number of times that the benchmark should be repeated for each block.
 	#[arg(long, default_value_t = 10)]
 	pub repeat: u32,
+
+	/// Instead of benchmarking the blocks, simulate the `on_poll` and `on_idle` hooks of this
+	/// many empty blocks on top of each of them.
+	///
+	/// Logs how much weight the hooks of each pallet consumed over all simulated blocks. The
+	/// runtime has to implement the `HookSimulation` api, which usually requires building it
+	/// with the `runtime-benchmarks` feature.
+	#[arg(long, value_name = "BLOCKS")]
+	pub simulate_hooks: Option<u32>,
 }
 
 /// Convenience closure for the [`Benchmark::run()`] function.
@@ -79,7 +90,8 @@ where
 		+ StorageProvider<Block, BA>
 		+ UsageProvider<Block>
 		+ BlockBackend<Block>
-		+ HeaderBackend<Block>,
+		+ HeaderBackend<Block>
+		+ CallApiAt<Block>,
 	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
 {
 	/// Returns a new [`Self`] from the arguments.
@@ -96,6 +108,10 @@ where
 		for i in self.params.from..=self.params.to {
 			let block_num = BlockId::Number(i.into());
 			let hash = self.client.expect_block_hash_from_id(&block_num)?;
+			if let Some(blocks) = self.params.simulate_hooks {
+				self.simulate_hooks(i, hash, blocks)?;
+				continue
+			}
 			let consumed = self.consumed_weight(hash)?;
 
 			let block = self.client.block(hash)?.ok_or(format!("Block {} not found", block_num))?;
@@ -175,6 +191,54 @@ where
 		}
 	}
 
+	/// Simulates the hooks of `blocks` empty blocks on top of a block and logs the weight which
+	/// each pallet consumed.
+	fn simulate_hooks(&self, num: u32, hash: Block::Hash, blocks: u32) -> Result<()> {
+		let version = self
+			.client
+			.runtime_version_at(hash)
+			.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+		if !version.has_api_with(&<dyn HookSimulation<Block>>::ID, |_| true) {
+			return Err("The runtime does not implement the `HookSimulation` api".into())
+		}
+
+		// Called by name, so that the client is not required to implement the api.
+		let encoded = self
+			.client
+			.call_api_at(CallApiAtParams {
+				at: hash,
+				function: "HookSimulation_simulate_hooks",
+				arguments: blocks.encode(),
+				overlayed_changes: &Default::default(),
+				call_context: CallContext::Offchain,
+				recorder: &None,
+				extensions: &Default::default(),
+			})
+			.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+		let weights = Vec::<PalletHookWeights>::decode(&mut &encoded[..])?;
+
+		let idle_total = weights.iter().map(|w| w.on_idle.ref_time() as f64).sum::<f64>();
+		info!(target: LOG_TARGET, "Hooks of {} blocks on top of block {}:", blocks, num);
+		for weight in weights {
+			// The share of all `on_idle` weight that went to this pallet.
+			let percent = if idle_total > 0.0 {
+				weight.on_idle.ref_time() as f64 / idle_total * 100.0
+			} else {
+				0.0
+			};
+			info!(
+				target: LOG_TARGET,
+				"{: <24} on_poll {: >18} ps, on_idle {: >18} ps ({: >6.2}% of all idle weight)",
+				String::from_utf8_lossy(&weight.pallet),
+				weight.on_poll.ref_time().separate_with_commas(),
+				weight.on_idle.ref_time().separate_with_commas(),
+				percent,
+			);
+		}
+
+		Ok(())
+	}
+
 	/// Removes the consensus seal from the block.
 	fn unsealed(&self, block: Block) -> Block {
 		let (mut header, exts) = block.deconstruct();
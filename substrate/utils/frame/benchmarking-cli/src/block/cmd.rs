@@ -21,7 +21,7 @@
 use sc_block_builder::BlockBuilderApi;
 use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
 use sc_client_api::{Backend as ClientBackend, BlockBackend, StorageProvider, UsageProvider};
-use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{traits::Block as BlockT, OpaqueExtrinsic};
 
@@ -55,6 +55,10 @@ use super::bench::{Benchmark, BenchmarkParams};
 /// The percent number is important and indicates how much weight
 /// was used as compared to the consumed weight.
 /// This number should be below 100% for reference hardware.
+///
+/// With `--simulate-hooks <BLOCKS>` the blocks are not benchmarked. Instead, the `on_poll` and
+/// `on_idle` hooks of that many empty blocks are simulated on top of each of them, reporting how
+/// much of the remaining weight went to each pallet.
 #[derive(Debug, Parser)]
 pub struct BlockCmd {
 	#[allow(missing_docs)]
@@ -88,7 +92,8 @@ impl BlockCmd {
 			+ ProvideRuntimeApi<Block>
 			+ StorageProvider<Block, BA>
 			+ UsageProvider<Block>
-			+ HeaderBackend<Block>,
+			+ HeaderBackend<Block>
+			+ CallApiAt<Block>,
 		C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
 	{
 		// Put everything in the benchmark type to have the generic types handy.
@@ -23,6 +23,7 @@ mod machine;
 mod overhead;
 mod pallet;
 mod shared;
+mod slashing;
 mod storage;
 
 pub use block::BlockCmd;
@@ -34,6 +35,7 @@ pub use overhead::{
 };
 pub use pallet::PalletCmd;
 pub use sc_service::BasePath;
+pub use slashing::SlashingCmd;
 pub use storage::StorageCmd;
 
 use sc_cli::{CliConfiguration, DatabaseParams, ImportParams, PruningParams, Result, SharedParams};
@@ -49,6 +51,7 @@ pub enum BenchmarkCmd {
 	Block(BlockCmd),
 	Machine(MachineCmd),
 	Extrinsic(ExtrinsicCmd),
+	Slashing(SlashingCmd),
 }
 
 /// Unwraps a [`BenchmarkCmd`] into its concrete sub-command.
@@ -65,6 +68,7 @@ macro_rules! unwrap_cmd {
 			BenchmarkCmd::Block($cmd) => $code,
 			BenchmarkCmd::Machine($cmd) => $code,
 			BenchmarkCmd::Extrinsic($cmd) => $code,
+			BenchmarkCmd::Slashing($cmd) => $code,
 		}
 	}
 }
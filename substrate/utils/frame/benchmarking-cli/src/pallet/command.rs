@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use super::{
+	regression::{self, Baseline},
 	types::{ComponentRange, ComponentRangeMap},
 	writer, ListOutput, PalletCmd,
 };
@@ -37,12 +38,16 @@ use linked_hash_map::LinkedHashMap;
 use sc_cli::{execution_method_from_cli, ChainSpec, CliConfiguration, Result, SharedParams};
 use sc_client_db::BenchmarkingState;
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
+use sc_runtime_utilities::fetch_latest_metadata_from_code_blob;
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
 use sp_core::{
 	offchain::{
 		testing::{TestOffchainExt, TestTransactionPoolExt},
 		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
 	},
-	traits::{CallContext, CodeExecutor, ReadRuntimeVersionExt, WrappedRuntimeCode},
+	traits::{
+		CallContext, CodeExecutor, FetchRuntimeCode, ReadRuntimeVersionExt, WrappedRuntimeCode,
+	},
 	Hasher,
 };
 use sp_externalities::Extensions;
@@ -53,16 +58,24 @@ use sp_trie::{proof_size_extension::ProofSizeExt, recorder::Recorder};
 use sp_wasm_interface::HostFunctions;
 use std::{
 	borrow::Cow,
-	collections::{BTreeMap, BTreeSet, HashMap},
+	collections::{btree_map::Entry, BTreeMap, BTreeSet, HashMap},
 	fmt::Debug,
 	fs,
 	str::FromStr,
 	time,
 };
+use subxt::metadata::types::{StorageEntryType, StorageHasher};
 
 /// Logging target
 const LOG_TARGET: &'static str = "polkadot_sdk_frame::benchmark::pallet";
 
+/// The length of a dummy key, before hashing, if the key is hashed opaquely and its type has no
+/// fixed encoded length.
+const DUMMY_KEY_DEFAULT_LEN: usize = 32;
+
+/// How deep the type of a dummy value may nest before it is assumed to be recursive.
+const MAX_DUMMY_VALUE_DEPTH: u32 = 32;
+
 type SubstrateAndExtraHF<T> =
 	(sp_io::SubstrateHostFunctions, frame_benchmarking::benchmarking::HostFunctions, T);
 /// How the PoV size of a storage item should be estimated.
@@ -284,7 +297,7 @@ impl PalletCmd {
 		)?;
 
 		let state_without_tracking = BenchmarkingState::<Hasher>::new(
-			genesis_storage,
+			genesis_storage.clone(),
 			cache_size,
 			// Proof recording depends on CLI settings
 			!self.disable_proof_recording,
@@ -359,6 +372,40 @@ impl PalletCmd {
 			return Ok(())
 		}
 
+		// Give every storage map a realistic size, so that the recorded proof reflects the trie
+		// depth of a populated state instead of the mostly empty genesis state. Verification, the
+		// tracking run and the timing runs all see the same dummy entries.
+		let populated_states;
+		let (state_with_tracking, state_without_tracking) = if self.populate_storage_maps > 0 {
+			let code =
+				runtime_code.fetch_runtime_code().ok_or("Could not fetch the runtime code")?;
+			let opaque_metadata = fetch_latest_metadata_from_code_blob(&executor, code)
+				.map_err(|_| "Unable to fetch latest stable metadata")?;
+			let metadata = subxt::Metadata::decode(&mut (*opaque_metadata).as_slice())?;
+
+			let mut storage = genesis_storage;
+			let inserted = populate_storage_maps(
+				&mut storage,
+				&storage_info,
+				&map_layouts(&metadata),
+				self.populate_storage_maps,
+			);
+			log::info!(target: LOG_TARGET, "Populated storage maps with {inserted} dummy keys");
+
+			populated_states = (
+				BenchmarkingState::<Hasher>::new(storage.clone(), cache_size, true, true)?,
+				BenchmarkingState::<Hasher>::new(
+					storage,
+					cache_size,
+					!self.disable_proof_recording,
+					false,
+				)?,
+			);
+			(&populated_states.0, &populated_states.1)
+		} else {
+			(&state_with_tracking, &state_without_tracking)
+		};
+
 		// Run the benchmarks
 		let mut batches = Vec::new();
 		let mut batches_db = Vec::new();
@@ -449,7 +496,7 @@ impl PalletCmd {
 
 				// First we run a verification
 				if !self.no_verify {
-					let state = &state_without_tracking;
+					let state = state_without_tracking;
 					// Don't use these results since verification code will add overhead.
 					let _batch: Vec<BenchmarkBatch> = match Self::exec_state_machine::<
 						std::result::Result<Vec<BenchmarkBatch>, String>,
@@ -483,7 +530,7 @@ impl PalletCmd {
 				}
 				// Do one loop of DB tracking.
 				{
-					let state = &state_with_tracking;
+					let state = state_with_tracking;
 					let batch: Vec<BenchmarkBatch> = match Self::exec_state_machine::<
 						std::result::Result<Vec<BenchmarkBatch>, String>,
 						_,
@@ -518,7 +565,7 @@ impl PalletCmd {
 				}
 				// Finally run a bunch of loops to get extrinsic timing information.
 				for r in 0..self.external_repeat {
-					let state = &state_without_tracking;
+					let state = state_without_tracking;
 					let batch = match Self::exec_state_machine::<
 						std::result::Result<Vec<BenchmarkBatch>, String>,
 						_,
@@ -733,10 +780,25 @@ impl PalletCmd {
 		component_ranges: &ComponentRangeMap,
 		pov_modes: PovModesMap,
 	) -> Result<()> {
+		// Load the baseline before the weight files are written, since they may be the same.
+		let baseline = self.baseline.as_deref().map(Baseline::load).transpose()?;
+
 		// Jsonify the result and write it to a file or stdout if desired.
 		if !self.jsonify(&batches)? && !self.quiet {
 			// Print the summary only if `jsonify` did not write to stdout.
-			self.print_summary(&batches, &storage_info, pov_modes.clone())
+			self.print_summary(&batches, &storage_info, pov_modes.clone());
+
+			if self.populate_storage_maps > 0 {
+				let table = writer::proof_size_table(
+					batches,
+					storage_info,
+					component_ranges,
+					pov_modes.clone(),
+					self.default_pov_mode,
+					self,
+				)?;
+				println!("Estimated vs Measured Proof Size\n========\n{table}\n");
+			}
 		}
 
 		// Create the weights.rs file.
@@ -745,13 +807,67 @@ impl PalletCmd {
 				&batches,
 				&storage_info,
 				&component_ranges,
-				pov_modes,
+				pov_modes.clone(),
 				self.default_pov_mode,
 				output_path,
 				self,
 			)?;
 		}
 
+		if let Some(baseline) = baseline {
+			self.check_regressions(baseline, batches, storage_info, component_ranges, pov_modes)?;
+		}
+
+		Ok(())
+	}
+
+	/// Compare the analyzed results against the `--baseline` and error if any weight regressed
+	/// by more than the `--regression-threshold`.
+	fn check_regressions(
+		&self,
+		baseline: Baseline,
+		batches: &[BenchmarkBatchSplitResults],
+		storage_info: &[StorageInfo],
+		component_ranges: &ComponentRangeMap,
+		pov_modes: PovModesMap,
+	) -> Result<()> {
+		let new = writer::analyze_weights(
+			batches,
+			storage_info,
+			component_ranges,
+			pov_modes.clone(),
+			self.default_pov_mode,
+			self,
+		)?;
+		let old = match baseline {
+			Baseline::Weights(weights) => weights,
+			// Analyze the old results in the same way to make them comparable.
+			Baseline::Results(old_batches) => writer::analyze_weights(
+				&old_batches,
+				storage_info,
+				&Default::default(),
+				pov_modes,
+				self.default_pov_mode,
+				self,
+			)?,
+		};
+
+		let report = regression::compare(&old, &new, self.regression_threshold);
+		if !self.quiet {
+			println!("{report}");
+		}
+		if let Some(path) = &self.regression_report {
+			report.write(path)?;
+		}
+
+		if !report.regressions.is_empty() {
+			return Err(format!(
+				"Found {} weight regression(s) above the threshold of {}%",
+				report.regressions.len(),
+				self.regression_threshold
+			)
+			.into())
+		}
 		Ok(())
 	}
 
@@ -986,6 +1102,25 @@ impl PalletCmd {
 			}
 		}
 
+		if let Some(baseline) = &self.baseline {
+			if !baseline.exists() {
+				return Err((
+					ErrorKind::InvalidValue,
+					format!("Baseline could not be found: {baseline:?}"),
+				));
+			}
+		}
+
+		if !self.regression_threshold.is_finite() || self.regression_threshold < 0.0 {
+			return Err((
+				ErrorKind::InvalidValue,
+				format!(
+					"Regression threshold must be a non-negative percentage: {}",
+					self.regression_threshold
+				),
+			));
+		}
+
 		if let Some(header_file) = &self.header {
 			if !header_file.is_file() {
 				return Err((
@@ -1059,10 +1194,229 @@ fn list_benchmark(
 		},
 	}
 }
+/// How the dummy entries of a storage map are built.
+struct MapLayout {
+	/// The hasher and the encoded length of every key of the map.
+	keys: Vec<(StorageHasher, usize)>,
+	/// The shortest encoding of a value of the map.
+	value: Vec<u8>,
+	/// The storage key of the counter, if the map is a `CountedStorageMap`.
+	counter: Option<Vec<u8>>,
+}
+
+/// The layout of every storage map which can be populated, by the prefix of the map.
+type MapLayouts = BTreeMap<Vec<u8>, MapLayout>;
+
+/// Reads how the entries of every storage map are built from the runtime metadata.
+///
+/// Maps are left out if a dummy entry would not decode: if a key which is stored in the clear
+/// (`Identity` or a `*Concat` hasher) does not decode from every byte string of its length, or if
+/// the value type has no encoding at all.
+fn map_layouts(metadata: &subxt::Metadata) -> MapLayouts {
+	let types = metadata.types();
+	let mut layouts = MapLayouts::new();
+	for storage in metadata.pallets().filter_map(|pallet| pallet.storage()) {
+		let pallet_prefix = sp_crypto_hashing::twox_128(storage.prefix().as_bytes());
+		let storage_key = |name: &str| {
+			[&pallet_prefix[..], &sp_crypto_hashing::twox_128(name.as_bytes())].concat()
+		};
+		'entries: for entry in storage.entries() {
+			let StorageEntryType::Map { hashers, key_ty, value_ty } = entry.entry_type() else {
+				continue
+			};
+			// A map with several keys has a tuple of all of them as its key type.
+			let key_tys = match types.resolve(*key_ty).map(|ty| &ty.type_def) {
+				Some(TypeDef::Tuple(tuple)) if hashers.len() > 1 =>
+					tuple.fields.iter().map(|field| field.id).collect(),
+				_ => vec![*key_ty],
+			};
+			let mut keys = Vec::new();
+			for (hasher, key_ty) in hashers.iter().zip(key_tys) {
+				let len = match (any_bytes_len(types, key_ty), hasher) {
+					(Some(len), _) => len,
+					(
+						None,
+						StorageHasher::Blake2_128 |
+						StorageHasher::Blake2_256 |
+						StorageHasher::Twox128 |
+						StorageHasher::Twox256,
+					) => DUMMY_KEY_DEFAULT_LEN,
+					(None, _) => {
+						log::debug!(
+							target: LOG_TARGET,
+							"Not populating {}::{}, its dummy keys would not decode",
+							storage.prefix(),
+							entry.name(),
+						);
+						continue 'entries
+					},
+				};
+				keys.push((hasher.clone(), len));
+			}
+			let Some(value) = shortest_encoding(types, *value_ty, 0) else {
+				log::debug!(
+					target: LOG_TARGET,
+					"Not populating {}::{}, its value type has no encoding",
+					storage.prefix(),
+					entry.name(),
+				);
+				continue
+			};
+			let counter_name = format!("CounterFor{}", entry.name());
+			let counter = storage.entry_by_name(&counter_name).map(|_| storage_key(&counter_name));
+			layouts.insert(storage_key(entry.name()), MapLayout { keys, value, counter });
+		}
+	}
+	layouts
+}
+
+/// The encoded length of the type `id`, if every byte string of that length decodes to it.
+fn any_bytes_len(types: &PortableRegistry, id: u32) -> Option<usize> {
+	match &types.resolve(id)?.type_def {
+		TypeDef::Primitive(primitive) => match primitive {
+			TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => Some(1),
+			TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => Some(2),
+			TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => Some(4),
+			TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => Some(8),
+			TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => Some(16),
+			TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => Some(32),
+			TypeDefPrimitive::Bool | TypeDefPrimitive::Char | TypeDefPrimitive::Str => None,
+		},
+		TypeDef::Array(array) =>
+			Some(array.len as usize * any_bytes_len(types, array.type_param.id)?),
+		TypeDef::Composite(composite) =>
+			composite.fields.iter().map(|field| any_bytes_len(types, field.ty.id)).sum(),
+		TypeDef::Tuple(tuple) =>
+			tuple.fields.iter().map(|field| any_bytes_len(types, field.id)).sum(),
+		TypeDef::Variant(_) |
+		TypeDef::Sequence(_) |
+		TypeDef::Compact(_) |
+		TypeDef::BitSequence(_) => None,
+	}
+}
+
+/// The shortest encoding of a value of the type `id`, if the type has any values.
+///
+/// Numbers are zero, sequences are empty and enums take their first variant which can be encoded.
+/// Types which nest deeper than `MAX_DUMMY_VALUE_DEPTH` are treated as having no values.
+fn shortest_encoding(types: &PortableRegistry, id: u32, depth: u32) -> Option<Vec<u8>> {
+	if depth > MAX_DUMMY_VALUE_DEPTH {
+		return None
+	}
+	let concat = |ids: &mut dyn Iterator<Item = u32>| {
+		ids.map(|id| shortest_encoding(types, id, depth + 1))
+			.collect::<Option<Vec<_>>>()
+			.map(|parts| parts.concat())
+	};
+	match &types.resolve(id)?.type_def {
+		TypeDef::Primitive(primitive) => {
+			let len = match primitive {
+				TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+				TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+				TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+				TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+				TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+				TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+				// The compact encoded length of the empty string.
+				TypeDefPrimitive::Str => 1,
+			};
+			Some(vec![0; len])
+		},
+		TypeDef::Array(array) => Some(
+			shortest_encoding(types, array.type_param.id, depth + 1)?.repeat(array.len as usize),
+		),
+		TypeDef::Composite(composite) =>
+			concat(&mut composite.fields.iter().map(|field| field.ty.id)),
+		TypeDef::Tuple(tuple) => concat(&mut tuple.fields.iter().map(|field| field.id)),
+		TypeDef::Variant(variant) => variant.variants.iter().find_map(|variant| {
+			let fields = concat(&mut variant.fields.iter().map(|field| field.ty.id))?;
+			Some([vec![variant.index], fields].concat())
+		}),
+		// An empty sequence and a compact zero are both encoded as a single zero byte.
+		TypeDef::Sequence(_) | TypeDef::Compact(_) | TypeDef::BitSequence(_) => Some(vec![0]),
+	}
+}
+
+/// Builds the `index`th dummy key of the map `prefix`, hashing every key like the map does.
+///
+/// Every key is the little endian `index`, cut or zero padded to the encoded length of its type.
+fn dummy_map_key(prefix: &[u8], layout: &[(StorageHasher, usize)], index: u32) -> Vec<u8> {
+	let mut key = prefix.to_vec();
+	for (hasher, len) in layout {
+		let mut raw = index.to_le_bytes().to_vec();
+		raw.resize(*len, 0);
+		match hasher {
+			StorageHasher::Blake2_128 => key.extend(sp_crypto_hashing::blake2_128(&raw)),
+			StorageHasher::Blake2_256 => key.extend(sp_crypto_hashing::blake2_256(&raw)),
+			StorageHasher::Blake2_128Concat => {
+				key.extend(sp_crypto_hashing::blake2_128(&raw));
+				key.extend(raw);
+			},
+			StorageHasher::Twox128 => key.extend(sp_crypto_hashing::twox_128(&raw)),
+			StorageHasher::Twox256 => key.extend(sp_crypto_hashing::twox_256(&raw)),
+			StorageHasher::Twox64Concat => {
+				key.extend(sp_crypto_hashing::twox_64(&raw));
+				key.extend(raw);
+			},
+			StorageHasher::Identity => key.extend(raw),
+		}
+	}
+	key
+}
+
+/// Inserts `keys` dummy entries into every storage map that is listed in `storage_info`.
+///
+/// The entries are built as described by `layouts`, so that they decode like real ones, and the
+/// counter of a `CountedStorageMap` is raised by the number of inserted entries. Maps which are
+/// not described there are left alone, and so are keys which already exist in `storage`. Returns
+/// the number of inserted keys.
+fn populate_storage_maps(
+	storage: &mut sp_storage::Storage,
+	storage_info: &[StorageInfo],
+	layouts: &MapLayouts,
+	keys: u32,
+) -> usize {
+	// Maps are identified by their two 16 byte prefix hashes. Storage values have at most one.
+	let maps = storage_info
+		.iter()
+		.filter(|info| info.prefix.len() == 32 && info.max_values != Some(1))
+		.map(|info| (info.prefix.clone(), info))
+		.collect::<BTreeMap<_, _>>();
+
+	let mut inserted = 0;
+	for (prefix, info) in maps {
+		let Some(layout) = layouts.get(&prefix) else { continue };
+		let mut inserted_here = 0u32;
+		for index in 0..info.max_values.map_or(keys, |max| max.min(keys)) {
+			let key = dummy_map_key(&prefix, &layout.keys, index);
+			if let Entry::Vacant(entry) = storage.top.entry(key) {
+				entry.insert(layout.value.clone());
+				inserted_here += 1;
+			}
+		}
+		if let Some(counter) = layout.counter.as_ref().filter(|_| inserted_here > 0) {
+			let count = storage
+				.top
+				.get(counter)
+				.and_then(|count| u32::decode(&mut &count[..]).ok())
+				.unwrap_or_default();
+			storage
+				.top
+				.insert(counter.clone(), count.saturating_add(inserted_here).encode());
+		}
+		inserted += inserted_here as usize;
+	}
+	inserted
+}
+
 #[cfg(test)]
 mod tests {
+	use super::{populate_storage_maps, MapLayout, MapLayouts};
 	use crate::pallet::PalletCmd;
 	use clap::Parser;
+	use codec::Encode;
+	use frame_support::traits::StorageInfo;
+	use subxt::metadata::types::StorageHasher;
 
 	fn cli_succeed(args: &[&str]) -> Result<(), clap::Error> {
 		let cmd = PalletCmd::try_parse_from(args)?;
@@ -1221,4 +1575,100 @@ mod tests {
 		]);
 		Ok(())
 	}
+
+	#[test]
+	fn populate_storage_maps_works() {
+		let info = |prefix: &[u8], max_values, max_size| StorageInfo {
+			pallet_name: b"Pallet".to_vec(),
+			storage_name: prefix.to_vec(),
+			prefix: prefix.to_vec(),
+			max_values,
+			max_size,
+		};
+		let storage_info = vec![
+			// A storage value is not populated.
+			info(&[1; 32], Some(1), Some(4)),
+			// An unbounded map.
+			info(&[2; 32], None, None),
+			// A map with at most 3 values.
+			info(&[3; 32], Some(3), Some(64)),
+			// A whole pallet without storage info.
+			info(&[4; 16], None, None),
+			// A map which is not in the metadata.
+			info(&[5; 32], None, None),
+		];
+		let counter = vec![6; 32];
+		let layouts = MapLayouts::from([
+			(
+				vec![2; 32],
+				MapLayout {
+					keys: vec![(StorageHasher::Blake2_128Concat, 32)],
+					value: vec![0],
+					counter: None,
+				},
+			),
+			(
+				vec![3; 32],
+				MapLayout {
+					keys: vec![(StorageHasher::Twox64Concat, 4), (StorageHasher::Identity, 2)],
+					value: vec![1, 0, 0],
+					counter: Some(counter.clone()),
+				},
+			),
+		]);
+
+		let mut storage = sp_storage::Storage::default();
+		// A real key is kept as it is.
+		let real_key =
+			[&[3; 32][..], &sp_crypto_hashing::twox_64(&[1, 0, 0, 0]), &[1, 0, 0, 0, 1, 0]]
+				.concat();
+		storage.top.insert(real_key.clone(), vec![1]);
+		storage.top.insert(counter.clone(), 1u32.encode());
+		assert_eq!(populate_storage_maps(&mut storage, &storage_info, &layouts, 5), 7);
+		assert_eq!(storage.top.len(), 9);
+		assert_eq!(storage.top.get(&real_key), Some(&vec![1]));
+		// The counter of the map includes the inserted keys.
+		assert_eq!(storage.top.get(&counter), Some(&3u32.encode()));
+
+		let under = |prefix: [u8; 32]| {
+			storage.top.iter().filter(|(k, _)| k.starts_with(&prefix)).collect::<Vec<_>>()
+		};
+		assert!(under([1; 32]).is_empty());
+		assert_eq!(under([2; 32]).len(), 5);
+		assert!(under([2; 32]).iter().all(|(k, v)| k.len() == 32 + 16 + 32 && **v == vec![0]));
+		assert_eq!(under([3; 32]).len(), 3);
+		assert!(under([3; 32]).iter().all(|(k, _)| k.len() == 32 + 8 + 4 + 2));
+		assert!(under([5; 32]).is_empty());
+
+		// Every key is hashed like the map hashes it.
+		let key = [&[3; 32][..], &sp_crypto_hashing::twox_64(&[2, 0, 0, 0]), &[2, 0, 0, 0, 2, 0]]
+			.concat();
+		assert_eq!(storage.top.get(&key), Some(&vec![1, 0, 0]));
+	}
+
+	#[test]
+	fn test_cli_regression_args() -> Result<(), clap::Error> {
+		let baseline = env!("CARGO_MANIFEST_DIR");
+		let args = ["test", "--extrinsic", "", "--pallet", "", "--runtime", "path/to/runtime"];
+
+		cli_succeed(&[&args[..], &["--baseline", baseline]].concat())?;
+		cli_succeed(
+			&[
+				&args[..],
+				&[
+					"--baseline",
+					baseline,
+					"--regression-threshold",
+					"5",
+					"--regression-report",
+					"r.md",
+				],
+			]
+			.concat(),
+		)?;
+		cli_fail(&[&args[..], &["--baseline", "path/to/missing/baseline"]].concat());
+		cli_fail(&[&args[..], &["--baseline", baseline, "--regression-threshold", "-1"]].concat());
+		cli_fail(&[&args[..], &["--regression-report", "report.md"]].concat());
+		Ok(())
+	}
 }
@@ -16,6 +16,7 @@
 // limitations under the License.
 
 mod command;
+mod regression;
 mod types;
 mod writer;
 
@@ -237,12 +238,42 @@ pub struct PalletCmd {
 	#[clap(long, default_value = "2")]
 	pub additional_trie_layers: u8,
 
+	/// Pre-populate every storage map with this many dummy keys before benchmarking.
+	///
+	/// This gives the state a realistic size, so that the proof size that is recorded during the
+	/// benchmarks reflects the actual trie depth instead of the mostly empty genesis state. The
+	/// estimated and the measured proof size are then printed side by side. The dummy entries are
+	/// built from the runtime metadata: keys are hashed like the real keys of each map, values are
+	/// the shortest valid encoding of the value type, and the counters of counted maps include
+	/// them. Every run of a benchmark sees the same entries, so benchmarks which iterate a map
+	/// iterate them too. Maps whose dummy keys could not be decoded when iterated are left empty.
+	#[arg(long, default_value_t = 0)]
+	pub populate_storage_maps: u32,
+
 	/// A path to a `.json` file with existing benchmark results generated with `--json` or
 	/// `--json-file`. When specified the benchmarks are not actually executed, and the data for
 	/// the analysis is read from this file.
 	#[arg(long)]
 	pub json_input: Option<PathBuf>,
 
+	/// Compare the results against a baseline and fail if any weight regressed.
+	///
+	/// The baseline is either a `.json` file generated with `--json-file`, a weight file
+	/// generated by this command or a directory containing such weight files. The base weight
+	/// and every component slope of each benchmark is compared separately.
+	#[arg(long)]
+	pub baseline: Option<PathBuf>,
+
+	/// Relative increase in percent above which a weight counts as a regression.
+	#[arg(long, default_value_t = 10.0)]
+	pub regression_threshold: f64,
+
+	/// Write the regression report to the given file.
+	///
+	/// The report is written as JSON if the file ends with `.json` and as Markdown otherwise.
+	#[arg(long, requires = "baseline")]
+	pub regression_report: Option<PathBuf>,
+
 	/// Allow overwriting a single file with multiple results.
 	///
 	/// This exists only to restore legacy behaviour. It should never actually be needed.
@@ -0,0 +1,480 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Compares benchmark results against a baseline to detect weight regressions.
+
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap},
+	fmt, fs,
+	path::Path,
+};
+
+use frame_benchmarking::BenchmarkBatchSplitResults;
+use serde::Serialize;
+
+/// The costs of a benchmark, either as a base value or as the slope of one component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Costs {
+	pub ref_time: u128,
+	pub reads: u128,
+	pub writes: u128,
+	pub proof_size: u128,
+}
+
+impl Costs {
+	fn get(&self, metric: Metric) -> u128 {
+		match metric {
+			Metric::RefTime => self.ref_time,
+			Metric::Reads => self.reads,
+			Metric::Writes => self.writes,
+			Metric::ProofSize => self.proof_size,
+		}
+	}
+}
+
+/// The weight formula of a single benchmark.
+///
+/// This is what ends up in the generated weight file: a base cost plus a slope per component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct BenchmarkWeights {
+	pub pallet: String,
+	pub benchmark: String,
+	pub base: Costs,
+	pub components: BTreeMap<String, Costs>,
+}
+
+/// A single dimension of a weight formula that is checked for regressions.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Metric {
+	RefTime,
+	Reads,
+	Writes,
+	ProofSize,
+}
+
+impl Metric {
+	const ALL: [Metric; 4] = [Metric::RefTime, Metric::Reads, Metric::Writes, Metric::ProofSize];
+}
+
+impl fmt::Display for Metric {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Metric::RefTime => write!(f, "ref_time"),
+			Metric::Reads => write!(f, "reads"),
+			Metric::Writes => write!(f, "writes"),
+			Metric::ProofSize => write!(f, "proof_size"),
+		}
+	}
+}
+
+/// The results that new benchmark results are compared against.
+pub(crate) enum Baseline {
+	/// Weight formulas that were parsed from existing weight files.
+	Weights(Vec<BenchmarkWeights>),
+	/// Raw results from a previous `--json-file` run which still need to be analyzed.
+	Results(Vec<BenchmarkBatchSplitResults>),
+}
+
+impl Baseline {
+	/// Load the baseline from a `.json` file, a weight file or a directory of weight files.
+	pub(crate) fn load(path: &Path) -> Result<Self, sc_cli::Error> {
+		if path.is_dir() {
+			let mut weights = Vec::new();
+			let mut entries = fs::read_dir(path)?
+				.map(|entry| entry.map(|e| e.path()))
+				.collect::<Result<Vec<_>, _>>()?;
+			entries.sort();
+			for file in entries {
+				if file.is_file() && file.extension().map_or(false, |e| e == "rs") {
+					weights.extend(parse_weight_file(&file)?);
+				}
+			}
+			return Ok(Self::Weights(weights))
+		}
+
+		if path.extension().map_or(false, |e| e == "json") {
+			let raw_data =
+				fs::read(path).map_err(|e| format!("Failed to read baseline {:?}: {}", path, e))?;
+			let batches = serde_json::from_slice(&raw_data)
+				.map_err(|e| format!("Failed to deserialize baseline {:?}: {}", path, e))?;
+			return Ok(Self::Results(batches))
+		}
+
+		Ok(Self::Weights(parse_weight_file(path)?))
+	}
+}
+
+/// Parse the weight formulas of a weight file that was generated with the default template.
+fn parse_weight_file(path: &Path) -> Result<Vec<BenchmarkWeights>, sc_cli::Error> {
+	let content = fs::read_to_string(path)
+		.map_err(|e| format!("Failed to read baseline {:?}: {}", path, e))?;
+	let fallback = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+	Ok(parse_weights(&content, &fallback))
+}
+
+/// Parse the weight formulas out of the content of a weight file.
+///
+/// The pallet name is taken from the file header and `fallback_pallet` is used if there is none.
+/// Only the first implementation of each function is considered, so the `()` implementation that
+/// pallets put into their own weight files is ignored.
+fn parse_weights(content: &str, fallback_pallet: &str) -> Vec<BenchmarkWeights> {
+	let pallet = content
+		.lines()
+		.find_map(|line| line.trim().strip_prefix("//! Autogenerated weights for "))
+		.map(|name| name.trim().trim_matches('`').to_string())
+		.unwrap_or_else(|| fallback_pallet.to_string());
+
+	let mut parsed = Vec::<BenchmarkWeights>::new();
+	let mut current: Option<BenchmarkWeights> = None;
+
+	for line in content.lines().map(str::trim) {
+		if let Some(signature) = line.strip_prefix("fn ") {
+			if line.contains("-> Weight") {
+				let name = signature.split('(').next().unwrap_or_default().trim().to_string();
+				current = Some(BenchmarkWeights {
+					pallet: pallet.clone(),
+					benchmark: name,
+					..Default::default()
+				});
+			}
+			continue
+		}
+
+		let Some(weights) = current.as_mut() else { continue };
+		if line == "}" {
+			if !parsed.iter().any(|w| w.benchmark == weights.benchmark) {
+				parsed.extend(current.take());
+			}
+			current = None;
+			continue
+		}
+		if line.starts_with("//") {
+			continue
+		}
+
+		let costs = match line.split(".saturating_mul(").nth(1) {
+			Some(component) => {
+				let name = component.split(".into()").next().unwrap_or_default().trim();
+				weights.components.entry(name.to_string()).or_default()
+			},
+			None => &mut weights.base,
+		};
+		if let Some(args) = call_args(line, "Weight::from_parts(") {
+			let mut args = args.split(',').map(parse_number);
+			costs.ref_time += args.next().flatten().unwrap_or_default();
+			costs.proof_size += args.next().flatten().unwrap_or_default();
+		} else if let Some(args) = call_args(line, ".reads(") {
+			costs.reads += parse_number(args).unwrap_or_default();
+		} else if let Some(args) = call_args(line, ".writes(") {
+			costs.writes += parse_number(args).unwrap_or_default();
+		}
+	}
+
+	parsed
+}
+
+// Returns the arguments of the first call to `function` in `line`, without any nested calls.
+fn call_args<'a>(line: &'a str, function: &str) -> Option<&'a str> {
+	let args = line.split(function).nth(1)?.trim_start_matches('(');
+	args.split(')').next()
+}
+
+// Parses a number literal like `1_000_u64`.
+fn parse_number(literal: &str) -> Option<u128> {
+	let digits = literal.trim().trim_end_matches("u64").replace('_', "");
+	digits.parse().ok()
+}
+
+/// A single weight dimension that grew by more than the threshold.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub(crate) struct Regression {
+	pub pallet: String,
+	pub benchmark: String,
+	/// The component whose slope regressed, or `None` for the base weight.
+	pub component: Option<String>,
+	pub metric: Metric,
+	pub old: u128,
+	pub new: u128,
+	/// The relative change in percent, or `None` if the old value was zero.
+	pub change: Option<f64>,
+}
+
+/// The result of comparing benchmark results against a baseline.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub(crate) struct RegressionReport {
+	/// The threshold in percent that was used.
+	pub threshold: f64,
+	/// How many benchmarks were present in the baseline and compared.
+	pub compared: usize,
+	/// Benchmarks that are not part of the baseline and could therefore not be compared.
+	pub new_benchmarks: Vec<String>,
+	pub regressions: Vec<Regression>,
+}
+
+/// Compare `new` against `baseline` and collect everything that grew by more than `threshold`
+/// percent.
+///
+/// Base weights and component slopes are compared separately, so a benchmark that got cheaper
+/// overall can still report a regression of a single component. A value that was zero in the
+/// baseline counts as regressed as soon as it is non-zero.
+pub(crate) fn compare(
+	baseline: &[BenchmarkWeights],
+	new: &[BenchmarkWeights],
+	threshold: f64,
+) -> RegressionReport {
+	let baseline = baseline
+		.iter()
+		.map(|w| ((w.pallet.as_str(), w.benchmark.as_str()), w))
+		.collect::<HashMap<_, _>>();
+	let mut report = RegressionReport {
+		threshold,
+		compared: 0,
+		new_benchmarks: Vec::new(),
+		regressions: Vec::new(),
+	};
+
+	for new in new {
+		let Some(old) = baseline.get(&(new.pallet.as_str(), new.benchmark.as_str())) else {
+			report.new_benchmarks.push(format!("{}::{}", new.pallet, new.benchmark));
+			continue
+		};
+		report.compared += 1;
+
+		let components =
+			old.components.keys().chain(new.components.keys()).collect::<BTreeSet<_>>();
+		let costs =
+			std::iter::once((None, old.base, new.base)).chain(components.into_iter().map(|name| {
+				let old = old.components.get(name).copied().unwrap_or_default();
+				let new = new.components.get(name).copied().unwrap_or_default();
+				(Some(name.clone()), old, new)
+			}));
+
+		for (component, old_costs, new_costs) in costs {
+			for metric in Metric::ALL {
+				let (old_value, new_value) = (old_costs.get(metric), new_costs.get(metric));
+				if new_value <= old_value {
+					continue
+				}
+				let change = (old_value != 0)
+					.then(|| (new_value - old_value) as f64 / old_value as f64 * 100.0);
+				if change.map_or(true, |change| change > threshold) {
+					report.regressions.push(Regression {
+						pallet: new.pallet.clone(),
+						benchmark: new.benchmark.clone(),
+						component: component.clone(),
+						metric,
+						old: old_value,
+						new: new_value,
+						change,
+					});
+				}
+			}
+		}
+	}
+
+	report
+}
+
+impl RegressionReport {
+	/// Write the report as JSON if `path` has a `.json` extension and as Markdown otherwise.
+	pub(crate) fn write(&self, path: &Path) -> Result<(), sc_cli::Error> {
+		let content = if path.extension().map_or(false, |e| e == "json") {
+			serde_json::to_string_pretty(self)
+				.map_err(|e| format!("Serializing into JSON: {:?}", e))?
+		} else {
+			self.to_string()
+		};
+		fs::write(path, content)
+			.map_err(|e| format!("Could not write regression report to {:?}: {}", path, e))?;
+		Ok(())
+	}
+}
+
+impl fmt::Display for RegressionReport {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		writeln!(f, "## Weight Regressions\n")?;
+		writeln!(
+			f,
+			"Compared {} benchmark(s) against the baseline with a threshold of {}%.\n",
+			self.compared, self.threshold
+		)?;
+
+		if self.regressions.is_empty() {
+			writeln!(f, "No regressions found.")?;
+		} else {
+			writeln!(f, "| Pallet | Benchmark | Component | Metric | Baseline | New | Change |")?;
+			writeln!(f, "|--------|-----------|-----------|--------|----------|-----|--------|")?;
+			for r in &self.regressions {
+				let change = r.change.map_or("new".into(), |change| format!("+{change:.2}%"));
+				writeln!(
+					f,
+					"| {} | {} | {} | {} | {} | {} | {} |",
+					r.pallet,
+					r.benchmark,
+					r.component.as_deref().unwrap_or("base"),
+					r.metric,
+					r.old,
+					r.new,
+					change,
+				)?;
+			}
+		}
+
+		if !self.new_benchmarks.is_empty() {
+			writeln!(f, "\nBenchmarks without a baseline:\n")?;
+			for benchmark in &self.new_benchmarks {
+				writeln!(f, "- `{}`", benchmark)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const WEIGHT_FILE: &str = r#"
+//! Autogenerated weights for `pallet_example`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+
+/// Weight functions for `pallet_example`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_example::WeightInfo for WeightInfo<T> {
+	/// Storage: `Example::Value` (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_value(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (32 ±0)`
+		//  Estimated: `1493 + n * (32 ±0)`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_224_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(12_500, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(n.into()))
+	}
+	fn noop() -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
+}
+
+impl WeightInfo for () {
+	fn noop() -> Weight {
+		Weight::from_parts(7_000, 0)
+	}
+}
+"#;
+
+	fn weights(benchmark: &str, base: u128, slope: Option<u128>) -> BenchmarkWeights {
+		BenchmarkWeights {
+			pallet: "pallet_example".into(),
+			benchmark: benchmark.into(),
+			base: Costs { ref_time: base, reads: 1, ..Default::default() },
+			components: slope
+				.map(|slope| ("n".to_string(), Costs { ref_time: slope, ..Default::default() }))
+				.into_iter()
+				.collect(),
+		}
+	}
+
+	#[test]
+	fn parse_weights_works() {
+		let parsed = parse_weights(WEIGHT_FILE, "fallback");
+		assert_eq!(parsed.len(), 2);
+
+		let set_value = &parsed[0];
+		assert_eq!(set_value.pallet, "pallet_example");
+		assert_eq!(set_value.benchmark, "set_value");
+		assert_eq!(
+			set_value.base,
+			Costs { ref_time: 5_224_000, reads: 1, writes: 1, proof_size: 1493 }
+		);
+		assert_eq!(
+			set_value.components.get("n"),
+			Some(&Costs { ref_time: 12_500, reads: 2, writes: 0, proof_size: 32 })
+		);
+
+		// The `()` implementation is ignored.
+		assert_eq!(parsed[1].benchmark, "noop");
+		assert_eq!(parsed[1].base.ref_time, 1_000);
+		assert!(parsed[1].components.is_empty());
+	}
+
+	#[test]
+	fn parse_weights_uses_fallback_pallet() {
+		let parsed =
+			parse_weights("fn noop() -> Weight {\n Weight::from_parts(1, 2)\n}", "fallback");
+		assert_eq!(parsed[0].pallet, "fallback");
+		assert_eq!(parsed[0].base, Costs { ref_time: 1, proof_size: 2, ..Default::default() });
+	}
+
+	#[test]
+	fn compare_respects_threshold() {
+		let baseline = vec![weights("a", 1_000, Some(100)), weights("b", 1_000, None)];
+		let new = vec![weights("a", 1_099, Some(111)), weights("b", 1_200, None)];
+
+		let report = compare(&baseline, &new, 10.0);
+		assert_eq!(report.compared, 2);
+		assert_eq!(report.regressions.len(), 2);
+
+		// The slope of `a` regressed but its base stayed within the threshold.
+		let slope = &report.regressions[0];
+		assert_eq!((slope.benchmark.as_str(), slope.component.as_deref()), ("a", Some("n")));
+		assert_eq!((slope.metric, slope.old, slope.new), (Metric::RefTime, 100, 111));
+		assert!((slope.change.unwrap() - 11.0).abs() < 1e-9);
+
+		let base = &report.regressions[1];
+		assert_eq!((base.benchmark.as_str(), base.component.as_deref()), ("b", None));
+		assert!((base.change.unwrap() - 20.0).abs() < 1e-9);
+
+		// A higher threshold accepts both.
+		assert!(compare(&baseline, &new, 25.0).regressions.is_empty());
+	}
+
+	#[test]
+	fn compare_reports_new_components_and_benchmarks() {
+		let baseline = vec![weights("a", 1_000, None)];
+		let new = vec![weights("a", 900, Some(5)), weights("b", 1_000, None)];
+
+		let report = compare(&baseline, &new, 10.0);
+		assert_eq!(report.compared, 1);
+		assert_eq!(report.new_benchmarks, vec!["pallet_example::b".to_string()]);
+		assert_eq!(
+			report.regressions,
+			vec![Regression {
+				pallet: "pallet_example".into(),
+				benchmark: "a".into(),
+				component: Some("n".into()),
+				metric: Metric::RefTime,
+				old: 0,
+				new: 5,
+				change: None,
+			}]
+		);
+
+		let markdown = report.to_string();
+		assert!(markdown.contains("| pallet_example | a | n | ref_time | 0 | 5 | new |"));
+		assert!(markdown.contains("- `pallet_example::b`"));
+	}
+}
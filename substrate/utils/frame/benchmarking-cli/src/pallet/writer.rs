@@ -18,7 +18,7 @@
 // Outputs benchmark results to Rust files that can be ingested by the runtime.
 
 use std::{
-	collections::{HashMap, HashSet},
+	collections::{BTreeMap, HashMap, HashSet},
 	fs,
 	path::PathBuf,
 };
@@ -30,6 +30,7 @@ use serde::Serialize;
 use crate::{
 	pallet::{
 		command::{PovEstimationMode, PovModesMap},
+		regression::{BenchmarkWeights, Costs},
 		types::{ComponentRange, ComponentRangeMap},
 	},
 	shared::UnderscoreHelper,
@@ -376,6 +377,162 @@ fn get_benchmark_data(
 	}
 }
 
+// Calls `map_results` with the analysis settings of `cmd`.
+fn map_results_with_cmd(
+	batches: &[BenchmarkBatchSplitResults],
+	storage_info: &[StorageInfo],
+	component_ranges: &ComponentRangeMap,
+	pov_modes: PovModesMap,
+	default_pov_mode: PovEstimationMode,
+	cmd: &PalletCmd,
+) -> Result<HashMap<(String, String), Vec<BenchmarkData>>, std::io::Error> {
+	let analysis_choice: AnalysisChoice =
+		cmd.output_analysis.clone().try_into().map_err(io_error)?;
+	let pov_analysis_choice: AnalysisChoice =
+		cmd.output_pov_analysis.clone().try_into().map_err(io_error)?;
+
+	map_results(
+		batches,
+		storage_info,
+		component_ranges,
+		pov_modes,
+		default_pov_mode,
+		&analysis_choice,
+		&pov_analysis_choice,
+		cmd.worst_case_map_values,
+		cmd.additional_trie_layers,
+	)
+}
+
+/// Analyze the batches like [`write_results`] does and return the weight formula of each
+/// benchmark, as it would be written into the weight file.
+pub(crate) fn analyze_weights(
+	batches: &[BenchmarkBatchSplitResults],
+	storage_info: &[StorageInfo],
+	component_ranges: &ComponentRangeMap,
+	pov_modes: PovModesMap,
+	default_pov_mode: PovEstimationMode,
+	cmd: &PalletCmd,
+) -> Result<Vec<BenchmarkWeights>, sc_cli::Error> {
+	let all_results = map_results_with_cmd(
+		batches,
+		storage_info,
+		component_ranges,
+		pov_modes,
+		default_pov_mode,
+		cmd,
+	)?;
+
+	let mut weights = Vec::new();
+	for ((pallet, _instance), benchmarks) in all_results {
+		for benchmark in benchmarks {
+			let mut components = BTreeMap::<String, Costs>::new();
+			for slope in &benchmark.component_weight {
+				components.entry(slope.name.clone()).or_default().ref_time = slope.slope;
+			}
+			for slope in &benchmark.component_reads {
+				components.entry(slope.name.clone()).or_default().reads = slope.slope;
+			}
+			for slope in &benchmark.component_writes {
+				components.entry(slope.name.clone()).or_default().writes = slope.slope;
+			}
+			for slope in &benchmark.component_calculated_proof_size {
+				components.entry(slope.name.clone()).or_default().proof_size = slope.slope;
+			}
+
+			weights.push(BenchmarkWeights {
+				pallet: pallet.clone(),
+				benchmark: benchmark.name,
+				base: Costs {
+					ref_time: benchmark.base_weight,
+					reads: benchmark.base_reads,
+					writes: benchmark.base_writes,
+					proof_size: benchmark.base_calculated_proof_size,
+				},
+				components,
+			});
+		}
+	}
+	weights.sort_by(|a, b| (&a.pallet, &a.benchmark).cmp(&(&b.pallet, &b.benchmark)));
+	Ok(weights)
+}
+
+/// Tabulate the estimated and the measured proof size of each benchmark side by side.
+///
+/// The estimate is what ends up in the weight file, while the measured proof size is what the
+/// trie recorder saw while executing the benchmark.
+pub(crate) fn proof_size_table(
+	batches: &[BenchmarkBatchSplitResults],
+	storage_info: &[StorageInfo],
+	component_ranges: &ComponentRangeMap,
+	pov_modes: PovModesMap,
+	default_pov_mode: PovEstimationMode,
+	cmd: &PalletCmd,
+) -> Result<comfy_table::Table, sc_cli::Error> {
+	let all_results = map_results_with_cmd(
+		batches,
+		storage_info,
+		component_ranges,
+		pov_modes,
+		default_pov_mode,
+		cmd,
+	)?;
+
+	let mut table = comfy_table::Table::new();
+	table.set_header(["Pallet", "Benchmark", "Component", "Estimated", "Measured", "Difference"]);
+	for ((pallet, _instance), benchmarks) in all_results.iter().sorted_by_key(|(key, _)| *key) {
+		for benchmark in benchmarks {
+			for (component, estimated, measured) in proof_size_rows(benchmark) {
+				table.add_row([
+					pallet.clone(),
+					benchmark.name.clone(),
+					component,
+					estimated.to_string(),
+					measured.to_string(),
+					relative_difference(estimated, measured),
+				]);
+			}
+		}
+	}
+	Ok(table)
+}
+
+// Pairs up the estimated and measured base proof size and the slope of every component.
+fn proof_size_rows(benchmark: &BenchmarkData) -> Vec<(String, u128, u128)> {
+	let slope_of = |slopes: &[ComponentSlope], name: &str| {
+		slopes.iter().find(|s| s.name == name).map_or(0, |s| s.slope)
+	};
+	let names = benchmark
+		.component_calculated_proof_size
+		.iter()
+		.chain(benchmark.component_recorded_proof_size.iter())
+		.map(|s| s.name.as_str())
+		.sorted()
+		.dedup();
+
+	std::iter::once((
+		"base".to_string(),
+		benchmark.base_calculated_proof_size,
+		benchmark.base_recorded_proof_size,
+	))
+	.chain(names.map(|name| {
+		(
+			name.to_string(),
+			slope_of(&benchmark.component_calculated_proof_size, name),
+			slope_of(&benchmark.component_recorded_proof_size, name),
+		)
+	}))
+	.collect()
+}
+
+// Formats how much the measured value deviates from the estimate.
+fn relative_difference(estimated: u128, measured: u128) -> String {
+	if estimated == 0 {
+		return "-".into()
+	}
+	format!("{:+.1} %", (measured as f64 - estimated as f64) / estimated as f64 * 100.0)
+}
+
 /// Create weight file from benchmark data and Handlebars template.
 pub(crate) fn write_results(
 	batches: &[BenchmarkBatchSplitResults],
@@ -1248,6 +1405,33 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn proof_size_rows_work() {
+		let mapped_results = map_results(
+			&[test_data(b"bounded", b"bounded", BenchmarkParameter::d, 4, 6)],
+			&test_storage_info(),
+			&Default::default(),
+			Default::default(),
+			PovEstimationMode::MaxEncodedLen,
+			&AnalysisChoice::default(),
+			&AnalysisChoice::MedianSlopes,
+			1_000_000,
+			0,
+		)
+		.unwrap();
+		let benchmark = &mapped_results
+			.get(&("bounded_pallet".to_string(), "instance".to_string()))
+			.unwrap()[0];
+
+		assert_eq!(
+			proof_size_rows(benchmark),
+			vec![("base".to_string(), 10028, 1024), ("d".to_string(), 15042, 1024)]
+		);
+		assert_eq!(relative_difference(1000, 250), "-75.0 %");
+		assert_eq!(relative_difference(1000, 1200), "+20.0 %");
+		assert_eq!(relative_difference(0, 1024), "-");
+	}
+
 	#[test]
 	fn additional_trie_layers_work() {
 		let mapped_results = map_results(
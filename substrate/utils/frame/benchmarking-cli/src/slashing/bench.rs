@@ -0,0 +1,216 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the core benchmarking logic.
+
+use pallet_staking_runtime_api::StakingApi;
+use sc_cli::{Error, Result};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::{Error::RuntimeApiError, HeaderBackend};
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT},
+	Perbill,
+};
+use sp_staking::{EraIndex, SlashSimulation};
+
+use clap::Args;
+use codec::Codec;
+use log::info;
+use serde::Serialize;
+use std::{collections::BTreeSet, fmt::Debug, marker::PhantomData, sync::Arc, time::Instant};
+use thousands::Separable;
+
+use crate::shared::{StatSelect, Stats};
+
+/// Log target for printing the replayed offences.
+const LOG_TARGET: &'static str = "benchmark::slashing";
+
+/// The version of the staking runtime API that introduced the slash simulation.
+const SLASH_SIMULATION_API_VERSION: u32 = 3;
+
+/// The number of recorded offences that are fetched with a single runtime API call.
+const RECORDED_OFFENCES_PAGE_SIZE: u32 = 512;
+
+/// Parameters for modifying the benchmark behaviour.
+#[derive(Debug, Default, Serialize, Clone, PartialEq, Args)]
+pub struct BenchmarkParams {
+	/// Number of the first block to consider.
+	#[arg(long)]
+	pub from: u32,
+
+	/// Last block number to consider.
+	#[arg(long)]
+	pub to: u32,
+
+	/// Number of times that the simulation should be repeated for each offence.
+	#[arg(long, default_value_t = 10)]
+	pub repeat: u32,
+}
+
+/// Convenience closure for the [`Benchmark::run()`] function.
+pub struct Benchmark<Block, C, Balance, AccountId> {
+	client: Arc<C>,
+	params: BenchmarkParams,
+	_p: PhantomData<(Block, C, Balance, AccountId)>,
+}
+
+/// Helper for nano seconds.
+type NanoSeconds = u64;
+
+/// An offence as recorded by the staking pallet.
+type Offence<AccountId> = (EraIndex, AccountId, Perbill);
+
+impl<Block, C, Balance, AccountId> Benchmark<Block, C, Balance, AccountId>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: ApiExt<Block> + StakingApi<Block, Balance, AccountId>,
+	Balance: Codec + Into<u128>,
+	AccountId: Codec + Clone + Debug + Ord,
+{
+	/// Returns a new [`Self`] from the arguments.
+	pub fn new(client: Arc<C>, params: BenchmarkParams) -> Self {
+		Self { client, params, _p: PhantomData }
+	}
+
+	/// Replay the offences recorded in the configured blocks and log the results.
+	///
+	/// The staking pallet records an offence once it computes its slash, which may happen some
+	/// blocks after the offence was reported.
+	pub fn run(&self) -> Result<()> {
+		if self.params.from == 0 {
+			return Err("Cannot replay offences of the genesis block".into())
+		}
+
+		for i in self.params.from..=self.params.to {
+			let hash = self.client.hash(i.into())?.ok_or(format!("Block {} not found", i))?;
+			let header =
+				self.client.header(hash)?.ok_or(format!("Header of block {} not found", i))?;
+			let parent_hash = *header.parent_hash();
+
+			// Offences recorded in a block are those that were not yet recorded in its parent.
+			let before: BTreeSet<_> = self.recorded_offences(parent_hash)?.into_iter().collect();
+			for offence in self.recorded_offences(hash)? {
+				if before.contains(&offence) {
+					continue
+				}
+				let (simulation, took) = self.measure_offence(&offence, parent_hash)?;
+				self.log_offence(i, offence, simulation, took);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// The offences recorded in the state of the given block.
+	///
+	/// Errors if the runtime of the block does not support the slash simulation.
+	fn recorded_offences(&self, hash: Block::Hash) -> Result<Vec<Offence<AccountId>>> {
+		let runtime_api = self.client.runtime_api();
+		let supported = runtime_api
+			.has_api_with::<dyn StakingApi<Block, Balance, AccountId>, _>(hash, |version| {
+				version >= SLASH_SIMULATION_API_VERSION
+			})
+			.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+		if !supported {
+			return Err(format!(
+				"The runtime of block {} does not support simulating slashes, which requires \
+				version {} of the `StakingApi`",
+				hash, SLASH_SIMULATION_API_VERSION,
+			)
+			.into())
+		}
+
+		let mut offences = Vec::new();
+		loop {
+			let start_after = offences
+				.last()
+				.map(|(era, validator, _): &Offence<AccountId>| (*era, validator.clone()));
+			let page = runtime_api
+				.recorded_offences(hash, start_after, RECORDED_OFFENCES_PAGE_SIZE)
+				.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+			let last_page = page.len() < RECORDED_OFFENCES_PAGE_SIZE as usize;
+			offences.extend(page);
+			if last_page {
+				return Ok(offences)
+			}
+		}
+	}
+
+	/// Simulate an offence in the state of the given block and return the simulation as well as
+	/// the average time it took.
+	fn measure_offence(
+		&self,
+		(era, validator, fraction): &Offence<AccountId>,
+		at: Block::Hash,
+	) -> Result<(Option<SlashSimulation<AccountId, Balance>>, NanoSeconds)> {
+		let mut record = Vec::<NanoSeconds>::default();
+		let mut simulation = None;
+		// Interesting part here:
+		// Simulate the offence multiple times and collect stats about its execution time.
+		for _ in 0..self.params.repeat.max(1) {
+			let runtime_api = self.client.runtime_api();
+			let start = Instant::now();
+
+			simulation = runtime_api
+				.simulate_slash(at, validator.clone(), *era, *fraction)
+				.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+
+			record.push(start.elapsed().as_nanos() as NanoSeconds);
+		}
+
+		let took = Stats::new(&record)?.select(StatSelect::Average);
+		Ok((simulation, took))
+	}
+
+	/// Prints the outcome of a replayed offence to the console.
+	fn log_offence(
+		&self,
+		num: u32,
+		(era, validator, fraction): Offence<AccountId>,
+		simulation: Option<SlashSimulation<AccountId, Balance>>,
+		took: NanoSeconds,
+	) {
+		let offence = format!(
+			"Offence of {:?} in era {} ({:?}) recorded in block {}",
+			validator, era, fraction, num
+		);
+		let Some(simulation) = simulation else {
+			info!(target: LOG_TARGET, "{} is not slashed (took {} ns)", offence, took.separate_with_commas());
+			return
+		};
+
+		let own: u128 = simulation.validator.amount.into();
+		let total: u128 = simulation.total.into();
+		let nominators = simulation.nominators.len();
+		let applied = match simulation.apply_era {
+			Some(apply_era) => format!("deferred to era {}", apply_era),
+			None => "applied immediately".into(),
+		};
+
+		info!(
+			target: LOG_TARGET,
+			"{} slashes {} from the validator and {} from {} nominators, {} (took {} ns)",
+			offence,
+			own.separate_with_commas(),
+			total.saturating_sub(own).separate_with_commas(),
+			nominators,
+			applied,
+			took.separate_with_commas(),
+		);
+	}
+}
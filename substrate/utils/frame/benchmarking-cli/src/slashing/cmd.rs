@@ -0,0 +1,86 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`SlashingCmd`] as entry point for the CLI to execute
+//! the *slashing* benchmark.
+
+use pallet_staking_runtime_api::StakingApi;
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use clap::Parser;
+use codec::Codec;
+use std::{fmt::Debug, sync::Arc};
+
+use super::bench::{Benchmark, BenchmarkParams};
+
+/// Replay the offences recorded in historic blocks against the slash simulation of the staking
+/// runtime API.
+///
+/// This shows what an offence would have slashed, had it been processed in the state right
+/// before its slash was computed, and how long computing that takes. Example:
+///
+/// $ substrate benchmark slashing --from 1200 --to 1300 -d /tmp/my-dev --pruning archive
+///
+/// The output will be similar to this:
+///
+/// Offence of 5GNJqT… in era 12 (10.00000000%) recorded in block 1234 slashes 1,000,000 from
+/// the validator and 4,200,000 from 12 nominators, deferred to era 39 (took 1,234,567 ns)
+#[derive(Debug, Parser)]
+pub struct SlashingCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub params: BenchmarkParams,
+}
+
+impl SlashingCmd {
+	/// Replay the offences reported within the configured block range.
+	///
+	/// Output will be printed to console.
+	pub fn run<Block, C, Balance, AccountId>(&self, client: Arc<C>) -> Result<()>
+	where
+		Block: BlockT,
+		C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+		C::Api: ApiExt<Block> + StakingApi<Block, Balance, AccountId>,
+		Balance: Codec + Into<u128>,
+		AccountId: Codec + Clone + Debug + Ord,
+	{
+		// Put everything in the benchmark type to have the generic types handy.
+		Benchmark::<Block, C, Balance, AccountId>::new(client, self.params.clone()).run()
+	}
+}
+
+// Boilerplate
+impl CliConfiguration for SlashingCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
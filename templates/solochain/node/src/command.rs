@@ -168,6 +168,9 @@ pub fn run() -> sc_cli::Result<()> {
 					},
 					BenchmarkCmd::Machine(cmd) =>
 						cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone()),
+					BenchmarkCmd::Slashing(_) =>
+						Err("Slashing benchmarking is not supported, the runtime has no staking."
+							.into()),
 				}
 			})
 		},
@@ -653,6 +653,10 @@ pub use pallet_skip_feeless_payment;
 #[cfg(feature = "pallet-society")]
 pub use pallet_society;
 
+/// pallet to let a sponsor pay the transaction fees of another account.
+#[cfg(feature = "pallet-sponsored-tx-payment")]
+pub use pallet_sponsored_tx_payment;
+
 /// FRAME pallet staking.
 #[cfg(feature = "pallet-staking")]
 pub use pallet_staking;